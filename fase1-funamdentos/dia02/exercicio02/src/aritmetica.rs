@@ -0,0 +1,188 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{EscalaTemperatura, Temperatura, Unidade};
+
+/// Tolerância de `assert_quase_igual!` quando nenhuma é dada
+pub const TOLERANCIA_PADRAO: f64 = 1e-6;
+
+/// Diferença entre duas temperaturas. Ao contrário de um ponto, não tem zero:
+/// 10°C a mais são 10 K a mais, mas 10°C são 283.15 K
+#[derive(Debug, Clone, Copy)]
+pub struct DiferencaTemperatura {
+    pub valor: f64,
+    pub escala: EscalaTemperatura,
+}
+
+impl DiferencaTemperatura {
+    /// Cria nova diferença
+    pub fn new(valor: f64, escala: EscalaTemperatura) -> Self {
+        DiferencaTemperatura { valor, escala }
+    }
+
+    /// Tamanho da diferença em Kelvin (só o fator da escala, sem deslocamento)
+    pub fn em_kelvin(&self) -> f64 {
+        self.valor * self.escala.conversao().fator()
+    }
+
+    /// A mesma diferença em outra escala
+    pub fn converter_para(&self, escala_destino: EscalaTemperatura) -> DiferencaTemperatura {
+        let valor = self.em_kelvin() / escala_destino.conversao().fator() + 0.0;
+        DiferencaTemperatura::new(valor, escala_destino)
+    }
+}
+
+impl fmt::Display for DiferencaTemperatura {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let casas = f.precision().unwrap_or(2);
+        write!(f, "Δ{:.*}{}", casas, self.valor, self.escala.simbolo())
+    }
+}
+
+// ========================================
+// OPERADORES
+// ========================================
+
+// O resultado fica na escala do operando da esquerda. Somar dois pontos não
+// existe de propósito: 10°C + 10°C não é 20°C em nenhuma escala absoluta.
+
+/// Ponto − ponto = diferença
+impl Sub for Temperatura {
+    type Output = DiferencaTemperatura;
+
+    fn sub(self, outra: Temperatura) -> DiferencaTemperatura {
+        let outra = self.escala.de_kelvin(outra.para_kelvin());
+        DiferencaTemperatura::new(self.valor - outra, self.escala)
+    }
+}
+
+/// Ponto + diferença = ponto (não verifica o zero absoluto; use `validar`)
+impl Add<DiferencaTemperatura> for Temperatura {
+    type Output = Temperatura;
+
+    fn add(self, diferenca: DiferencaTemperatura) -> Temperatura {
+        Temperatura::new(self.valor + diferenca.converter_para(self.escala).valor, self.escala)
+    }
+}
+
+/// Ponto − diferença = ponto
+impl Sub<DiferencaTemperatura> for Temperatura {
+    type Output = Temperatura;
+
+    fn sub(self, diferenca: DiferencaTemperatura) -> Temperatura {
+        self + -diferenca
+    }
+}
+
+impl Add for DiferencaTemperatura {
+    type Output = DiferencaTemperatura;
+
+    fn add(self, outra: DiferencaTemperatura) -> DiferencaTemperatura {
+        DiferencaTemperatura::new(self.valor + outra.converter_para(self.escala).valor, self.escala)
+    }
+}
+
+impl Sub for DiferencaTemperatura {
+    type Output = DiferencaTemperatura;
+
+    fn sub(self, outra: DiferencaTemperatura) -> DiferencaTemperatura {
+        self + -outra
+    }
+}
+
+impl Neg for DiferencaTemperatura {
+    type Output = DiferencaTemperatura;
+
+    fn neg(self) -> DiferencaTemperatura {
+        DiferencaTemperatura::new(-self.valor, self.escala)
+    }
+}
+
+impl Mul<f64> for DiferencaTemperatura {
+    type Output = DiferencaTemperatura;
+
+    fn mul(self, fator: f64) -> DiferencaTemperatura {
+        DiferencaTemperatura::new(self.valor * fator, self.escala)
+    }
+}
+
+impl Div<f64> for DiferencaTemperatura {
+    type Output = DiferencaTemperatura;
+
+    fn div(self, divisor: f64) -> DiferencaTemperatura {
+        DiferencaTemperatura::new(self.valor / divisor, self.escala)
+    }
+}
+
+// ========================================
+// COMPARAÇÃO
+// ========================================
+
+// Compara o estado físico, em Kelvin, e não o número: 0°C == 273.15 K.
+// Conversões acumulam arredondamento, então prefira `quase_igual` a `==`.
+
+impl PartialEq for Temperatura {
+    fn eq(&self, outra: &Temperatura) -> bool {
+        self.para_kelvin() == outra.para_kelvin()
+    }
+}
+
+impl PartialOrd for Temperatura {
+    fn partial_cmp(&self, outra: &Temperatura) -> Option<Ordering> {
+        self.para_kelvin().partial_cmp(&outra.para_kelvin())
+    }
+}
+
+impl PartialEq for DiferencaTemperatura {
+    fn eq(&self, outra: &DiferencaTemperatura) -> bool {
+        self.em_kelvin() == outra.em_kelvin()
+    }
+}
+
+impl PartialOrd for DiferencaTemperatura {
+    fn partial_cmp(&self, outra: &DiferencaTemperatura) -> Option<Ordering> {
+        self.em_kelvin().partial_cmp(&outra.em_kelvin())
+    }
+}
+
+/// Igualdade com tolerância; para temperaturas a tolerância é em Kelvin
+pub trait QuaseIgual {
+    fn quase_igual(&self, outro: &Self, tolerancia: f64) -> bool;
+}
+
+impl QuaseIgual for f64 {
+    fn quase_igual(&self, outro: &f64, tolerancia: f64) -> bool {
+        (self - outro).abs() <= tolerancia
+    }
+}
+
+impl QuaseIgual for Temperatura {
+    fn quase_igual(&self, outra: &Temperatura, tolerancia: f64) -> bool {
+        self.para_kelvin().quase_igual(&outra.para_kelvin(), tolerancia)
+    }
+}
+
+impl QuaseIgual for DiferencaTemperatura {
+    fn quase_igual(&self, outra: &DiferencaTemperatura, tolerancia: f64) -> bool {
+        self.em_kelvin().quase_igual(&outra.em_kelvin(), tolerancia)
+    }
+}
+
+/// `assert_eq!` com tolerância (padrão `TOLERANCIA_PADRAO`) para qualquer `QuaseIgual`
+#[macro_export]
+macro_rules! assert_quase_igual {
+    ($esquerda:expr, $direita:expr $(,)?) => {
+        $crate::assert_quase_igual!($esquerda, $direita, $crate::TOLERANCIA_PADRAO)
+    };
+    ($esquerda:expr, $direita:expr, $tolerancia:expr $(,)?) => {{
+        let (esquerda, direita) = (&$esquerda, &$direita);
+        assert!(
+            $crate::QuaseIgual::quase_igual(esquerda, direita, $tolerancia),
+            "assertion `esquerda ≈ direita` failed (tolerância {})\n esquerda: {:?}\n  direita: {:?}",
+            $tolerancia,
+            esquerda,
+            direita
+        );
+    }};
+}
@@ -0,0 +1,144 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{EscalaTemperatura, Temperatura, TemperaturaError};
+
+use EscalaTemperatura::{Celsius, Fahrenheit};
+
+/// Entradas fora do domínio das fórmulas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErroIndiceTermico {
+    /// Umidade relativa fora de (0, 100] %
+    UmidadeInvalida(f64),
+    /// Velocidade do vento negativa ou não finita
+    VentoInvalido(f64),
+    /// Ponto de orvalho acima da temperatura do ar (°C); o ar não passa de 100 %
+    OrvalhoAcimaDoAr { orvalho: f64, temperatura: f64 },
+    Temperatura(TemperaturaError),
+}
+
+impl fmt::Display for ErroIndiceTermico {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErroIndiceTermico::UmidadeInvalida(umidade) => {
+                write!(f, "Umidade relativa inválida: {}% (deve estar entre 0 e 100)", umidade)
+            }
+            ErroIndiceTermico::VentoInvalido(vento) => write!(f, "Velocidade do vento inválida: {} km/h", vento),
+            ErroIndiceTermico::OrvalhoAcimaDoAr { orvalho, temperatura } => write!(
+                f,
+                "Ponto de orvalho ({:.2}°C) acima da temperatura do ar ({:.2}°C)",
+                orvalho, temperatura
+            ),
+            ErroIndiceTermico::Temperatura(erro) => write!(f, "{}", erro),
+        }
+    }
+}
+
+impl Error for ErroIndiceTermico {}
+
+impl From<TemperaturaError> for ErroIndiceTermico {
+    fn from(erro: TemperaturaError) -> Self {
+        ErroIndiceTermico::Temperatura(erro)
+    }
+}
+
+// As fórmulas são publicadas em uma escala fixa (°F para o NWS, °C para o
+// Environment Canada); a entrada é convertida para ela e o resultado para
+// a escala pedida em `destino`.
+
+impl Temperatura {
+    /// Índice de calor (sensação com umidade), pela regressão de Rothfusz
+    /// com os ajustes do NWS. Abaixo de ~80°F usa a fórmula simples do NWS
+    pub fn indice_de_calor(
+        &self,
+        umidade_relativa: f64,
+        destino: EscalaTemperatura,
+    ) -> Result<Temperatura, ErroIndiceTermico> {
+        let umidade = validar_umidade(umidade_relativa)?;
+        let t = self.converter_para(Fahrenheit)?.valor;
+
+        let simples = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + umidade * 0.094);
+        let indice = if (simples + t) / 2.0 < 80.0 {
+            simples
+        } else {
+            let mut indice = -42.379 + 2.04901523 * t + 10.14333127 * umidade
+                - 0.22475541 * t * umidade
+                - 0.00683783 * t * t
+                - 0.05481717 * umidade * umidade
+                + 0.00122874 * t * t * umidade
+                + 0.00085282 * t * umidade * umidade
+                - 0.00000199 * t * t * umidade * umidade;
+            if umidade < 13.0 && (80.0..=112.0).contains(&t) {
+                indice -= (13.0 - umidade) / 4.0 * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt();
+            } else if umidade > 85.0 && (80.0..=87.0).contains(&t) {
+                indice += (umidade - 85.0) / 10.0 * ((87.0 - t) / 5.0);
+            }
+            indice
+        };
+
+        Ok(Temperatura::new(indice, Fahrenheit).converter_para(destino)?)
+    }
+
+    /// Sensação térmica do vento (fórmula de 2001 do NWS/Environment Canada).
+    /// Só é definida até 10°C e com vento acima de 4.8 km/h; fora disso a
+    /// sensação é a própria temperatura do ar
+    pub fn sensacao_do_vento(
+        &self,
+        vento_kmh: f64,
+        destino: EscalaTemperatura,
+    ) -> Result<Temperatura, ErroIndiceTermico> {
+        if !vento_kmh.is_finite() || vento_kmh < 0.0 {
+            return Err(ErroIndiceTermico::VentoInvalido(vento_kmh));
+        }
+        let t = self.converter_para(Celsius)?.valor;
+        if t > 10.0 || vento_kmh <= 4.8 {
+            return Ok(self.converter_para(destino)?);
+        }
+
+        let v = vento_kmh.powf(0.16);
+        let sensacao = 13.12 + 0.6215 * t - 11.37 * v + 0.3965 * t * v;
+        Ok(Temperatura::new(sensacao, Celsius).converter_para(destino)?)
+    }
+
+    /// Ponto de orvalho pela fórmula de Magnus (constantes de Sonntag),
+    /// precisa a ±0.35°C entre -45°C e 60°C
+    pub fn ponto_de_orvalho(
+        &self,
+        umidade_relativa: f64,
+        destino: EscalaTemperatura,
+    ) -> Result<Temperatura, ErroIndiceTermico> {
+        const A: f64 = 17.62;
+        const B: f64 = 243.12;
+
+        let umidade = validar_umidade(umidade_relativa)?;
+        let t = self.converter_para(Celsius)?.valor;
+        let gama = (umidade / 100.0).ln() + A * t / (B + t);
+        let orvalho = B * gama / (A - gama);
+        Ok(Temperatura::new(orvalho, Celsius).converter_para(destino)?)
+    }
+
+    /// Humidex do Environment Canada, a partir do ponto de orvalho
+    /// (ver `ponto_de_orvalho` para obtê-lo da umidade relativa), que não
+    /// pode passar da temperatura do ar
+    pub fn humidex(
+        &self,
+        ponto_de_orvalho: &Temperatura,
+        destino: EscalaTemperatura,
+    ) -> Result<Temperatura, ErroIndiceTermico> {
+        let t = self.converter_para(Celsius)?.valor;
+        let orvalho_kelvin = ponto_de_orvalho.converter_para(EscalaTemperatura::Kelvin)?.valor;
+        if orvalho_kelvin > self.para_kelvin() + crate::TOLERANCIA_KELVIN {
+            let orvalho = ponto_de_orvalho.para_celsius();
+            return Err(ErroIndiceTermico::OrvalhoAcimaDoAr { orvalho, temperatura: t });
+        }
+
+        // Pressão de vapor em hPa
+        let vapor = 6.11 * (5417.7530 * (1.0 / 273.16 - 1.0 / orvalho_kelvin)).exp();
+        let humidex = t + 0.5555 * (vapor - 10.0);
+        Ok(Temperatura::new(humidex, Celsius).converter_para(destino)?)
+    }
+}
+
+fn validar_umidade(umidade: f64) -> Result<f64, ErroIndiceTermico> {
+    if umidade > 0.0 && umidade <= 100.0 { Ok(umidade) } else { Err(ErroIndiceTermico::UmidadeInvalida(umidade)) }
+}
@@ -0,0 +1,118 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{EscalaTemperatura, Temperatura, TemperaturaError};
+
+/// Erros ao ler uma temperatura ou escala de um texto
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErroLeituraTemperatura {
+    Vazia,
+    NumeroInvalido(String),
+    SemEscala,
+    EscalaDesconhecida(String),
+    /// Linha de conversão sem `to`/`para`/`->` entre origem e destino
+    SemDestino,
+    /// Lida, mas fisicamente impossível
+    ForaDosLimites(TemperaturaError),
+}
+
+impl fmt::Display for ErroLeituraTemperatura {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErroLeituraTemperatura::Vazia => write!(f, "Entrada vazia"),
+            ErroLeituraTemperatura::NumeroInvalido(texto) => write!(f, "Número inválido '{}'", texto),
+            ErroLeituraTemperatura::SemEscala => write!(f, "Faltou a escala (ex.: 25C, 77 °F, 298.15 K)"),
+            ErroLeituraTemperatura::EscalaDesconhecida(texto) => write!(f, "Escala desconhecida '{}'", texto),
+            ErroLeituraTemperatura::SemDestino => write!(f, "Faltou o destino (ex.: 100F to K)"),
+            ErroLeituraTemperatura::ForaDosLimites(erro) => write!(f, "{}", erro),
+        }
+    }
+}
+
+impl Error for ErroLeituraTemperatura {}
+
+impl From<TemperaturaError> for ErroLeituraTemperatura {
+    fn from(erro: TemperaturaError) -> Self {
+        ErroLeituraTemperatura::ForaDosLimites(erro)
+    }
+}
+
+/// Aceita símbolo com ou sem grau, nome e variações sem acento, sem diferenciar maiúsculas
+impl FromStr for EscalaTemperatura {
+    type Err = ErroLeituraTemperatura;
+
+    fn from_str(texto: &str) -> Result<Self, Self::Err> {
+        let texto = texto.trim();
+        if texto.is_empty() {
+            return Err(ErroLeituraTemperatura::SemEscala);
+        }
+
+        // `º` (ordinal) aparece muito no lugar de `°`
+        let minusculo = texto.to_lowercase();
+        let nome = minusculo.trim_start_matches(['°', 'º']).trim_start();
+        let escala = match nome {
+            "c" | "celsius" | "centigrado" | "centígrado" => EscalaTemperatura::Celsius,
+            "f" | "fahrenheit" => EscalaTemperatura::Fahrenheit,
+            "k" | "kelvin" => EscalaTemperatura::Kelvin,
+            "r" | "ra" | "rankine" => EscalaTemperatura::Rankine,
+            "ré" | "re" | "réaumur" | "reaumur" => EscalaTemperatura::Reaumur,
+            "de" | "d" | "delisle" => EscalaTemperatura::Delisle,
+            "n" | "newton" => EscalaTemperatura::Newton,
+            "rø" | "ro" | "rømer" | "romer" | "roemer" => EscalaTemperatura::Romer,
+            _ => return Err(ErroLeituraTemperatura::EscalaDesconhecida(texto.to_string())),
+        };
+        Ok(escala)
+    }
+}
+
+/// `25C`, `77 °F`, `298.15 K`, `-40 fahrenheit`, `25.00°C` (o que `Display` gera)
+impl FromStr for Temperatura {
+    type Err = ErroLeituraTemperatura;
+
+    fn from_str(texto: &str) -> Result<Self, Self::Err> {
+        let texto = texto.trim();
+        if texto.is_empty() {
+            return Err(ErroLeituraTemperatura::Vazia);
+        }
+
+        let (numero, escala) = separar_numero(texto);
+        // Sem nenhum dígito no começo, o texto inteiro é o número inválido
+        let invalido = if numero.trim().is_empty() { texto } else { numero.trim() };
+        let valor: f64 = numero
+            .trim()
+            .parse()
+            .map_err(|_| ErroLeituraTemperatura::NumeroInvalido(invalido.to_string()))?;
+        Ok(Temperatura::try_new(valor, escala.parse()?)?)
+    }
+}
+
+/// Separa o maior prefixo que é um número válido do resto (a escala).
+/// Recua caractere a caractere para que `5e` não engula o `e` de uma escala,
+/// mas não corta no meio de um número (`1.2.3 C` é número inválido, não `1.2` + `.3 C`)
+fn separar_numero(texto: &str) -> (&str, &str) {
+    let candidato = texto
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E' | ' ')))
+        .unwrap_or(texto.len());
+
+    (1..=candidato)
+        .rev()
+        .filter(|&fim| texto.is_char_boundary(fim))
+        .filter(|&fim| !texto[fim..].starts_with(|c: char| c.is_ascii_digit() || c == '.'))
+        .find(|&fim| texto[..fim].trim().parse::<f64>().is_ok())
+        .map_or((&texto[..candidato], &texto[candidato..]), |fim| (&texto[..fim], &texto[fim..]))
+}
+
+/// Lê uma linha de conversão livre: `100F to K`, `25 °C para Fahrenheit`, `300K -> C`
+pub fn interpretar_conversao(linha: &str) -> Result<(Temperatura, EscalaTemperatura), ErroLeituraTemperatura> {
+    let linha = linha.trim();
+    if linha.is_empty() {
+        return Err(ErroLeituraTemperatura::Vazia);
+    }
+
+    let (origem, destino) = [" to ", " para ", "->"]
+        .iter()
+        .find_map(|separador| linha.rsplit_once(separador))
+        .ok_or(ErroLeituraTemperatura::SemDestino)?;
+    Ok((origem.parse()?, destino.parse()?))
+}
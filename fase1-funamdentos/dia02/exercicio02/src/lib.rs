@@ -1,84 +1,254 @@
+use std::error::Error;
 use std::fmt;
 
+mod aritmetica;
+mod indices;
+mod leitura;
+mod localizacao;
+mod lote;
+mod registro;
+#[cfg(feature = "serde")]
+mod serializacao;
+mod tabela;
+mod unidade;
+
+pub use aritmetica::{DiferencaTemperatura, QuaseIgual, TOLERANCIA_PADRAO};
+pub use indices::ErroIndiceTermico;
+pub use leitura::{ErroLeituraTemperatura, interpretar_conversao};
+pub use localizacao::{EstiloUnidade, Formatacao, Localidade};
+pub use lote::{ErroLinha, ErroLote, FormatoLote, MAXIMO_CASAS, OpcoesLote, ResumoLote, converter_lote};
+pub use registro::{RegistroUnidades, UnidadeRegistrada};
+#[cfg(feature = "serde")]
+pub use serializacao::compacto;
+pub use tabela::{ErroTabela, FormatoTabela, MAXIMO_LINHAS, Tabela};
+pub use unidade::{Conversao, ErroConversao, Grandeza, Medida, Unidade};
+
 /// Enum para representar escalas de temperatura
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "lowercase"))]
 pub enum EscalaTemperatura {
     Celsius,
     Fahrenheit,
     Kelvin,
+    Rankine,
+    Reaumur,
+    Delisle,
+    Newton,
+    Romer,
+}
+
+impl EscalaTemperatura {
+    /// Todas as escalas, na ordem do menu
+    pub const TODAS: [EscalaTemperatura; 8] = [
+        EscalaTemperatura::Celsius,
+        EscalaTemperatura::Fahrenheit,
+        EscalaTemperatura::Kelvin,
+        EscalaTemperatura::Rankine,
+        EscalaTemperatura::Reaumur,
+        EscalaTemperatura::Delisle,
+        EscalaTemperatura::Newton,
+        EscalaTemperatura::Romer,
+    ];
+
+    /// Nome da escala, sem o símbolo
+    pub fn nome(&self) -> &'static str {
+        match self {
+            EscalaTemperatura::Celsius => "Celsius",
+            EscalaTemperatura::Fahrenheit => "Fahrenheit",
+            EscalaTemperatura::Kelvin => "Kelvin",
+            EscalaTemperatura::Rankine => "Rankine",
+            EscalaTemperatura::Reaumur => "Réaumur",
+            EscalaTemperatura::Delisle => "Delisle",
+            EscalaTemperatura::Newton => "Newton",
+            EscalaTemperatura::Romer => "Rømer",
+        }
+    }
+
+    /// Símbolo usado ao exibir valores
+    pub fn simbolo(&self) -> &'static str {
+        match self {
+            EscalaTemperatura::Celsius => "°C",
+            EscalaTemperatura::Fahrenheit => "°F",
+            EscalaTemperatura::Kelvin => "K",
+            EscalaTemperatura::Rankine => "°R",
+            EscalaTemperatura::Reaumur => "°Ré",
+            EscalaTemperatura::Delisle => "°De",
+            EscalaTemperatura::Newton => "°N",
+            EscalaTemperatura::Romer => "°Rø",
+        }
+    }
+
+    /// Converte um valor desta escala para Kelvin
+    pub fn para_kelvin(&self, valor: f64) -> f64 {
+        self.conversao().para_referencia(valor)
+    }
+
+    /// Converte um valor em Kelvin para esta escala
+    pub fn de_kelvin(&self, kelvin: f64) -> f64 {
+        self.conversao().de_referencia(kelvin)
+    }
+
+    /// 0 K nesta escala. É um mínimo, exceto em Delisle, que cresce no frio
+    pub fn zero_absoluto(&self) -> f64 {
+        self.de_kelvin(0.0)
+    }
+}
+
+/// Escalas de temperatura são unidades afins com Kelvin como referência
+impl Unidade for EscalaTemperatura {
+    fn simbolo(&self) -> &str {
+        EscalaTemperatura::simbolo(self)
+    }
+
+    fn nome(&self) -> &str {
+        EscalaTemperatura::nome(self)
+    }
+
+    fn grandeza(&self) -> Grandeza {
+        Grandeza::Temperatura
+    }
+
+    /// `K = valor * fator + deslocamento`; o deslocamento é o zero da escala
+    /// em Kelvin e Delisle cresce ao contrário
+    fn conversao(&self) -> Conversao {
+        let (fator, deslocamento) = match self {
+            EscalaTemperatura::Celsius => (1.0, 273.15),
+            EscalaTemperatura::Fahrenheit => (5.0 / 9.0, 459.67 * 5.0 / 9.0),
+            EscalaTemperatura::Kelvin => (1.0, 0.0),
+            EscalaTemperatura::Rankine => (5.0 / 9.0, 0.0),
+            EscalaTemperatura::Reaumur => (5.0 / 4.0, 273.15),
+            EscalaTemperatura::Delisle => (-2.0 / 3.0, 373.15),
+            EscalaTemperatura::Newton => (100.0 / 33.0, 273.15),
+            EscalaTemperatura::Romer => (40.0 / 21.0, 273.15 - 7.5 * 40.0 / 21.0),
+        };
+        Conversao::Afim { fator, deslocamento }
+    }
+
+    /// As mesmas regras de `Temperatura::validar`: finita e acima do zero absoluto
+    fn validar(&self, valor: f64) -> Result<(), ErroConversao> {
+        Ok(Temperatura::new(valor, *self).validar()?)
+    }
 }
 
 /// Implementa Display para exibir nomes bonitos
 impl fmt::Display for EscalaTemperatura {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            EscalaTemperatura::Celsius => write!(f, "Celsius (°C)"),
-            EscalaTemperatura::Fahrenheit => write!(f, "Fahrenheit (°F)"),
-            EscalaTemperatura::Kelvin => write!(f, "Kelvin (K)"),
+        write!(f, "{} ({})", self.nome(), self.simbolo())
+    }
+}
+
+/// Folga para arredondamentos: -459.67°F vira -1e-14 K e ainda é válido
+const TOLERANCIA_KELVIN: f64 = 1e-9;
+
+/// Temperaturas que não existem fisicamente
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TemperaturaError {
+    /// NaN ou infinito
+    NaoFinita { valor: f64, escala: EscalaTemperatura },
+    AbaixoDoZeroAbsoluto { valor: f64, escala: EscalaTemperatura },
+}
+
+impl fmt::Display for TemperaturaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TemperaturaError::NaoFinita { valor, escala } => {
+                write!(f, "Temperatura não finita: {}{}", valor, escala.simbolo())
+            }
+            TemperaturaError::AbaixoDoZeroAbsoluto { valor, escala } => write!(
+                f,
+                "{:.2}{} é mais frio que o zero absoluto ({:.2}{})",
+                valor,
+                escala.simbolo(),
+                escala.zero_absoluto(),
+                escala.simbolo()
+            ),
         }
     }
 }
 
+impl Error for TemperaturaError {}
+
 /// Struct para representar uma temperatura
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "serializacao::Forma")
+)]
 pub struct Temperatura {
     pub valor: f64,
     pub escala: EscalaTemperatura,
 }
 
 impl Temperatura {
-    /// Cria nova temperatura
+    /// Cria nova temperatura, sem verificar se ela existe (ver `try_new`)
     pub fn new(valor: f64, escala: EscalaTemperatura) -> Self {
         Temperatura { valor, escala }
     }
 
+    /// Cria nova temperatura, recusando NaN, infinito e valores além do zero absoluto
+    pub fn try_new(valor: f64, escala: EscalaTemperatura) -> Result<Self, TemperaturaError> {
+        let temperatura = Temperatura::new(valor, escala);
+        temperatura.validar()?;
+        Ok(temperatura)
+    }
+
+    /// Verifica os limites físicos; o limite de cada escala vem do seu zero absoluto
+    pub fn validar(&self) -> Result<(), TemperaturaError> {
+        let (valor, escala) = (self.valor, self.escala);
+        if !valor.is_finite() {
+            return Err(TemperaturaError::NaoFinita { valor, escala });
+        }
+        if self.para_kelvin() < -TOLERANCIA_KELVIN {
+            return Err(TemperaturaError::AbaixoDoZeroAbsoluto { valor, escala });
+        }
+        Ok(())
+    }
+
     /// Converte para Celsius
     pub fn para_celsius(&self) -> f64 {
-        match self.escala {
-            EscalaTemperatura::Celsius => self.valor,
-            EscalaTemperatura::Fahrenheit => (self.valor - 32.0) * 5.0 / 9.0,
-            EscalaTemperatura::Kelvin => self.valor - 273.15,
-        }
+        EscalaTemperatura::Celsius.de_kelvin(self.para_kelvin())
     }
 
     /// Converte para Fahrenheit
     pub fn para_fahrenheit(&self) -> f64 {
-        match self.escala {
-            EscalaTemperatura::Celsius => self.valor * 9.0 / 5.0 + 32.0,
-            EscalaTemperatura::Fahrenheit => self.valor,
-            EscalaTemperatura::Kelvin => (self.valor - 273.15) * 9.0 / 5.0 + 32.0,
-        }
+        EscalaTemperatura::Fahrenheit.de_kelvin(self.para_kelvin())
     }
 
     /// Converte para Kelvin
     pub fn para_kelvin(&self) -> f64 {
-        match self.escala {
-            EscalaTemperatura::Celsius => self.valor + 273.15,
-            EscalaTemperatura::Fahrenheit => (self.valor - 32.0) * 5.0 / 9.0 + 273.15,
-            EscalaTemperatura::Kelvin => self.valor,
+        self.escala.para_kelvin(self.valor)
+    }
+
+    /// Converte para outra escala, sempre passando por Kelvin.
+    /// Temperaturas criadas com `new` fora dos limites dão erro aqui
+    pub fn converter_para(&self, escala_destino: EscalaTemperatura) -> Result<Temperatura, TemperaturaError> {
+        self.validar()?;
+        if escala_destino == self.escala {
+            return Ok(*self);
         }
+
+        Ok(Temperatura::new(escala_destino.de_kelvin(self.para_kelvin()), escala_destino))
     }
+}
 
-    /// Converte para outra escala
-    pub fn converter_para(&self, escala_destino: EscalaTemperatura) -> Temperatura {
-        let novo_valor = match escala_destino {
-            EscalaTemperatura::Celsius => self.para_celsius(),
-            EscalaTemperatura::Fahrenheit => self.para_fahrenheit(),
-            EscalaTemperatura::Kelvin => self.para_kelvin(),
-        };
+/// `Temperatura` é a `Medida` de temperatura com nomes próprios
+impl From<Temperatura> for Medida<EscalaTemperatura> {
+    fn from(temperatura: Temperatura) -> Self {
+        Medida::new(temperatura.valor, temperatura.escala)
+    }
+}
 
-        Temperatura::new(novo_valor, escala_destino)
+impl From<Medida<EscalaTemperatura>> for Temperatura {
+    fn from(medida: Medida<EscalaTemperatura>) -> Self {
+        Temperatura::new(medida.valor, medida.unidade)
     }
 }
 
-/// Implementa Display para Temperatura
+/// Implementa Display para Temperatura; `{:.4}` escolhe as casas (padrão 2).
+/// Mesmo formato de `Medida`
 impl fmt::Display for Temperatura {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let simbolo = match self.escala {
-            EscalaTemperatura::Celsius => "°C",
-            EscalaTemperatura::Fahrenheit => "°F",
-            EscalaTemperatura::Kelvin => "K",
-        };
-        write!(f, "{:.2}{}", self.valor, simbolo)
+        fmt::Display::fmt(&Medida::from(*self), f)
     }
 }
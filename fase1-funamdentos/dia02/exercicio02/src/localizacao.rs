@@ -0,0 +1,155 @@
+use crate::{DiferencaTemperatura, EscalaTemperatura, Temperatura};
+
+/// Idiomas/regiões com formatação própria
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Localidade {
+    PtBr,
+    EnUs,
+    DeDe,
+}
+
+impl Localidade {
+    pub const TODAS: [Localidade; 3] = [Localidade::PtBr, Localidade::EnUs, Localidade::DeDe];
+
+    /// `pt-BR`, `en_US`, `de-de`... (sem diferenciar maiúsculas, `-` ou `_`)
+    pub fn de_codigo(codigo: &str) -> Option<Localidade> {
+        match codigo.to_lowercase().replace('_', "-").as_str() {
+            "pt-br" | "pt" => Some(Localidade::PtBr),
+            "en-us" | "en" => Some(Localidade::EnUs),
+            "de-de" | "de" => Some(Localidade::DeDe),
+            _ => None,
+        }
+    }
+
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            Localidade::PtBr => "pt-BR",
+            Localidade::EnUs => "en-US",
+            Localidade::DeDe => "de-DE",
+        }
+    }
+
+    fn separador_decimal(&self) -> char {
+        match self {
+            Localidade::EnUs => '.',
+            Localidade::PtBr | Localidade::DeDe => ',',
+        }
+    }
+
+    fn separador_milhar(&self) -> char {
+        match self {
+            Localidade::EnUs => ',',
+            Localidade::PtBr | Localidade::DeDe => '.',
+        }
+    }
+
+    /// Nome longo da unidade, no singular ou no plural
+    fn nome_unidade(&self, escala: EscalaTemperatura, singular: bool) -> String {
+        if escala == EscalaTemperatura::Kelvin {
+            let nome = match (self, singular) {
+                (Localidade::DeDe, _) => "Kelvin",
+                (_, true) => "kelvin",
+                (_, false) => "kelvins",
+            };
+            return String::from(nome);
+        }
+
+        let grau = match (self, singular) {
+            (Localidade::PtBr, true) => "grau",
+            (Localidade::PtBr, false) => "graus",
+            (Localidade::EnUs, true) => "degree",
+            (Localidade::EnUs, false) => "degrees",
+            (Localidade::DeDe, _) => "Grad",
+        };
+        format!("{} {}", grau, escala.nome())
+    }
+}
+
+/// Símbolo (`25,00 °C`) ou nome por extenso (`25,00 graus Celsius`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EstiloUnidade {
+    Curto,
+    Longo,
+}
+
+/// Como escrever temperaturas para uma localidade
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Formatacao {
+    localidade: Localidade,
+    casas: usize,
+    estilo: EstiloUnidade,
+}
+
+impl Formatacao {
+    /// Duas casas e símbolo curto, como o `Display`
+    pub fn new(localidade: Localidade) -> Self {
+        Formatacao { localidade, casas: 2, estilo: EstiloUnidade::Curto }
+    }
+
+    pub fn com_casas(mut self, casas: usize) -> Self {
+        self.casas = casas;
+        self
+    }
+
+    pub fn com_estilo(mut self, estilo: EstiloUnidade) -> Self {
+        self.estilo = estilo;
+        self
+    }
+
+    /// Número com os separadores da localidade: `-1.234,50` / `-1,234.50`
+    pub fn numero(&self, valor: f64) -> String {
+        let texto = format!("{:.*}", self.casas, valor.abs());
+        let (inteira, fracao) = texto.split_once('.').unwrap_or((&texto, ""));
+
+        let digitos: Vec<char> = inteira.chars().collect();
+        let grupos: Vec<String> = digitos.rchunks(3).rev().map(|grupo| grupo.iter().collect()).collect();
+        let mut numero = grupos.join(&self.localidade.separador_milhar().to_string());
+        if !fracao.is_empty() {
+            numero.push(self.localidade.separador_decimal());
+            numero.push_str(fracao);
+        }
+
+        // Sem "-0,00" quando o arredondamento zera o valor
+        let zerado = texto.chars().all(|c| c == '0' || c == '.');
+        if valor.is_sign_negative() && !zerado { format!("-{}", numero) } else { numero }
+    }
+
+    /// Nome da escala no estilo escolhido: `°C` ou `graus Celsius`
+    pub fn escala(&self, escala: EscalaTemperatura) -> String {
+        match self.estilo {
+            EstiloUnidade::Curto => escala.simbolo().to_string(),
+            EstiloUnidade::Longo => self.localidade.nome_unidade(escala, false),
+        }
+    }
+
+    pub fn temperatura(&self, temperatura: &Temperatura) -> String {
+        self.com_unidade(temperatura.valor, temperatura.escala)
+    }
+
+    pub fn diferenca(&self, diferenca: &DiferencaTemperatura) -> String {
+        format!("Δ{}", self.com_unidade(diferenca.valor, diferenca.escala))
+    }
+
+    fn com_unidade(&self, valor: f64, escala: EscalaTemperatura) -> String {
+        let numero = self.numero(valor);
+        match self.estilo {
+            EstiloUnidade::Curto => {
+                // Nos EUA o grau fica colado (77°F); no SI e na Europa, separado (25 °C)
+                let colado = self.localidade == Localidade::EnUs && escala.simbolo().starts_with('°');
+                format!("{}{}{}", numero, if colado { "" } else { " " }, escala.simbolo())
+            }
+            EstiloUnidade::Longo => {
+                // Singular só para exatamente um inteiro: "1 grau", mas "1,00 graus"
+                let singular = numero == "1" || numero == "-1";
+                format!("{} {}", numero, self.localidade.nome_unidade(escala, singular))
+            }
+        }
+    }
+}
+
+impl Temperatura {
+    /// Atalho para `Formatacao::temperatura`
+    pub fn formatar(&self, formatacao: &Formatacao) -> String {
+        formatacao.temperatura(self)
+    }
+}
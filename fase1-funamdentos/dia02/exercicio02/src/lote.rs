@@ -0,0 +1,247 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[cfg(feature = "jsonl")]
+use serde_json::{Map, Number, Value};
+
+use crate::{ErroLeituraTemperatura, EscalaTemperatura, Temperatura};
+
+/// Formato das linhas de entrada e saída
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FormatoLote {
+    /// Primeira linha é o cabeçalho; a coluna é escolhida pelo nome
+    Csv,
+    /// Um objeto JSON por linha; a coluna é uma chave do objeto (feature `jsonl`)
+    #[cfg(feature = "jsonl")]
+    JsonLinhas,
+}
+
+impl FormatoLote {
+    /// `.jsonl`/`.ndjson` são JSON-lines; o resto é tratado como CSV
+    pub fn pela_extensao(caminho: &str) -> FormatoLote {
+        match caminho {
+            #[cfg(feature = "jsonl")]
+            _ if caminho.ends_with(".jsonl") || caminho.ends_with(".ndjson") => FormatoLote::JsonLinhas,
+            _ => FormatoLote::Csv,
+        }
+    }
+}
+
+/// Mais casas que isso um f64 não distingue (tem uns 17 algarismos significativos)
+pub const MAXIMO_CASAS: usize = 17;
+
+/// O que converter e para onde
+#[derive(Debug, Clone)]
+pub struct OpcoesLote {
+    pub formato: FormatoLote,
+    pub coluna: String,
+    pub destino: EscalaTemperatura,
+    /// Escala para células só com número (`25` em vez de `25C`)
+    pub escala_padrao: Option<EscalaTemperatura>,
+    /// Casas decimais da saída; acima de `MAXIMO_CASAS` valem como `MAXIMO_CASAS`
+    pub casas: usize,
+}
+
+/// Linha que não pôde ser convertida; a linha fica fora da saída
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErroLinha {
+    pub linha: usize,
+    pub mensagem: String,
+}
+
+impl fmt::Display for ErroLinha {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "linha {}: {}", self.linha, self.mensagem)
+    }
+}
+
+/// Resultado de um lote que chegou ao fim
+#[derive(Debug, Default)]
+pub struct ResumoLote {
+    pub convertidas: usize,
+    pub erros: Vec<ErroLinha>,
+}
+
+/// Erros que impedem o lote inteiro (os de cada linha vão para `ResumoLote`)
+#[derive(Debug)]
+pub enum ErroLote {
+    Io(io::Error),
+    SemCabecalho,
+    ColunaAusente(String),
+}
+
+impl fmt::Display for ErroLote {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErroLote::Io(erro) => write!(f, "Erro de leitura/escrita: {}", erro),
+            ErroLote::SemCabecalho => write!(f, "Entrada CSV vazia, sem cabeçalho"),
+            ErroLote::ColunaAusente(coluna) => write!(f, "Coluna '{}' não existe no cabeçalho", coluna),
+        }
+    }
+}
+
+impl Error for ErroLote {}
+
+impl From<io::Error> for ErroLote {
+    fn from(erro: io::Error) -> Self {
+        ErroLote::Io(erro)
+    }
+}
+
+/// Lê `entrada` linha a linha, converte a coluna escolhida e escreve em `saida`.
+/// Linhas com problema são puladas e anotadas no resumo com o número da linha
+pub fn converter_lote<R: BufRead, W: Write>(
+    entrada: R,
+    mut saida: W,
+    opcoes: &OpcoesLote,
+) -> Result<ResumoLote, ErroLote> {
+    let mut resumo = ResumoLote::default();
+    let mut linhas = entrada.lines().enumerate().map(|(i, linha)| (i + 1, linha));
+
+    // No CSV a primeira linha diz em que posição está a coluna
+    let leitura = match opcoes.formato {
+        FormatoLote::Csv => {
+            let (_, cabecalho) = linhas.next().ok_or(ErroLote::SemCabecalho)?;
+            let cabecalho = cabecalho?;
+            let campos = dividir_csv(&cabecalho).map_err(|_| ErroLote::SemCabecalho)?;
+            let indice = campos
+                .iter()
+                .position(|campo| campo.trim() == opcoes.coluna)
+                .ok_or_else(|| ErroLote::ColunaAusente(opcoes.coluna.clone()))?;
+            writeln!(saida, "{}", cabecalho)?;
+            Leitura::Csv { indice, total: campos.len() }
+        }
+        #[cfg(feature = "jsonl")]
+        FormatoLote::JsonLinhas => Leitura::JsonLinhas,
+    };
+
+    for (numero, linha) in linhas {
+        let linha = linha?;
+        if linha.trim().is_empty() {
+            continue;
+        }
+
+        let convertida = match leitura {
+            Leitura::Csv { indice, total } => converter_csv(&linha, indice, total, opcoes),
+            #[cfg(feature = "jsonl")]
+            Leitura::JsonLinhas => converter_json(&linha, opcoes),
+        };
+        match convertida {
+            Ok(texto) => {
+                writeln!(saida, "{}", texto)?;
+                resumo.convertidas += 1;
+            }
+            Err(mensagem) => resumo.erros.push(ErroLinha { linha: numero, mensagem }),
+        }
+    }
+
+    Ok(resumo)
+}
+
+/// Onde achar a coluna em cada linha do lote
+#[derive(Clone, Copy)]
+enum Leitura {
+    /// Posição da coluna e quantos campos o cabeçalho tem
+    Csv { indice: usize, total: usize },
+    #[cfg(feature = "jsonl")]
+    JsonLinhas,
+}
+
+/// Lê a célula, aceitando número puro quando há escala padrão, e converte
+fn converter_celula(texto: &str, opcoes: &OpcoesLote) -> Result<f64, String> {
+    let temperatura = match (texto.parse::<Temperatura>(), opcoes.escala_padrao) {
+        (Ok(temperatura), _) => temperatura,
+        (Err(ErroLeituraTemperatura::SemEscala), Some(escala)) => {
+            let valor: f64 = texto.trim().parse().map_err(|_| format!("Número inválido '{}'", texto.trim()))?;
+            Temperatura::try_new(valor, escala).map_err(|e| e.to_string())?
+        }
+        (Err(erro), _) => return Err(erro.to_string()),
+    };
+    let convertida = temperatura.converter_para(opcoes.destino).map_err(|e| e.to_string())?;
+    Ok(arredondar(convertida.valor, opcoes.casas))
+}
+
+/// Arredonda para `casas` decimais. Se a escala estourar (valores enormes), o
+/// valor já não tem casas para arredondar e volta como está
+fn arredondar(valor: f64, casas: usize) -> f64 {
+    let escala = 10f64.powi(casas.min(MAXIMO_CASAS) as i32);
+    let arredondado = (valor * escala).round() / escala + 0.0;
+    if arredondado.is_finite() { arredondado } else { valor }
+}
+
+// ========================================
+// CSV
+// ========================================
+
+fn converter_csv(linha: &str, indice: usize, total: usize, opcoes: &OpcoesLote) -> Result<String, String> {
+    let mut campos = dividir_csv(linha)?;
+    if campos.len() != total {
+        return Err(format!("esperava {} campos, encontrou {}", total, campos.len()));
+    }
+
+    let valor = converter_celula(&campos[indice], opcoes)?;
+    campos[indice] = format!("{:.*}", opcoes.casas.min(MAXIMO_CASAS), valor);
+    Ok(campos.iter().map(|campo| escapar_csv(campo)).collect::<Vec<_>>().join(","))
+}
+
+/// Coloca aspas no campo se ele tiver separadores ou aspas
+fn escapar_csv(campo: &str) -> String {
+    if campo.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", campo.replace('"', "\"\""))
+    } else {
+        campo.to_string()
+    }
+}
+
+/// Divide uma linha CSV respeitando campos entre aspas
+fn dividir_csv(linha: &str) -> Result<Vec<String>, String> {
+    let mut campos = Vec::new();
+    let mut atual = String::new();
+    let mut entre_aspas = false;
+    let mut caracteres = linha.chars().peekable();
+
+    while let Some(c) = caracteres.next() {
+        match (c, entre_aspas) {
+            ('"', true) if caracteres.peek() == Some(&'"') => {
+                atual.push('"');
+                caracteres.next();
+            }
+            ('"', true) => entre_aspas = false,
+            ('"', false) if atual.is_empty() => entre_aspas = true,
+            (',', false) => campos.push(std::mem::take(&mut atual)),
+            _ => atual.push(c),
+        }
+    }
+
+    if entre_aspas {
+        return Err(String::from("aspas não fechadas"));
+    }
+    campos.push(atual);
+    Ok(campos)
+}
+
+// ========================================
+// JSON-LINES
+// ========================================
+
+#[cfg(feature = "jsonl")]
+fn converter_json(linha: &str, opcoes: &OpcoesLote) -> Result<String, String> {
+    let mut objeto: Map<String, Value> = match serde_json::from_str(linha) {
+        Ok(Value::Object(objeto)) => objeto,
+        Ok(_) => return Err(String::from("esperava um objeto JSON")),
+        Err(erro) => return Err(format!("JSON inválido: {}", erro)),
+    };
+
+    let texto = match objeto.get(&opcoes.coluna) {
+        Some(Value::String(texto)) => texto.clone(),
+        Some(Value::Number(numero)) => numero.to_string(),
+        Some(_) => return Err(format!("'{}' não é texto nem número", opcoes.coluna)),
+        None => return Err(format!("falta a chave '{}'", opcoes.coluna)),
+    };
+
+    let valor = converter_celula(&texto, opcoes)?;
+    let numero = Number::from_f64(valor).ok_or_else(|| format!("valor não representável: {}", valor))?;
+    objeto.insert(opcoes.coluna.clone(), Value::Number(numero));
+    serde_json::to_string(&objeto).map_err(|e| e.to_string())
+}
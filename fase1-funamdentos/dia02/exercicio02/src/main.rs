@@ -1,87 +1,299 @@
-use exercicio02::{EscalaTemperatura, Temperatura};
-use std::io;
+use exercicio02::{
+    EscalaTemperatura, FormatoLote, FormatoTabela, MAXIMO_CASAS, OpcoesLote, Tabela, Temperatura, converter_lote,
+    interpretar_conversao,
+};
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process::ExitCode;
+
+const USO_LOTE: &str = "Uso: exercicio02 lote --coluna NOME --para ESCALA [--de ESCALA] [--formato csv|jsonl] [--casas N] [ARQUIVO]
+  Sem ARQUIVO (ou com -), lê da entrada padrão. A saída convertida vai para a saída padrão
+  e as linhas com erro são listadas na saída de erro, com o número da linha.";
+
+const USO_TABELA: &str = "Uso: exercicio02 tabela --de INICIO --ate FIM [--passo P] [--escala ESCALA]
+                        [--para ESCALA,ESCALA,...] [--formato texto|md|csv|html] [--casas N]
+  Ex.: exercicio02 tabela --de -40 --ate 120 --passo 5 --escala C --para F,K --formato md";
+
+fn main() -> ExitCode {
+    // Com argumentos, converte a linha livre e sai: `exercicio02 100F to K`
+    let argumentos: Vec<String> = env::args().skip(1).collect();
+    match argumentos.first().map(String::as_str) {
+        Some("lote") => return executar_lote(&argumentos[1..]),
+        Some("tabela") => return executar_tabela(&argumentos[1..]),
+        _ => {}
+    }
+    if !argumentos.is_empty() {
+        let conversao = interpretar_conversao(&argumentos.join(" "))
+            .and_then(|(origem, destino)| Ok((origem, origem.converter_para(destino)?)));
+        return match conversao {
+            Ok((origem, destino)) => {
+                println!("{} = {}", origem, destino);
+                ExitCode::SUCCESS
+            }
+            Err(erro) => {
+                eprintln!("❌ {}", erro);
+                ExitCode::FAILURE
+            }
+        };
+    }
 
-fn main() {
     println!("╔════════════════════════════════════════╗");
     println!("║  CONVERSOR DE TEMPERATURA - RUST 🦀   ║");
     println!("╚════════════════════════════════════════╝\n");
 
     loop {
-        // Mostra menu
-        exibir_menu();
+        // Mostra menu e lê a escala de origem (ou uma conversão livre)
+        exibir_menu("ESCALA DE ORIGEM:");
+        println!("Ou digite uma conversão livre, ex.: 100F to K\n");
+        let escala_origem = match ler_opcao() {
+            Opcao::Sair => {
+                println!("\n👋 Até logo!");
+                break;
+            }
+            Opcao::Escala(escala) => escala,
+            Opcao::Livre(linha) => {
+                match interpretar_conversao(&linha) {
+                    Ok((origem, destino)) => exibir_resultado(&origem, destino),
+                    Err(erro) => println!("\n❌ {}\n", erro),
+                }
+                continue;
+            }
+        };
 
-        // Lê opção
-        let opcao = ler_opcao();
+        // Lê temperatura
+        print!("Digite a temperatura: ");
+        io::Write::flush(&mut io::stdout()).unwrap();
+        let valor = ler_numero();
 
-        if opcao == 0 {
+        // Lê a escala de destino
+        exibir_menu("ESCALA DE DESTINO:");
+        let escala_destino = loop {
+            match ler_opcao() {
+                Opcao::Escala(escala) => break Some(escala),
+                Opcao::Sair => break None,
+                Opcao::Livre(_) => println!("\n❌ Opção inválida! Tente novamente.\n"),
+            }
+        };
+        let Some(escala_destino) = escala_destino else {
             println!("\n👋 Até logo!");
             break;
+        };
+
+        match Temperatura::try_new(valor, escala_origem) {
+            Ok(temp_origem) => exibir_resultado(&temp_origem, escala_destino),
+            Err(erro) => println!("\n❌ {}\n", erro),
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Modo lote: converte uma coluna de um CSV ou JSON-lines inteiro
+fn executar_lote(argumentos: &[String]) -> ExitCode {
+    let (opcoes, arquivo) = match analisar_opcoes_lote(argumentos) {
+        Ok(analisadas) => analisadas,
+        Err(mensagem) => {
+            eprintln!("❌ {}\n{}", mensagem, USO_LOTE);
+            return ExitCode::from(2);
         }
+    };
 
-        // Valida opção
-        if opcao < 1 || opcao > 6 {
-            println!("\n❌ Opção inválida! Tente novamente.\n");
-            continue;
+    let saida = io::stdout().lock();
+    let resultado = match arquivo.as_deref() {
+        None | Some("-") => converter_lote(io::stdin().lock(), saida, &opcoes),
+        Some(caminho) => match File::open(caminho) {
+            Ok(arquivo) => converter_lote(BufReader::new(arquivo), saida, &opcoes),
+            Err(erro) => {
+                eprintln!("❌ Não foi possível abrir '{}': {}", caminho, erro);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    match resultado {
+        Ok(resumo) => {
+            for erro in &resumo.erros {
+                eprintln!("⚠️  {}", erro);
+            }
+            eprintln!("✓ {} linha(s) convertida(s), {} com erro", resumo.convertidas, resumo.erros.len());
+            if resumo.erros.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+        }
+        Err(erro) => {
+            eprintln!("❌ {}", erro);
+            ExitCode::FAILURE
         }
+    }
+}
 
-        // Lê temperatura
-        print!("Digite a temperatura: ");
-        io::Write::flush(&mut io::stdout()).unwrap();
-        let valor = ler_numero();
+fn analisar_opcoes_lote(argumentos: &[String]) -> Result<(OpcoesLote, Option<String>), String> {
+    let mut coluna = None;
+    let mut destino = None;
+    let mut escala_padrao = None;
+    let mut formato = None;
+    let mut casas = 2;
+    let mut arquivo = None;
 
-        // Determina escalas de origem e destino
-        let (escala_origem, escala_destino) = match opcao {
-            1 => (EscalaTemperatura::Celsius, EscalaTemperatura::Fahrenheit),
-            2 => (EscalaTemperatura::Celsius, EscalaTemperatura::Kelvin),
-            3 => (EscalaTemperatura::Fahrenheit, EscalaTemperatura::Celsius),
-            4 => (EscalaTemperatura::Fahrenheit, EscalaTemperatura::Kelvin),
-            5 => (EscalaTemperatura::Kelvin, EscalaTemperatura::Celsius),
-            6 => (EscalaTemperatura::Kelvin, EscalaTemperatura::Fahrenheit),
-            _ => unreachable!(), // Nunca vai chegar aqui
-        };
+    let mut argumentos = argumentos.iter();
+    while let Some(argumento) = argumentos.next() {
+        let mut valor = || argumentos.next().ok_or_else(|| format!("Faltou o valor de {}", argumento));
+        match argumento.as_str() {
+            "--coluna" => coluna = Some(valor()?.clone()),
+            "--para" => destino = Some(valor()?.parse::<EscalaTemperatura>().map_err(|e| e.to_string())?),
+            "--de" => escala_padrao = Some(valor()?.parse::<EscalaTemperatura>().map_err(|e| e.to_string())?),
+            "--formato" => {
+                formato = Some(match valor()?.as_str() {
+                    "csv" => FormatoLote::Csv,
+                    #[cfg(feature = "jsonl")]
+                    "jsonl" | "json" => FormatoLote::JsonLinhas,
+                    outro => return Err(format!("Formato desconhecido '{}'", outro)),
+                })
+            }
+            "--casas" => casas = ler_casas(valor()?)?,
+            opcao if opcao.starts_with("--") => return Err(format!("Opção desconhecida '{}'", opcao)),
+            caminho if arquivo.is_none() => arquivo = Some(caminho.to_string()),
+            extra => return Err(format!("Argumento inesperado '{}'", extra)),
+        }
+    }
 
-        // Cria temperatura e converte
-        let temp_origem = Temperatura::new(valor, escala_origem);
-        let temp_destino = temp_origem.converter_para(escala_destino);
+    let opcoes = OpcoesLote {
+        formato: formato.unwrap_or_else(|| FormatoLote::pela_extensao(arquivo.as_deref().unwrap_or(""))),
+        coluna: coluna.ok_or("Faltou --coluna")?,
+        destino: destino.ok_or("Faltou --para")?,
+        escala_padrao,
+        casas,
+    };
+    Ok((opcoes, arquivo))
+}
+
+/// Valor de `--casas`: de 0 a `MAXIMO_CASAS`, mais que isso um f64 não tem
+fn ler_casas(texto: &str) -> Result<usize, String> {
+    match texto.parse::<usize>() {
+        Ok(casas) if casas <= MAXIMO_CASAS => Ok(casas),
+        _ => Err(format!("--casas espera um número de 0 a {}", MAXIMO_CASAS)),
+    }
+}
 
-        // Exibe resultado
-        println!("\n┌─────────────────────────────┐");
-        println!("│ RESULTADO DA CONVERSÃO      │");
-        println!("├─────────────────────────────┤");
-        println!("│ Origem:  {}  │", temp_origem);
-        println!("│ Destino: {}  │", temp_destino);
-        println!("└─────────────────────────────┘\n");
+/// Gera uma tabela de conversão de um intervalo
+fn executar_tabela(argumentos: &[String]) -> ExitCode {
+    match analisar_opcoes_tabela(argumentos) {
+        Ok((tabela, formato)) => {
+            print!("{}", tabela.gerar(formato));
+            ExitCode::SUCCESS
+        }
+        Err(mensagem) => {
+            eprintln!("❌ {}\n{}", mensagem, USO_TABELA);
+            ExitCode::from(2)
+        }
+    }
+}
 
-        // Mostra tabela de comparação
-        exibir_tabela_comparacao(&temp_origem);
+fn analisar_opcoes_tabela(argumentos: &[String]) -> Result<(Tabela, FormatoTabela), String> {
+    let mut inicio = None;
+    let mut fim = None;
+    let mut passo = 1.0;
+    let mut origem = EscalaTemperatura::Celsius;
+    let mut escalas = EscalaTemperatura::TODAS.to_vec();
+    let mut formato = FormatoTabela::Texto;
+    let mut casas = 2;
 
-        println!();
+    let numero = |texto: &String| texto.parse::<f64>().map_err(|_| format!("Número inválido '{}'", texto));
+    let mut argumentos = argumentos.iter();
+    while let Some(argumento) = argumentos.next() {
+        let mut valor = || argumentos.next().ok_or_else(|| format!("Faltou o valor de {}", argumento));
+        match argumento.as_str() {
+            "--de" => inicio = Some(numero(valor()?)?),
+            "--ate" => fim = Some(numero(valor()?)?),
+            "--passo" => passo = numero(valor()?)?,
+            "--escala" => origem = valor()?.parse::<EscalaTemperatura>().map_err(|e| e.to_string())?,
+            "--para" => {
+                escalas = valor()?
+                    .split(',')
+                    .map(|nome| nome.parse::<EscalaTemperatura>().map_err(|e| e.to_string()))
+                    .collect::<Result<_, _>>()?
+            }
+            "--formato" => {
+                let nome = valor()?;
+                formato = FormatoTabela::de_nome(nome).ok_or_else(|| format!("Formato desconhecido '{}'", nome))?
+            }
+            "--casas" => casas = ler_casas(valor()?)?,
+            outro => return Err(format!("Argumento inesperado '{}'", outro)),
+        }
     }
+
+    let inicio = inicio.ok_or("Faltou --de")?;
+    let fim = fim.ok_or("Faltou --ate")?;
+    let tabela = Tabela::new(origem, inicio, fim, passo).map_err(|e| e.to_string())?;
+    Ok((tabela.com_escalas(&escalas).com_casas(casas), formato))
+}
+
+/// Converte, exibe o resultado e a tabela de comparação
+fn exibir_resultado(temp_origem: &Temperatura, escala_destino: EscalaTemperatura) {
+    let temp_destino = match temp_origem.converter_para(escala_destino) {
+        Ok(temp_destino) => temp_destino,
+        Err(erro) => {
+            println!("\n❌ {}\n", erro);
+            return;
+        }
+    };
+
+    println!("\n┌─────────────────────────────┐");
+    println!("│ RESULTADO DA CONVERSÃO      │");
+    println!("├─────────────────────────────┤");
+    println!("│ Origem:  {}  │", temp_origem);
+    println!("│ Destino: {}  │", temp_destino);
+    println!("└─────────────────────────────┘\n");
+
+    // Mostra tabela de comparação
+    exibir_tabela_comparacao(temp_origem);
+
+    println!();
 }
 
-/// Exibe menu de opções
-fn exibir_menu() {
-    println!("CONVERSÕES DISPONÍVEIS:");
-    println!("  1. Celsius → Fahrenheit");
-    println!("  2. Celsius → Kelvin");
-    println!("  3. Fahrenheit → Celsius");
-    println!("  4. Fahrenheit → Kelvin");
-    println!("  5. Kelvin → Celsius");
-    println!("  6. Kelvin → Fahrenheit");
+/// Exibe menu de escalas
+fn exibir_menu(titulo: &str) {
+    println!("{}", titulo);
+    for (i, escala) in EscalaTemperatura::TODAS.iter().enumerate() {
+        println!("  {}. {}", i + 1, escala);
+    }
     println!("  0. Sair\n");
 }
 
-/// Lê opção do menu
-fn ler_opcao() -> u8 {
-    print!("Escolha uma opção: ");
-    io::Write::flush(&mut io::stdout()).unwrap();
+/// O que o usuário digitou no menu
+enum Opcao {
+    Sair,
+    Escala(EscalaTemperatura),
+    /// Qualquer texto que não é número do menu
+    Livre(String),
+}
 
-    let mut entrada = String::new();
-    io::stdin()
-        .read_line(&mut entrada)
-        .expect("Falha ao ler entrada");
+/// Lê opção do menu; números fora da lista pedem de novo
+fn ler_opcao() -> Opcao {
+    loop {
+        print!("Escolha uma opção: ");
+        io::Write::flush(&mut io::stdout()).unwrap();
+
+        let mut entrada = String::new();
+        let lidos = io::stdin()
+            .read_line(&mut entrada)
+            .expect("Falha ao ler entrada");
+
+        // Fim da entrada conta como sair
+        if lidos == 0 {
+            return Opcao::Sair;
+        }
 
-    entrada.trim().parse().unwrap_or(99)
+        let entrada = entrada.trim();
+        match entrada.parse::<usize>() {
+            Ok(0) => return Opcao::Sair,
+            Ok(opcao) if opcao <= EscalaTemperatura::TODAS.len() => {
+                return Opcao::Escala(EscalaTemperatura::TODAS[opcao - 1]);
+            }
+            Ok(_) => println!("\n❌ Opção inválida! Tente novamente.\n"),
+            Err(_) if entrada.is_empty() => println!("\n❌ Opção inválida! Tente novamente.\n"),
+            Err(_) => return Opcao::Livre(entrada.to_string()),
+        }
+    }
 }
 
 /// Lê número do usuário
@@ -100,8 +312,9 @@ fn exibir_tabela_comparacao(temp: &Temperatura) {
     println!("┌────────────────┬──────────────┐");
     println!("│ Escala         │ Valor        │");
     println!("├────────────────┼──────────────┤");
-    println!("│ Celsius        │ {:>8.2}°C  │", temp.para_celsius());
-    println!("│ Fahrenheit     │ {:>8.2}°F  │", temp.para_fahrenheit());
-    println!("│ Kelvin         │ {:>8.2}K   │", temp.para_kelvin());
+    for escala in EscalaTemperatura::TODAS {
+        let valor = escala.de_kelvin(temp.para_kelvin());
+        println!("│ {:<14} │ {:>8.2}{:<4}│", escala.nome(), valor, escala.simbolo());
+    }
     println!("└────────────────┴──────────────┘");
 }
@@ -0,0 +1,188 @@
+use crate::unidade::{Conversao, ErroConversao, Grandeza, Unidade};
+use crate::EscalaTemperatura;
+
+/// Unidade definida em tempo de execução; é o que o registro guarda
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnidadeRegistrada {
+    simbolo: String,
+    nome: String,
+    grandeza: Grandeza,
+    conversao: Conversao,
+}
+
+impl UnidadeRegistrada {
+    /// Unidade proporcional à referência: `referencia = valor * fator`.
+    /// O fator precisa ser finito e diferente de zero
+    pub fn linear(simbolo: &str, nome: &str, grandeza: Grandeza, fator: f64) -> Result<Self, ErroConversao> {
+        UnidadeRegistrada::nova(simbolo, nome, grandeza, Conversao::Linear(fator))
+    }
+
+    /// Unidade com zero próprio: `referencia = valor * fator + deslocamento`.
+    /// O fator precisa ser finito e diferente de zero, e o deslocamento finito
+    pub fn afim(
+        simbolo: &str,
+        nome: &str,
+        grandeza: Grandeza,
+        fator: f64,
+        deslocamento: f64,
+    ) -> Result<Self, ErroConversao> {
+        UnidadeRegistrada::nova(simbolo, nome, grandeza, Conversao::Afim { fator, deslocamento })
+    }
+
+    fn nova(simbolo: &str, nome: &str, grandeza: Grandeza, conversao: Conversao) -> Result<Self, ErroConversao> {
+        let fator = conversao.fator();
+        if fator == 0.0 || !fator.is_finite() {
+            return Err(ErroConversao::FatorInvalido { simbolo: simbolo.to_string(), fator });
+        }
+        let deslocamento = match conversao {
+            Conversao::Afim { deslocamento, .. } => deslocamento,
+            Conversao::Linear(_) => 0.0,
+        };
+        if !deslocamento.is_finite() {
+            return Err(ErroConversao::DeslocamentoInvalido { simbolo: simbolo.to_string(), deslocamento });
+        }
+
+        Ok(UnidadeRegistrada { simbolo: simbolo.to_string(), nome: nome.to_string(), grandeza, conversao })
+    }
+
+    /// Copia qualquer outra unidade (ex.: uma `EscalaTemperatura`) para o registro
+    pub fn de<U: Unidade + ?Sized>(unidade: &U) -> Self {
+        UnidadeRegistrada {
+            simbolo: unidade.simbolo().to_string(),
+            nome: unidade.nome().to_string(),
+            grandeza: unidade.grandeza(),
+            conversao: unidade.conversao(),
+        }
+    }
+}
+
+impl Unidade for UnidadeRegistrada {
+    fn simbolo(&self) -> &str {
+        &self.simbolo
+    }
+
+    fn nome(&self) -> &str {
+        &self.nome
+    }
+
+    fn grandeza(&self) -> Grandeza {
+        self.grandeza
+    }
+
+    fn conversao(&self) -> Conversao {
+        self.conversao
+    }
+}
+
+/// Catálogo de unidades por símbolo. `padrao()` traz as unidades comuns;
+/// outros crates acrescentam as suas com `registrar`
+#[derive(Debug, Clone, Default)]
+pub struct RegistroUnidades {
+    unidades: Vec<UnidadeRegistrada>,
+}
+
+impl RegistroUnidades {
+    /// Registro vazio
+    pub fn new() -> Self {
+        RegistroUnidades::default()
+    }
+
+    /// Registro com as unidades de todas as grandezas conhecidas.
+    /// Referências: m, kg, m³, Pa, J, m/s, B e K
+    pub fn padrao() -> Self {
+        use Grandeza::*;
+
+        let lineares: [(&str, &str, Grandeza, f64); 47] = [
+            ("m", "metro", Comprimento, 1.0),
+            ("km", "quilômetro", Comprimento, 1000.0),
+            ("cm", "centímetro", Comprimento, 0.01),
+            ("mm", "milímetro", Comprimento, 0.001),
+            ("mi", "milha", Comprimento, 1609.344),
+            ("yd", "jarda", Comprimento, 0.9144),
+            ("ft", "pé", Comprimento, 0.3048),
+            ("in", "polegada", Comprimento, 0.0254),
+            ("nmi", "milha náutica", Comprimento, 1852.0),
+            ("kg", "quilograma", Massa, 1.0),
+            ("g", "grama", Massa, 0.001),
+            ("mg", "miligrama", Massa, 1e-6),
+            ("t", "tonelada", Massa, 1000.0),
+            ("lb", "libra", Massa, 0.45359237),
+            ("oz", "onça", Massa, 0.028349523125),
+            ("m³", "metro cúbico", Volume, 1.0),
+            ("L", "litro", Volume, 0.001),
+            ("mL", "mililitro", Volume, 1e-6),
+            ("gal", "galão americano", Volume, 0.003785411784),
+            ("ft³", "pé cúbico", Volume, 0.028316846592),
+            ("Pa", "pascal", Pressao, 1.0),
+            ("kPa", "quilopascal", Pressao, 1000.0),
+            ("bar", "bar", Pressao, 100_000.0),
+            ("atm", "atmosfera", Pressao, 101_325.0),
+            ("psi", "libra por polegada quadrada", Pressao, 6894.757293168),
+            ("mmHg", "milímetro de mercúrio", Pressao, 133.322387415),
+            ("J", "joule", Energia, 1.0),
+            ("kJ", "quilojoule", Energia, 1000.0),
+            ("cal", "caloria", Energia, 4.184),
+            ("kcal", "quilocaloria", Energia, 4184.0),
+            ("Wh", "watt-hora", Energia, 3600.0),
+            ("kWh", "quilowatt-hora", Energia, 3.6e6),
+            ("eV", "elétron-volt", Energia, 1.602176634e-19),
+            ("m/s", "metro por segundo", Velocidade, 1.0),
+            ("km/h", "quilômetro por hora", Velocidade, 1.0 / 3.6),
+            ("mph", "milha por hora", Velocidade, 0.44704),
+            ("kn", "nó", Velocidade, 1852.0 / 3600.0),
+            ("ft/s", "pé por segundo", Velocidade, 0.3048),
+            ("B", "byte", Dados, 1.0),
+            ("bit", "bit", Dados, 0.125),
+            ("kB", "quilobyte", Dados, 1e3),
+            ("MB", "megabyte", Dados, 1e6),
+            ("GB", "gigabyte", Dados, 1e9),
+            ("TB", "terabyte", Dados, 1e12),
+            ("KiB", "kibibyte", Dados, 1024.0),
+            ("MiB", "mebibyte", Dados, 1_048_576.0),
+            ("GiB", "gibibyte", Dados, 1_073_741_824.0),
+        ];
+
+        let mut registro = RegistroUnidades::new();
+        let unidades = lineares
+            .iter()
+            .map(|&(simbolo, nome, grandeza, fator)| {
+                UnidadeRegistrada::linear(simbolo, nome, grandeza, fator).expect("fatores do catálogo são válidos")
+            })
+            .chain(EscalaTemperatura::TODAS.iter().map(UnidadeRegistrada::de));
+        for unidade in unidades {
+            registro.registrar(unidade).expect("catálogo padrão não repete símbolos");
+        }
+        registro
+    }
+
+    /// Acrescenta uma unidade; símbolos repetidos são recusados
+    pub fn registrar(&mut self, unidade: UnidadeRegistrada) -> Result<(), ErroConversao> {
+        if self.buscar(unidade.simbolo()).is_some() {
+            return Err(ErroConversao::SimboloDuplicado(unidade.simbolo));
+        }
+
+        self.unidades.push(unidade);
+        Ok(())
+    }
+
+    pub fn buscar(&self, simbolo: &str) -> Option<&UnidadeRegistrada> {
+        self.unidades.iter().find(|u| u.simbolo() == simbolo)
+    }
+
+    /// Unidades de uma grandeza, na ordem em que foram registradas
+    pub fn da_grandeza(&self, grandeza: Grandeza) -> impl Iterator<Item = &UnidadeRegistrada> {
+        self.unidades.iter().filter(move |u| u.grandeza() == grandeza)
+    }
+
+    /// Converte entre dois símbolos do registro
+    pub fn converter(&self, valor: f64, origem: &str, destino: &str) -> Result<f64, ErroConversao> {
+        let origem = self.unidade(origem)?;
+        let destino = self.unidade(destino)?;
+        origem.converter(valor, destino)
+    }
+
+    fn unidade(&self, simbolo: &str) -> Result<&UnidadeRegistrada, ErroConversao> {
+        self.buscar(simbolo)
+            .ok_or_else(|| ErroConversao::UnidadeDesconhecida(simbolo.to_string()))
+    }
+}
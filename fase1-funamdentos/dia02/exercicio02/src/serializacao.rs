@@ -0,0 +1,56 @@
+//! Formato de fio (feature `serde`). `Temperatura` serializa como
+//! `{"valor": 25.0, "escala": "celsius"}`; `compacto` troca por `"25C"`.
+//! A leitura aceita as duas formas e recusa temperaturas impossíveis.
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{EscalaTemperatura, ErroLeituraTemperatura, Temperatura};
+
+/// O que pode chegar do fio antes de virar `Temperatura`
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Forma {
+    Completa { valor: f64, escala: EscalaTemperatura },
+    Compacta(String),
+}
+
+impl TryFrom<Forma> for Temperatura {
+    type Error = ErroLeituraTemperatura;
+
+    fn try_from(forma: Forma) -> Result<Self, Self::Error> {
+        match forma {
+            Forma::Completa { valor, escala } => Ok(Temperatura::try_new(valor, escala)?),
+            Forma::Compacta(texto) => texto.parse(),
+        }
+    }
+}
+
+/// Símbolo só com ASCII, que `FromStr` lê de volta
+fn simbolo_compacto(escala: EscalaTemperatura) -> &'static str {
+    match escala {
+        EscalaTemperatura::Celsius => "C",
+        EscalaTemperatura::Fahrenheit => "F",
+        EscalaTemperatura::Kelvin => "K",
+        EscalaTemperatura::Rankine => "R",
+        EscalaTemperatura::Reaumur => "Re",
+        EscalaTemperatura::Delisle => "De",
+        EscalaTemperatura::Newton => "N",
+        EscalaTemperatura::Romer => "Ro",
+    }
+}
+
+/// Forma compacta, para usar com `#[serde(with = "exercicio02::compacto")]`
+pub mod compacto {
+    use super::*;
+
+    /// `Temperatura::new(25.0, Celsius)` vira `"25C"`
+    pub fn serialize<S: Serializer>(temperatura: &Temperatura, serializer: S) -> Result<S::Ok, S::Error> {
+        let texto = format!("{}{}", temperatura.valor, simbolo_compacto(temperatura.escala));
+        serializer.serialize_str(&texto)
+    }
+
+    /// Aceita a forma compacta e também a completa
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Temperatura, D::Error> {
+        Temperatura::deserialize(deserializer)
+    }
+}
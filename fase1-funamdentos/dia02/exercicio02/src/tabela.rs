@@ -0,0 +1,198 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{EscalaTemperatura, MAXIMO_CASAS, Temperatura};
+
+/// Limite de linhas, para um passo pequeno demais não gerar uma tabela gigante
+pub const MAXIMO_LINHAS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FormatoTabela {
+    /// Caixa com bordas, alinhada à direita, como a tabela do menu
+    Texto,
+    Markdown,
+    Csv,
+    Html,
+}
+
+impl FormatoTabela {
+    pub fn de_nome(nome: &str) -> Option<FormatoTabela> {
+        match nome {
+            "texto" | "text" | "txt" => Some(FormatoTabela::Texto),
+            "md" | "markdown" => Some(FormatoTabela::Markdown),
+            "csv" => Some(FormatoTabela::Csv),
+            "html" => Some(FormatoTabela::Html),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErroTabela {
+    /// Início, fim ou passo não finitos, ou início depois do fim
+    IntervaloInvalido,
+    PassoInvalido(f64),
+    LinhasDemais(usize),
+}
+
+impl fmt::Display for ErroTabela {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErroTabela::IntervaloInvalido => write!(f, "Intervalo inválido: o início deve ser finito e não maior que o fim"),
+            ErroTabela::PassoInvalido(passo) => write!(f, "Passo inválido: {} (deve ser positivo)", passo),
+            ErroTabela::LinhasDemais(linhas) => {
+                write!(f, "A tabela teria {} linhas (máximo {})", linhas, MAXIMO_LINHAS)
+            }
+        }
+    }
+}
+
+impl Error for ErroTabela {}
+
+/// Tabela de conversão de um intervalo, ex.: -40 a 120 °C de 5 em 5.
+/// A primeira coluna é sempre a escala de origem
+#[derive(Debug, Clone)]
+pub struct Tabela {
+    origem: EscalaTemperatura,
+    inicio: f64,
+    fim: f64,
+    passo: f64,
+    escalas: Vec<EscalaTemperatura>,
+    casas: usize,
+}
+
+impl Tabela {
+    /// Tabela de `inicio` a `fim` (inclusive) na escala `origem`, em todas as escalas
+    pub fn new(origem: EscalaTemperatura, inicio: f64, fim: f64, passo: f64) -> Result<Tabela, ErroTabela> {
+        if !(inicio.is_finite() && fim.is_finite()) || inicio > fim {
+            return Err(ErroTabela::IntervaloInvalido);
+        }
+        if !passo.is_finite() || passo <= 0.0 {
+            return Err(ErroTabela::PassoInvalido(passo));
+        }
+        let linhas = ((fim - inicio) / passo).floor() + 1.0;
+        if linhas > MAXIMO_LINHAS as f64 {
+            return Err(ErroTabela::LinhasDemais(linhas as usize));
+        }
+
+        Ok(Tabela { origem, inicio, fim, passo, escalas: EscalaTemperatura::TODAS.to_vec(), casas: 2 })
+    }
+
+    /// Escalas das colunas depois da origem (a origem não se repete)
+    pub fn com_escalas(mut self, escalas: &[EscalaTemperatura]) -> Self {
+        self.escalas = escalas.to_vec();
+        self
+    }
+
+    /// Casas decimais dos valores; acima de `MAXIMO_CASAS` valem como `MAXIMO_CASAS`
+    pub fn com_casas(mut self, casas: usize) -> Self {
+        self.casas = casas.min(MAXIMO_CASAS);
+        self
+    }
+
+    /// Escalas na ordem das colunas
+    pub fn colunas(&self) -> Vec<EscalaTemperatura> {
+        let mut colunas = vec![self.origem];
+        colunas.extend(self.escalas.iter().filter(|e| **e != self.origem));
+        colunas
+    }
+
+    /// Valores de cada linha, na ordem de `colunas()`. Pontos além do zero
+    /// absoluto ficam de fora
+    pub fn linhas(&self) -> Vec<Vec<f64>> {
+        let colunas = self.colunas();
+        // Multiplicar pelo índice evita acumular erro de soma; a folga inclui o fim
+        let folga = self.passo * 1e-9;
+        (0..)
+            .map(|i| self.inicio + i as f64 * self.passo)
+            .take_while(|valor| *valor <= self.fim + folga)
+            .filter_map(|valor| Temperatura::try_new(valor, self.origem).ok())
+            .map(|t| colunas.iter().map(|escala| escala.de_kelvin(t.para_kelvin())).collect())
+            .collect()
+    }
+
+    pub fn gerar(&self, formato: FormatoTabela) -> String {
+        let cabecalho: Vec<String> = self.colunas().iter().map(|e| e.to_string()).collect();
+        let linhas: Vec<Vec<String>> = self
+            .linhas()
+            .iter()
+            .map(|linha| linha.iter().map(|valor| format!("{:.*}", self.casas, valor)).collect())
+            .collect();
+
+        match formato {
+            FormatoTabela::Texto => gerar_texto(&cabecalho, &linhas),
+            FormatoTabela::Markdown => gerar_markdown(&cabecalho, &linhas),
+            FormatoTabela::Csv => gerar_csv(&cabecalho, &linhas),
+            FormatoTabela::Html => gerar_html(&cabecalho, &linhas),
+        }
+    }
+}
+
+// ========================================
+// FORMATOS
+// ========================================
+
+fn gerar_texto(cabecalho: &[String], linhas: &[Vec<String>]) -> String {
+    let larguras: Vec<usize> = cabecalho
+        .iter()
+        .enumerate()
+        .map(|(i, titulo)| {
+            linhas.iter().map(|linha| linha[i].chars().count()).chain([titulo.chars().count()]).max().unwrap_or(0)
+        })
+        .collect();
+    let borda = |esquerda: &str, meio: &str, direita: &str| {
+        let tracos: Vec<String> = larguras.iter().map(|l| "─".repeat(l + 2)).collect();
+        format!("{}{}{}\n", esquerda, tracos.join(meio), direita)
+    };
+    let linha = |celulas: &[String]| {
+        let celulas: Vec<String> = celulas
+            .iter()
+            .zip(&larguras)
+            .map(|(celula, largura)| format!(" {:>largura$} ", celula, largura = largura))
+            .collect();
+        format!("│{}│\n", celulas.join("│"))
+    };
+
+    let mut texto = borda("┌", "┬", "┐");
+    texto += &linha(cabecalho);
+    texto += &borda("├", "┼", "┤");
+    for celulas in linhas {
+        texto += &linha(celulas);
+    }
+    texto + &borda("└", "┴", "┘")
+}
+
+fn gerar_markdown(cabecalho: &[String], linhas: &[Vec<String>]) -> String {
+    let mut texto = format!("| {} |\n", cabecalho.join(" | "));
+    texto += &format!("|{}|\n", vec!["---:"; cabecalho.len()].join("|"));
+    for celulas in linhas {
+        texto += &format!("| {} |\n", celulas.join(" | "));
+    }
+    texto
+}
+
+fn gerar_csv(cabecalho: &[String], linhas: &[Vec<String>]) -> String {
+    let mut texto = format!("{}\n", cabecalho.join(","));
+    for celulas in linhas {
+        texto += &format!("{}\n", celulas.join(","));
+    }
+    texto
+}
+
+fn gerar_html(cabecalho: &[String], linhas: &[Vec<String>]) -> String {
+    let celulas = |tag: &str, celulas: &[String]| -> String {
+        celulas.iter().map(|c| format!("<{tag}>{}</{tag}>", escapar_html(c), tag = tag)).collect()
+    };
+
+    let mut texto = String::from("<table>\n  <thead>\n");
+    texto += &format!("    <tr>{}</tr>\n", celulas("th", cabecalho));
+    texto += "  </thead>\n  <tbody>\n";
+    for linha in linhas {
+        texto += &format!("    <tr>{}</tr>\n", celulas("td", linha));
+    }
+    texto + "  </tbody>\n</table>\n"
+}
+
+fn escapar_html(texto: &str) -> String {
+    texto.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
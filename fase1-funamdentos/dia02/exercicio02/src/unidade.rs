@@ -0,0 +1,214 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::TemperaturaError;
+
+/// Grandezas físicas que o conversor conhece. Outros crates criam as suas com
+/// `Personalizada` (ex.: `Grandeza::Personalizada("luminosidade")`) e registram
+/// unidades para elas em `RegistroUnidades`; o nome é a identidade da grandeza
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Grandeza {
+    Comprimento,
+    Massa,
+    Volume,
+    Pressao,
+    Energia,
+    Velocidade,
+    Dados,
+    Temperatura,
+    Personalizada(&'static str),
+}
+
+impl Grandeza {
+    /// As grandezas embutidas (sem as personalizadas)
+    pub const TODAS: [Grandeza; 8] = [
+        Grandeza::Comprimento,
+        Grandeza::Massa,
+        Grandeza::Volume,
+        Grandeza::Pressao,
+        Grandeza::Energia,
+        Grandeza::Velocidade,
+        Grandeza::Dados,
+        Grandeza::Temperatura,
+    ];
+}
+
+impl fmt::Display for Grandeza {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nome = match self {
+            Grandeza::Comprimento => "comprimento",
+            Grandeza::Massa => "massa",
+            Grandeza::Volume => "volume",
+            Grandeza::Pressao => "pressão",
+            Grandeza::Energia => "energia",
+            Grandeza::Velocidade => "velocidade",
+            Grandeza::Dados => "dados",
+            Grandeza::Temperatura => "temperatura",
+            Grandeza::Personalizada(nome) => nome,
+        };
+        write!(f, "{}", nome)
+    }
+}
+
+/// Como um valor nesta unidade vira um valor na unidade de referência da grandeza
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Conversao {
+    /// `referencia = valor * fator` (metro, quilograma, joule...)
+    Linear(f64),
+    /// `referencia = valor * fator + deslocamento`, para escalas com zero próprio
+    Afim { fator: f64, deslocamento: f64 },
+}
+
+impl Conversao {
+    /// Quanto vale um passo desta unidade na referência; é o que converte diferenças
+    pub fn fator(&self) -> f64 {
+        match *self {
+            Conversao::Linear(fator) | Conversao::Afim { fator, .. } => fator,
+        }
+    }
+
+    pub fn para_referencia(&self, valor: f64) -> f64 {
+        match *self {
+            Conversao::Linear(fator) => valor * fator,
+            Conversao::Afim { fator, deslocamento } => valor * fator + deslocamento,
+        }
+    }
+
+    pub fn de_referencia(&self, referencia: f64) -> f64 {
+        // `+ 0.0` troca o -0.0 de fatores negativos (Delisle) por 0.0
+        match *self {
+            Conversao::Linear(fator) => referencia / fator + 0.0,
+            Conversao::Afim { fator, deslocamento } => (referencia - deslocamento) / fator + 0.0,
+        }
+    }
+}
+
+/// Erros de conversão entre unidades
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErroConversao {
+    UnidadeDesconhecida(String),
+    GrandezasIncompativeis { origem: Grandeza, destino: Grandeza },
+    SimboloDuplicado(String),
+    /// Fator zero ou não finito: a conversão não teria volta
+    FatorInvalido { simbolo: String, fator: f64 },
+    /// Deslocamento NaN ou infinito
+    DeslocamentoInvalido { simbolo: String, deslocamento: f64 },
+    /// Valor que não existe na escala de origem (ex.: abaixo do zero absoluto)
+    Temperatura(TemperaturaError),
+}
+
+impl fmt::Display for ErroConversao {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErroConversao::UnidadeDesconhecida(simbolo) => write!(f, "Unidade desconhecida '{}'", simbolo),
+            ErroConversao::GrandezasIncompativeis { origem, destino } => {
+                write!(f, "Não é possível converter {} em {}", origem, destino)
+            }
+            ErroConversao::SimboloDuplicado(simbolo) => write!(f, "Já existe uma unidade com o símbolo '{}'", simbolo),
+            ErroConversao::FatorInvalido { simbolo, fator } => {
+                write!(f, "Fator inválido para '{}': {} (precisa ser finito e diferente de zero)", simbolo, fator)
+            }
+            ErroConversao::DeslocamentoInvalido { simbolo, deslocamento } => {
+                write!(f, "Deslocamento inválido para '{}': {} (precisa ser finito)", simbolo, deslocamento)
+            }
+            ErroConversao::Temperatura(erro) => write!(f, "{}", erro),
+        }
+    }
+}
+
+impl Error for ErroConversao {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ErroConversao::Temperatura(erro) => Some(erro),
+            _ => None,
+        }
+    }
+}
+
+impl From<TemperaturaError> for ErroConversao {
+    fn from(erro: TemperaturaError) -> Self {
+        ErroConversao::Temperatura(erro)
+    }
+}
+
+/// Uma unidade de medida: sabe a sua grandeza e como chegar à unidade de referência
+pub trait Unidade {
+    fn simbolo(&self) -> &str;
+    fn nome(&self) -> &str;
+    fn grandeza(&self) -> Grandeza;
+    fn conversao(&self) -> Conversao;
+
+    /// Recusa valores que não existem nesta unidade; por padrão, qualquer um serve
+    fn validar(&self, _valor: f64) -> Result<(), ErroConversao> {
+        Ok(())
+    }
+
+    /// Converte `valor` desta unidade para `destino`, passando pela referência
+    fn converter<D: Unidade + ?Sized>(&self, valor: f64, destino: &D) -> Result<f64, ErroConversao> {
+        if self.grandeza() != destino.grandeza() {
+            return Err(ErroConversao::GrandezasIncompativeis {
+                origem: self.grandeza(),
+                destino: destino.grandeza(),
+            });
+        }
+        self.validar(valor)?;
+
+        Ok(destino.conversao().de_referencia(self.conversao().para_referencia(valor)))
+    }
+}
+
+/// Permite usar `&UnidadeRegistrada` (ou qualquer referência) como unidade de uma `Medida`
+impl<U: Unidade + ?Sized> Unidade for &U {
+    fn simbolo(&self) -> &str {
+        (**self).simbolo()
+    }
+
+    fn nome(&self) -> &str {
+        (**self).nome()
+    }
+
+    fn grandeza(&self) -> Grandeza {
+        (**self).grandeza()
+    }
+
+    fn conversao(&self) -> Conversao {
+        (**self).conversao()
+    }
+
+    fn validar(&self, valor: f64) -> Result<(), ErroConversao> {
+        (**self).validar(valor)
+    }
+}
+
+/// Valor com unidade, com a mesma API de `Temperatura` para qualquer grandeza
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Medida<U: Unidade> {
+    pub valor: f64,
+    pub unidade: U,
+}
+
+impl<U: Unidade> Medida<U> {
+    /// Cria nova medida
+    pub fn new(valor: f64, unidade: U) -> Self {
+        Medida { valor, unidade }
+    }
+
+    /// Converte para outra unidade da mesma grandeza
+    pub fn converter_para(&self, destino: U) -> Result<Medida<U>, ErroConversao> {
+        let valor = self.unidade.converter(self.valor, &destino)?;
+        Ok(Medida::new(valor, destino))
+    }
+}
+
+/// Temperaturas ficam coladas ao número (25.00°C, 310.15K), como sempre foi em
+/// `Temperatura`, que exibe por aqui; as demais, separadas (1.50 km).
+/// `{:.4}` escolhe as casas
+impl<U: Unidade> fmt::Display for Medida<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let simbolo = self.unidade.simbolo();
+        let separador = if self.unidade.grandeza() == Grandeza::Temperatura { "" } else { " " };
+        let casas = f.precision().unwrap_or(2);
+        write!(f, "{:.*}{}{}", casas, self.valor, separador, simbolo)
+    }
+}
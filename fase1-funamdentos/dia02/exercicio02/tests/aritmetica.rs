@@ -0,0 +1,87 @@
+use exercicio02::{DiferencaTemperatura, EscalaTemperatura, QuaseIgual, Temperatura, assert_quase_igual};
+
+use EscalaTemperatura::{Celsius, Delisle, Fahrenheit, Kelvin};
+
+#[test]
+fn test_ponto_menos_ponto_e_diferenca() {
+    let diferenca = Temperatura::new(30.0, Celsius) - Temperatura::new(20.0, Celsius);
+    assert_eq!(diferenca.escala, Celsius);
+    assert_quase_igual!(diferenca.valor, 10.0);
+
+    // Entre escalas, o resultado fica na escala da esquerda
+    let diferenca = Temperatura::new(300.0, Kelvin) - Temperatura::new(0.0, Celsius);
+    assert_eq!(diferenca.escala, Kelvin);
+    assert_quase_igual!(diferenca.valor, 26.85);
+    assert_eq!(format!("{}", diferenca), "Δ26.85K");
+}
+
+#[test]
+fn test_diferenca_converte_so_pelo_fator() {
+    // 10°C a mais são 10 K a mais e 18°F a mais, não 283.15 K nem 50°F
+    let dez = DiferencaTemperatura::new(10.0, Celsius);
+    assert_quase_igual!(dez.converter_para(Kelvin).valor, 10.0);
+    assert_quase_igual!(dez.converter_para(Fahrenheit).valor, 18.0);
+    // Delisle cresce no frio: esquentar 10°C é -15°De
+    assert_quase_igual!(dez.converter_para(Delisle).valor, -15.0);
+}
+
+#[test]
+fn test_ponto_mais_diferenca_e_ponto() {
+    let agua = Temperatura::new(20.0, Celsius);
+    let aquecida = agua + DiferencaTemperatura::new(18.0, Fahrenheit);
+    assert_eq!(aquecida.escala, Celsius);
+    assert_quase_igual!(aquecida.valor, 30.0);
+
+    let resfriada = Temperatura::new(68.0, Fahrenheit) - DiferencaTemperatura::new(10.0, Kelvin);
+    assert_eq!(resfriada.escala, Fahrenheit);
+    assert_quase_igual!(resfriada.valor, 50.0);
+
+    // (b - a) + a volta para b, em qualquer combinação de escalas
+    for escala_a in EscalaTemperatura::TODAS {
+        for escala_b in EscalaTemperatura::TODAS {
+            let a = Temperatura::new(25.0, escala_a);
+            let b = Temperatura::new(300.0, escala_b);
+            assert_quase_igual!(a + (b - a), b);
+        }
+    }
+}
+
+#[test]
+fn test_aritmetica_de_diferencas() {
+    let soma = DiferencaTemperatura::new(10.0, Celsius) + DiferencaTemperatura::new(9.0, Fahrenheit);
+    assert_quase_igual!(soma.valor, 15.0);
+    let resto = DiferencaTemperatura::new(10.0, Kelvin) - DiferencaTemperatura::new(4.0, Celsius);
+    assert_quase_igual!(resto.valor, 6.0);
+    assert_quase_igual!((DiferencaTemperatura::new(3.0, Kelvin) * 2.0).valor, 6.0);
+    assert_quase_igual!((DiferencaTemperatura::new(3.0, Kelvin) / 2.0).valor, 1.5);
+    assert_quase_igual!((-DiferencaTemperatura::new(3.0, Kelvin)).valor, -3.0);
+}
+
+#[test]
+fn test_comparacao_entre_escalas() {
+    let agua_fervendo = Temperatura::new(100.0, Celsius);
+    assert!(Temperatura::new(211.0, Fahrenheit) < agua_fervendo);
+    assert!(Temperatura::new(374.0, Kelvin) > agua_fervendo);
+    assert_eq!(Temperatura::new(0.0, Celsius), Temperatura::new(273.15, Kelvin));
+    // Delisle: número maior é mais frio
+    assert!(Temperatura::new(150.0, Delisle) < Temperatura::new(0.0, Delisle));
+
+    assert!(DiferencaTemperatura::new(10.0, Celsius) > DiferencaTemperatura::new(17.0, Fahrenheit));
+    assert!(Temperatura::new(f64::NAN, Celsius).partial_cmp(&agua_fervendo).is_none());
+}
+
+#[test]
+fn test_quase_igual() {
+    let a = Temperatura::new(212.0, Fahrenheit);
+    let b = Temperatura::new(100.0, Celsius);
+    assert!(a.quase_igual(&b, 1e-9));
+    assert!(!a.quase_igual(&Temperatura::new(100.1, Celsius), 0.01));
+    assert!(1.0_f64.quase_igual(&1.05, 0.1));
+    assert_quase_igual!(a, b, 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "esquerda ≈ direita")]
+fn test_assert_quase_igual_falha_fora_da_tolerancia() {
+    assert_quase_igual!(1.0, 1.1);
+}
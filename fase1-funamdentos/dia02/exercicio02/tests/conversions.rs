@@ -1,50 +1,143 @@
-use exercicio02::{EscalaTemperatura, Temperatura};
-
-const EPS: f64 = 1e-6;
+use exercicio02::{EscalaTemperatura, Temperatura, TemperaturaError, assert_quase_igual};
 
 #[test]
 fn test_celsius_conversions() {
     let t = Temperatura::new(0.0, EscalaTemperatura::Celsius);
-    assert!((t.para_fahrenheit() - 32.0).abs() < EPS);
-    assert!((t.para_kelvin() - 273.15).abs() < EPS);
+    assert_quase_igual!(t.para_fahrenheit(), 32.0);
+    assert_quase_igual!(t.para_kelvin(), 273.15);
 
     let t2 = Temperatura::new(100.0, EscalaTemperatura::Celsius);
-    assert!((t2.para_fahrenheit() - 212.0).abs() < EPS);
-    assert!((t2.para_kelvin() - 373.15).abs() < EPS);
+    assert_quase_igual!(t2.para_fahrenheit(), 212.0);
+    assert_quase_igual!(t2.para_kelvin(), 373.15);
 }
 
 #[test]
 fn test_fahrenheit_conversions() {
     let t = Temperatura::new(32.0, EscalaTemperatura::Fahrenheit);
-    assert!((t.para_celsius() - 0.0).abs() < EPS);
-    assert!((t.para_kelvin() - 273.15).abs() < EPS);
+    assert_quase_igual!(t.para_celsius(), 0.0);
+    assert_quase_igual!(t.para_kelvin(), 273.15);
 
     let t2 = Temperatura::new(212.0, EscalaTemperatura::Fahrenheit);
-    assert!((t2.para_celsius() - 100.0).abs() < EPS);
-    assert!((t2.para_kelvin() - 373.15).abs() < EPS);
+    assert_quase_igual!(t2.para_celsius(), 100.0);
+    assert_quase_igual!(t2.para_kelvin(), 373.15);
 }
 
 #[test]
 fn test_kelvin_conversions() {
     let t = Temperatura::new(273.15, EscalaTemperatura::Kelvin);
-    assert!((t.para_celsius() - 0.0).abs() < EPS);
-    assert!((t.para_fahrenheit() - 32.0).abs() < EPS);
+    assert_quase_igual!(t.para_celsius(), 0.0);
+    assert_quase_igual!(t.para_fahrenheit(), 32.0);
 
     let t2 = Temperatura::new(0.0, EscalaTemperatura::Kelvin);
-    assert!((t2.para_celsius() + 273.15).abs() < EPS);
+    assert_quase_igual!(t2.para_celsius(), -273.15);
 }
 
 #[test]
 fn test_converter_para_and_display() {
     let origem = Temperatura::new(25.0, EscalaTemperatura::Celsius);
-    let dest = origem.converter_para(EscalaTemperatura::Fahrenheit);
+    let dest = origem.converter_para(EscalaTemperatura::Fahrenheit).unwrap();
     assert_eq!(dest.escala, EscalaTemperatura::Fahrenheit);
-    assert!((dest.valor - 77.0).abs() < EPS);
+    assert_quase_igual!(dest.valor, 77.0);
 
-    let origem_k = origem.converter_para(EscalaTemperatura::Kelvin);
+    let origem_k = origem.converter_para(EscalaTemperatura::Kelvin).unwrap();
     assert_eq!(origem_k.escala, EscalaTemperatura::Kelvin);
-    assert!((origem_k.valor - 298.15).abs() < EPS);
+    assert_quase_igual!(origem_k.valor, 298.15);
 
     let t = Temperatura::new(0.0, EscalaTemperatura::Celsius);
     assert_eq!(format!("{}", t), "0.00°C");
 }
+
+#[test]
+fn test_escalas_historicas_no_ponto_de_ebulicao() {
+    // 100°C em cada escala, pelos valores de referência
+    let ebulicao = Temperatura::new(100.0, EscalaTemperatura::Celsius);
+    let esperados = [
+        (EscalaTemperatura::Rankine, 671.67),
+        (EscalaTemperatura::Reaumur, 80.0),
+        (EscalaTemperatura::Delisle, 0.0),
+        (EscalaTemperatura::Newton, 33.0),
+        (EscalaTemperatura::Romer, 60.0),
+    ];
+    for (escala, valor) in esperados {
+        let convertida = ebulicao.converter_para(escala).unwrap();
+        assert_quase_igual!(convertida.valor, valor);
+    }
+
+    let congelamento = Temperatura::new(0.0, EscalaTemperatura::Celsius);
+    assert_quase_igual!(congelamento.converter_para(EscalaTemperatura::Delisle).unwrap().valor, 150.0);
+    assert_quase_igual!(congelamento.converter_para(EscalaTemperatura::Romer).unwrap().valor, 7.5);
+    assert_quase_igual!(congelamento.converter_para(EscalaTemperatura::Rankine).unwrap().valor, 491.67);
+}
+
+#[test]
+fn test_ida_e_volta_por_todas_as_escalas() {
+    for origem in EscalaTemperatura::TODAS {
+        for destino in EscalaTemperatura::TODAS {
+            let t = Temperatura::new(36.6, origem);
+            let volta = t.converter_para(destino).unwrap().converter_para(origem).unwrap();
+            assert_quase_igual!(volta.valor, 36.6);
+        }
+    }
+}
+
+#[test]
+fn test_display_das_novas_escalas() {
+    assert_eq!(format!("{}", Temperatura::new(80.0, EscalaTemperatura::Reaumur)), "80.00°Ré");
+    assert_eq!(format!("{}", Temperatura::new(60.0, EscalaTemperatura::Romer)), "60.00°Rø");
+    assert_eq!(format!("{}", EscalaTemperatura::Delisle), "Delisle (°De)");
+    assert_eq!(format!("{}", EscalaTemperatura::Kelvin), "Kelvin (K)");
+}
+
+#[test]
+fn test_try_new_aceita_o_zero_absoluto_de_cada_escala() {
+    for escala in EscalaTemperatura::TODAS {
+        let zero = Temperatura::try_new(escala.zero_absoluto(), escala).unwrap();
+        assert_quase_igual!(zero.para_kelvin(), 0.0);
+    }
+    assert!(Temperatura::try_new(-459.67, EscalaTemperatura::Fahrenheit).is_ok());
+    assert!(Temperatura::try_new(-273.15, EscalaTemperatura::Celsius).is_ok());
+    assert!(Temperatura::try_new(559.725, EscalaTemperatura::Delisle).is_ok());
+}
+
+#[test]
+fn test_try_new_recusa_abaixo_do_zero_absoluto() {
+    assert_eq!(
+        Temperatura::try_new(-10.0, EscalaTemperatura::Kelvin).unwrap_err(),
+        TemperaturaError::AbaixoDoZeroAbsoluto { valor: -10.0, escala: EscalaTemperatura::Kelvin }
+    );
+    assert!(Temperatura::try_new(-273.16, EscalaTemperatura::Celsius).is_err());
+    assert!(Temperatura::try_new(-460.0, EscalaTemperatura::Fahrenheit).is_err());
+    assert!(Temperatura::try_new(-0.01, EscalaTemperatura::Rankine).is_err());
+    // Delisle cresce no frio: o limite é um máximo
+    assert!(Temperatura::try_new(600.0, EscalaTemperatura::Delisle).is_err());
+    assert!(Temperatura::try_new(-600.0, EscalaTemperatura::Delisle).is_ok());
+
+    let erro = Temperatura::try_new(-10.0, EscalaTemperatura::Kelvin).unwrap_err();
+    assert_eq!(erro.to_string(), "-10.00K é mais frio que o zero absoluto (0.00K)");
+}
+
+#[test]
+fn test_try_new_recusa_valores_nao_finitos() {
+    for valor in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let erro = Temperatura::try_new(valor, EscalaTemperatura::Celsius).unwrap_err();
+        assert!(matches!(erro, TemperaturaError::NaoFinita { escala: EscalaTemperatura::Celsius, .. }));
+    }
+}
+
+#[test]
+fn test_converter_para_verifica_os_limites() {
+    // `new` não verifica, mas a conversão sim
+    let impossivel = Temperatura::new(-10.0, EscalaTemperatura::Kelvin);
+    assert!(impossivel.converter_para(EscalaTemperatura::Celsius).is_err());
+    assert!(impossivel.converter_para(EscalaTemperatura::Kelvin).is_err());
+
+    let nan = Temperatura::new(f64::NAN, EscalaTemperatura::Fahrenheit);
+    assert!(matches!(
+        nan.converter_para(EscalaTemperatura::Kelvin),
+        Err(TemperaturaError::NaoFinita { .. })
+    ));
+
+    let zero = Temperatura::new(-273.15, EscalaTemperatura::Celsius);
+    let em_rankine = zero.converter_para(EscalaTemperatura::Rankine).unwrap();
+    assert_quase_igual!(em_rankine.valor, 0.0);
+}
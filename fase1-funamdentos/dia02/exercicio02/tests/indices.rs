@@ -0,0 +1,82 @@
+use exercicio02::{ErroIndiceTermico, EscalaTemperatura, Temperatura, assert_quase_igual};
+
+use EscalaTemperatura::{Celsius, Fahrenheit, Kelvin};
+
+// As tabelas publicadas arredondam para inteiros, daí a tolerância de meio grau
+
+#[test]
+fn test_indice_de_calor_tabela_nws() {
+    // (°F, umidade %, índice °F) da tabela do NWS
+    let tabela =
+        [(80.0, 40.0, 80.0), (90.0, 50.0, 95.0), (100.0, 40.0, 109.0), (86.0, 90.0, 105.0), (96.0, 65.0, 121.0)];
+    for (t, umidade, esperado) in tabela {
+        let indice = Temperatura::new(t, Fahrenheit).indice_de_calor(umidade, Fahrenheit).unwrap();
+        assert_quase_igual!(indice.valor, esperado, 0.5);
+    }
+}
+
+#[test]
+fn test_indice_de_calor_em_qualquer_escala() {
+    let em_fahrenheit = Temperatura::new(90.0, Fahrenheit).indice_de_calor(50.0, Fahrenheit).unwrap();
+    let em_celsius = Temperatura::new(32.0 + 2.0 / 9.0, Celsius).indice_de_calor(50.0, Celsius).unwrap();
+    assert_eq!(em_celsius.escala, Celsius);
+    assert_quase_igual!(em_celsius, em_fahrenheit);
+}
+
+#[test]
+fn test_sensacao_do_vento_tabelas() {
+    // Environment Canada (°C, km/h)
+    for (t, vento, esperado) in [(-10.0, 20.0, -18.0), (-20.0, 30.0, -33.0), (0.0, 10.0, -3.0)] {
+        let sensacao = Temperatura::new(t, Celsius).sensacao_do_vento(vento, Celsius).unwrap();
+        assert_quase_igual!(sensacao.valor, esperado, 0.5);
+    }
+    // NWS (°F, mph); a fórmula em km/h arredonda as constantes da original em °F
+    for (t, mph, esperado) in [(0.0, 15.0, -19.0), (-10.0, 30.0, -39.0), (40.0, 10.0, 34.0)] {
+        let sensacao = Temperatura::new(t, Fahrenheit).sensacao_do_vento(mph * 1.609344, Fahrenheit).unwrap();
+        assert_quase_igual!(sensacao.valor, esperado, 1.0);
+    }
+}
+
+#[test]
+fn test_sensacao_do_vento_fora_do_dominio_e_a_temperatura() {
+    let quente = Temperatura::new(25.0, Celsius);
+    assert_quase_igual!(quente.sensacao_do_vento(40.0, Kelvin).unwrap(), quente);
+    let calmo = Temperatura::new(-5.0, Celsius);
+    assert_quase_igual!(calmo.sensacao_do_vento(3.0, Celsius).unwrap(), calmo);
+}
+
+#[test]
+fn test_ponto_de_orvalho() {
+    for (t, umidade, esperado) in [(25.0, 50.0, 13.86), (30.0, 70.0, 23.93), (20.0, 100.0, 20.0)] {
+        let orvalho = Temperatura::new(t, Celsius).ponto_de_orvalho(umidade, Celsius).unwrap();
+        assert_quase_igual!(orvalho.valor, esperado, 0.05);
+    }
+    let em_kelvin = Temperatura::new(77.0, Fahrenheit).ponto_de_orvalho(50.0, Kelvin).unwrap();
+    assert_quase_igual!(em_kelvin.valor, 287.01, 0.05);
+}
+
+#[test]
+fn test_humidex_tabela_environment_canada() {
+    // (°C, ponto de orvalho °C, humidex)
+    for (t, orvalho, esperado) in [(30.0, 15.0, 34.0), (30.0, 20.0, 38.0), (25.0, 10.0, 26.0)] {
+        let humidex = Temperatura::new(t, Celsius).humidex(&Temperatura::new(orvalho, Celsius), Celsius).unwrap();
+        assert_quase_igual!(humidex.valor, esperado, 0.5);
+    }
+}
+
+#[test]
+fn test_entradas_invalidas() {
+    let t = Temperatura::new(30.0, Celsius);
+    assert_eq!(t.indice_de_calor(0.0, Celsius).unwrap_err(), ErroIndiceTermico::UmidadeInvalida(0.0));
+    assert_eq!(t.ponto_de_orvalho(120.0, Celsius).unwrap_err(), ErroIndiceTermico::UmidadeInvalida(120.0));
+    assert_eq!(t.sensacao_do_vento(-1.0, Celsius).unwrap_err(), ErroIndiceTermico::VentoInvalido(-1.0));
+    let impossivel = Temperatura::new(-10.0, Kelvin);
+    assert!(matches!(impossivel.ponto_de_orvalho(50.0, Celsius), Err(ErroIndiceTermico::Temperatura(_))));
+
+    // Orvalho acima do ar é umidade acima de 100 %; igual ao ar ainda vale
+    assert_eq!(
+        t.humidex(&Temperatura::new(35.0, Celsius), Celsius).unwrap_err(),
+        ErroIndiceTermico::OrvalhoAcimaDoAr { orvalho: 35.0, temperatura: 30.0 }
+    );
+    assert!(t.humidex(&Temperatura::new(86.0, Fahrenheit), Celsius).is_ok());
+}
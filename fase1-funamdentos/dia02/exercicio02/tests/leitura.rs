@@ -0,0 +1,80 @@
+use exercicio02::{ErroLeituraTemperatura, EscalaTemperatura, Temperatura, assert_quase_igual, interpretar_conversao};
+
+fn ler(texto: &str) -> Temperatura {
+    texto.parse().unwrap_or_else(|e| panic!("'{}': {}", texto, e))
+}
+
+#[test]
+fn test_formatos_aceitos() {
+    let casos = [
+        ("25C", 25.0, EscalaTemperatura::Celsius),
+        ("77 °F", 77.0, EscalaTemperatura::Fahrenheit),
+        ("298.15 K", 298.15, EscalaTemperatura::Kelvin),
+        ("-40 fahrenheit", -40.0, EscalaTemperatura::Fahrenheit),
+        ("  12.5 ºc ", 12.5, EscalaTemperatura::Celsius),
+        ("1e2 Kelvin", 100.0, EscalaTemperatura::Kelvin),
+        ("80 Réaumur", 80.0, EscalaTemperatura::Reaumur),
+        ("150De", 150.0, EscalaTemperatura::Delisle),
+        ("60 °Rø", 60.0, EscalaTemperatura::Romer),
+        ("671.67 rankine", 671.67, EscalaTemperatura::Rankine),
+    ];
+    for (texto, valor, escala) in casos {
+        let t = ler(texto);
+        assert_quase_igual!(t.valor, valor);
+        assert_eq!(t.escala, escala, "{}", texto);
+    }
+}
+
+#[test]
+fn test_le_de_volta_o_que_display_gera() {
+    for escala in EscalaTemperatura::TODAS {
+        let original = Temperatura::new(12.5, escala);
+        let lida = ler(&original.to_string());
+        assert_eq!(lida.escala, escala);
+        assert_quase_igual!(lida.valor, original.valor);
+    }
+}
+
+#[test]
+fn test_erros_de_leitura() {
+    assert_eq!("".parse::<Temperatura>().unwrap_err(), ErroLeituraTemperatura::Vazia);
+    assert_eq!("25".parse::<Temperatura>().unwrap_err(), ErroLeituraTemperatura::SemEscala);
+    assert_eq!(
+        "25 X".parse::<Temperatura>().unwrap_err(),
+        ErroLeituraTemperatura::EscalaDesconhecida(String::from("X"))
+    );
+    assert_eq!(
+        "quente".parse::<Temperatura>().unwrap_err(),
+        ErroLeituraTemperatura::NumeroInvalido(String::from("quente"))
+    );
+    assert_eq!(
+        "1.2.3 C".parse::<Temperatura>().unwrap_err(),
+        ErroLeituraTemperatura::NumeroInvalido(String::from("1.2.3"))
+    );
+    assert!(matches!(
+        "-300 C".parse::<Temperatura>().unwrap_err(),
+        ErroLeituraTemperatura::ForaDosLimites(_)
+    ));
+    assert!(matches!(
+        "1e400 K".parse::<Temperatura>().unwrap_err(),
+        ErroLeituraTemperatura::ForaDosLimites(_)
+    ));
+}
+
+#[test]
+fn test_conversao_livre() {
+    let (origem, destino) = interpretar_conversao("100F to K").unwrap();
+    assert_eq!(origem.escala, EscalaTemperatura::Fahrenheit);
+    assert_eq!(destino, EscalaTemperatura::Kelvin);
+    assert_quase_igual!(origem.converter_para(destino).unwrap().valor, 310.927778);
+
+    let (origem, destino) = interpretar_conversao("25 °C para Fahrenheit").unwrap();
+    assert_eq!(origem.converter_para(destino).unwrap().to_string(), "77.00°F");
+    assert!(interpretar_conversao("300K -> C").is_ok());
+
+    assert_eq!(interpretar_conversao("100F").unwrap_err(), ErroLeituraTemperatura::SemDestino);
+    assert_eq!(
+        interpretar_conversao("100F to parsecs").unwrap_err(),
+        ErroLeituraTemperatura::EscalaDesconhecida(String::from("parsecs"))
+    );
+}
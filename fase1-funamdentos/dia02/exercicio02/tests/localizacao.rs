@@ -0,0 +1,63 @@
+use exercicio02::{DiferencaTemperatura, EscalaTemperatura, EstiloUnidade, Formatacao, Localidade, Temperatura};
+
+use EscalaTemperatura::{Celsius, Fahrenheit, Kelvin};
+
+#[test]
+fn test_codigos_de_localidade() {
+    assert_eq!(Localidade::de_codigo("pt-BR"), Some(Localidade::PtBr));
+    assert_eq!(Localidade::de_codigo("en_us"), Some(Localidade::EnUs));
+    assert_eq!(Localidade::de_codigo("DE-de"), Some(Localidade::DeDe));
+    assert_eq!(Localidade::de_codigo("fr-FR"), None);
+    for localidade in Localidade::TODAS {
+        assert_eq!(Localidade::de_codigo(localidade.codigo()), Some(localidade));
+    }
+}
+
+#[test]
+fn test_separadores_decimal_e_de_milhar() {
+    let valor = -1234567.891;
+    assert_eq!(Formatacao::new(Localidade::PtBr).numero(valor), "-1.234.567,89");
+    assert_eq!(Formatacao::new(Localidade::EnUs).numero(valor), "-1,234,567.89");
+    assert_eq!(Formatacao::new(Localidade::DeDe).com_casas(0).numero(valor), "-1.234.568");
+    assert_eq!(Formatacao::new(Localidade::PtBr).numero(-0.001), "0,00");
+}
+
+#[test]
+fn test_estilo_curto() {
+    let celsius = Temperatura::new(25.0, Celsius);
+    let fahrenheit = Temperatura::new(77.0, Fahrenheit);
+    let kelvin = Temperatura::new(298.15, Kelvin);
+
+    assert_eq!(celsius.formatar(&Formatacao::new(Localidade::PtBr)), "25,00 °C");
+    assert_eq!(fahrenheit.formatar(&Formatacao::new(Localidade::EnUs).com_casas(1)), "77.0°F");
+    assert_eq!(kelvin.formatar(&Formatacao::new(Localidade::EnUs)), "298.15 K");
+    assert_eq!(kelvin.formatar(&Formatacao::new(Localidade::DeDe)), "298,15 K");
+}
+
+#[test]
+fn test_estilo_longo_com_plural() {
+    let longo = |localidade| Formatacao::new(localidade).com_estilo(EstiloUnidade::Longo);
+    let celsius = Temperatura::new(25.0, Celsius);
+    let kelvin = Temperatura::new(1.0, Kelvin);
+
+    assert_eq!(celsius.formatar(&longo(Localidade::PtBr)), "25,00 graus Celsius");
+    assert_eq!(celsius.formatar(&longo(Localidade::EnUs)), "25.00 degrees Celsius");
+    assert_eq!(celsius.formatar(&longo(Localidade::DeDe)), "25,00 Grad Celsius");
+
+    assert_eq!(kelvin.formatar(&longo(Localidade::PtBr).com_casas(0)), "1 kelvin");
+    assert_eq!(kelvin.formatar(&longo(Localidade::PtBr)), "1,00 kelvins");
+    assert_eq!(kelvin.formatar(&longo(Localidade::DeDe)), "1,00 Kelvin");
+    assert_eq!(
+        Temperatura::new(1.0, Fahrenheit).formatar(&longo(Localidade::EnUs).com_casas(0)),
+        "1 degree Fahrenheit"
+    );
+}
+
+#[test]
+fn test_escala_e_diferenca() {
+    let curto = Formatacao::new(Localidade::DeDe);
+    let longo = curto.com_estilo(EstiloUnidade::Longo);
+    assert_eq!(curto.escala(EscalaTemperatura::Reaumur), "°Ré");
+    assert_eq!(longo.escala(EscalaTemperatura::Reaumur), "Grad Réaumur");
+    assert_eq!(curto.diferenca(&DiferencaTemperatura::new(2.5, Celsius)), "Δ2,50 °C");
+}
@@ -0,0 +1,105 @@
+use exercicio02::{ErroLote, EscalaTemperatura, FormatoLote, MAXIMO_CASAS, OpcoesLote, ResumoLote, converter_lote};
+
+fn opcoes(formato: FormatoLote, coluna: &str, destino: EscalaTemperatura) -> OpcoesLote {
+    OpcoesLote { formato, coluna: coluna.to_string(), destino, escala_padrao: None, casas: 2 }
+}
+
+fn converter(entrada: &str, opcoes: &OpcoesLote) -> (String, ResumoLote) {
+    let mut saida = Vec::new();
+    let resumo = converter_lote(entrada.as_bytes(), &mut saida, opcoes).unwrap();
+    (String::from_utf8(saida).unwrap(), resumo)
+}
+
+#[test]
+fn test_csv_com_escalas_misturadas() {
+    let entrada = "sensor,temp,local\ns1,25C,sala\ns2,77 °F,\"cozinha, fundos\"\ns3,298.15 K,garagem\n";
+    let (saida, resumo) = converter(entrada, &opcoes(FormatoLote::Csv, "temp", EscalaTemperatura::Celsius));
+
+    assert_eq!(
+        saida,
+        "sensor,temp,local\ns1,25.00,sala\ns2,25.00,\"cozinha, fundos\"\ns3,25.00,garagem\n"
+    );
+    assert_eq!(resumo.convertidas, 3);
+    assert!(resumo.erros.is_empty());
+}
+
+#[test]
+fn test_csv_linhas_ruins_sao_puladas_com_numero() {
+    let entrada = "sensor,temp\ns1,100C\ns2,quente\ns3\ns4,-500C\n\ns5,0C\n";
+    let (saida, resumo) = converter(entrada, &opcoes(FormatoLote::Csv, "temp", EscalaTemperatura::Kelvin));
+
+    assert_eq!(saida, "sensor,temp\ns1,373.15\ns5,273.15\n");
+    assert_eq!(resumo.convertidas, 2);
+    let linhas: Vec<usize> = resumo.erros.iter().map(|e| e.linha).collect();
+    assert_eq!(linhas, vec![3, 4, 5]);
+    assert_eq!(resumo.erros[1].to_string(), "linha 4: esperava 2 campos, encontrou 1");
+    assert!(resumo.erros[2].mensagem.contains("zero absoluto"));
+}
+
+#[test]
+fn test_csv_com_escala_padrao_para_numeros_puros() {
+    let mut opcoes = opcoes(FormatoLote::Csv, "t", EscalaTemperatura::Fahrenheit);
+    opcoes.escala_padrao = Some(EscalaTemperatura::Celsius);
+    opcoes.casas = 1;
+    let (saida, resumo) = converter("t\n100\n300K\n", &opcoes);
+    assert_eq!(saida, "t\n212.0\n80.3\n");
+    assert!(resumo.erros.is_empty());
+}
+
+#[test]
+fn test_csv_coluna_ausente_interrompe() {
+    let mut saida = Vec::new();
+    let erro = converter_lote("a,b\n1,2\n".as_bytes(), &mut saida, &opcoes(FormatoLote::Csv, "temp", EscalaTemperatura::Kelvin));
+    assert!(matches!(erro, Err(ErroLote::ColunaAusente(ref coluna)) if coluna == "temp"));
+
+    let erro = converter_lote("".as_bytes(), &mut saida, &opcoes(FormatoLote::Csv, "temp", EscalaTemperatura::Kelvin));
+    assert!(matches!(erro, Err(ErroLote::SemCabecalho)));
+}
+
+#[test]
+#[cfg(feature = "jsonl")]
+fn test_json_linhas() {
+    let entrada = concat!(
+        "{\"id\": 1, \"temp\": \"-40 fahrenheit\", \"ok\": true}\n",
+        "{\"id\": 2, \"temp\": 0}\n",
+        "não é json\n",
+        "[1, 2]\n",
+        "{\"id\": 5}\n",
+        "{\"id\": 6, \"temp\": \"10C\"}\n",
+    );
+    let (saida, resumo) = converter(entrada, &opcoes(FormatoLote::JsonLinhas, "temp", EscalaTemperatura::Celsius));
+
+    // A ordem das chaves é mantida
+    assert_eq!(saida, "{\"id\":1,\"temp\":-40.0,\"ok\":true}\n{\"id\":6,\"temp\":10.0}\n");
+    assert_eq!(resumo.convertidas, 2);
+    let linhas: Vec<usize> = resumo.erros.iter().map(|e| e.linha).collect();
+    assert_eq!(linhas, vec![2, 3, 4, 5]);
+    assert_eq!(resumo.erros[0].mensagem, "Faltou a escala (ex.: 25C, 77 °F, 298.15 K)");
+    assert!(resumo.erros[1].mensagem.starts_with("JSON inválido"));
+    assert_eq!(resumo.erros[3].mensagem, "falta a chave 'temp'");
+}
+
+#[test]
+fn test_formato_pela_extensao() {
+    #[cfg(feature = "jsonl")]
+    {
+        assert_eq!(FormatoLote::pela_extensao("leituras.jsonl"), FormatoLote::JsonLinhas);
+        assert_eq!(FormatoLote::pela_extensao("leituras.ndjson"), FormatoLote::JsonLinhas);
+    }
+    assert_eq!(FormatoLote::pela_extensao("leituras.csv"), FormatoLote::Csv);
+    assert_eq!(FormatoLote::pela_extensao(""), FormatoLote::Csv);
+}
+
+#[test]
+fn test_casas_demais_nao_estragam_o_valor() {
+    let mut opcoes = opcoes(FormatoLote::Csv, "t", EscalaTemperatura::Kelvin);
+    opcoes.casas = 400;
+    let (saida, resumo) = converter("t
+0C
+1e300K
+", &opcoes);
+    assert!(resumo.erros.is_empty());
+    let valores: Vec<f64> = saida.lines().skip(1).map(|linha| linha.parse().unwrap()).collect();
+    assert_eq!(valores, vec![273.15, 1e300]);
+    assert_eq!(saida.lines().nth(1).unwrap().split('.').nth(1).unwrap().len(), MAXIMO_CASAS);
+}
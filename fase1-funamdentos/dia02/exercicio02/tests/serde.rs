@@ -0,0 +1,90 @@
+use exercicio02::{EscalaTemperatura, Temperatura, assert_quase_igual};
+use serde::{Deserialize, Serialize};
+
+/// Registro de um sensor, como um serviço mandaria pelo fio
+#[derive(Debug, Serialize, Deserialize)]
+struct Leitura {
+    sensor: String,
+    temperatura: Temperatura,
+    #[serde(with = "exercicio02::compacto")]
+    maxima: Temperatura,
+}
+
+fn leitura() -> Leitura {
+    Leitura {
+        sensor: String::from("estufa-1"),
+        temperatura: Temperatura::new(25.0, EscalaTemperatura::Celsius),
+        maxima: Temperatura::new(98.6, EscalaTemperatura::Fahrenheit),
+    }
+}
+
+#[test]
+fn test_formato_json_estavel() {
+    let json = serde_json::to_string(&Temperatura::new(25.0, EscalaTemperatura::Celsius)).unwrap();
+    assert_eq!(json, r#"{"valor":25.0,"escala":"celsius"}"#);
+
+    let nomes: Vec<String> = EscalaTemperatura::TODAS
+        .iter()
+        .map(|escala| serde_json::to_string(escala).unwrap())
+        .collect();
+    assert_eq!(
+        nomes,
+        ["\"celsius\"", "\"fahrenheit\"", "\"kelvin\"", "\"rankine\"", "\"reaumur\"", "\"delisle\"", "\"newton\"", "\"romer\""]
+    );
+
+    let json = serde_json::to_string(&leitura()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"sensor":"estufa-1","temperatura":{"valor":25.0,"escala":"celsius"},"maxima":"98.6F"}"#
+    );
+}
+
+#[test]
+fn test_ida_e_volta_json_em_todas_as_escalas() {
+    for escala in EscalaTemperatura::TODAS {
+        let original = Temperatura::new(12.5, escala);
+        let completa: Temperatura = serde_json::from_str(&serde_json::to_string(&original).unwrap()).unwrap();
+        assert_eq!(completa.escala, escala);
+        assert_quase_igual!(completa.valor, 12.5);
+
+        let mut compacta = Vec::new();
+        exercicio02::compacto::serialize(&original, &mut serde_json::Serializer::new(&mut compacta)).unwrap();
+        let lida: Temperatura = serde_json::from_slice(&compacta).unwrap();
+        assert_eq!(lida.escala, escala, "{}", String::from_utf8_lossy(&compacta));
+        assert_quase_igual!(lida.valor, 12.5);
+    }
+}
+
+#[test]
+fn test_ida_e_volta_toml() {
+    let texto = toml::to_string(&leitura()).unwrap();
+    assert!(texto.contains("maxima = \"98.6F\""), "{}", texto);
+    assert!(texto.contains("escala = \"celsius\""), "{}", texto);
+
+    let lida: Leitura = toml::from_str(&texto).unwrap();
+    assert_eq!(lida.sensor, "estufa-1");
+    assert_eq!(lida.temperatura.escala, EscalaTemperatura::Celsius);
+    assert_quase_igual!(lida.temperatura.valor, 25.0);
+    assert_eq!(lida.maxima.escala, EscalaTemperatura::Fahrenheit);
+    assert_quase_igual!(lida.maxima.valor, 98.6);
+}
+
+#[test]
+fn test_leitura_aceita_as_duas_formas() {
+    let compacta: Temperatura = serde_json::from_str("\"-40 fahrenheit\"").unwrap();
+    assert_eq!(compacta.escala, EscalaTemperatura::Fahrenheit);
+
+    let toml_compacto: Leitura =
+        toml::from_str("sensor = \"s\"\ntemperatura = \"300K\"\nmaxima = { valor = 30.0, escala = \"celsius\" }\n").unwrap();
+    assert_eq!(toml_compacto.temperatura.escala, EscalaTemperatura::Kelvin);
+    assert_eq!(toml_compacto.maxima.escala, EscalaTemperatura::Celsius);
+}
+
+#[test]
+fn test_leitura_recusa_temperaturas_impossiveis() {
+    let erro = serde_json::from_str::<Temperatura>(r#"{"valor":-10.0,"escala":"kelvin"}"#).unwrap_err();
+    assert!(erro.to_string().contains("zero absoluto"), "{}", erro);
+    assert!(serde_json::from_str::<Temperatura>("\"-500C\"").is_err());
+    assert!(serde_json::from_str::<Temperatura>(r#"{"valor":1.0,"escala":"plank"}"#).is_err());
+    assert!(serde_json::from_str::<Temperatura>("\"quente\"").is_err());
+}
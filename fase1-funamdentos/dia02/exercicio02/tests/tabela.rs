@@ -0,0 +1,106 @@
+use exercicio02::{
+    DiferencaTemperatura, ErroTabela, EscalaTemperatura, FormatoTabela, MAXIMO_CASAS, Medida, Tabela, Temperatura,
+    assert_quase_igual,
+};
+
+use EscalaTemperatura::{Celsius, Fahrenheit, Kelvin};
+
+fn tabela_pequena() -> Tabela {
+    Tabela::new(Celsius, 0.0, 100.0, 50.0).unwrap().com_escalas(&[Fahrenheit, Kelvin])
+}
+
+#[test]
+fn test_intervalo_inclui_o_fim() {
+    let tabela = Tabela::new(Celsius, -40.0, 120.0, 5.0).unwrap();
+    let linhas = tabela.linhas();
+    assert_eq!(linhas.len(), 33);
+    assert_quase_igual!(linhas[0][0], -40.0);
+    assert_quase_igual!(linhas[32][0], 120.0);
+
+    // 0.1 não é exato em binário; o fim entra mesmo assim
+    assert_eq!(Tabela::new(Celsius, 0.0, 1.0, 0.1).unwrap().linhas().len(), 11);
+}
+
+#[test]
+fn test_colunas_comecam_pela_origem_sem_repetir() {
+    let tabela = Tabela::new(Fahrenheit, 32.0, 32.0, 1.0).unwrap().com_escalas(&[Celsius, Fahrenheit, Kelvin]);
+    assert_eq!(tabela.colunas(), vec![Fahrenheit, Celsius, Kelvin]);
+    let linha = &tabela.linhas()[0];
+    assert_quase_igual!(linha[1], 0.0);
+    assert_quase_igual!(linha[2], 273.15);
+
+    let todas = Tabela::new(Kelvin, 0.0, 1.0, 1.0).unwrap();
+    assert_eq!(todas.colunas().len(), EscalaTemperatura::TODAS.len());
+}
+
+#[test]
+fn test_pontos_alem_do_zero_absoluto_ficam_de_fora() {
+    let linhas = Tabela::new(Celsius, -300.0, -200.0, 50.0).unwrap().linhas();
+    assert_eq!(linhas.len(), 2);
+    assert_quase_igual!(linhas[0][0], -250.0);
+}
+
+#[test]
+fn test_parametros_invalidos() {
+    assert_eq!(Tabela::new(Celsius, 10.0, 0.0, 1.0).unwrap_err(), ErroTabela::IntervaloInvalido);
+    assert_eq!(Tabela::new(Celsius, f64::NAN, 0.0, 1.0).unwrap_err(), ErroTabela::IntervaloInvalido);
+    assert_eq!(Tabela::new(Celsius, 0.0, 10.0, 0.0).unwrap_err(), ErroTabela::PassoInvalido(0.0));
+    assert_eq!(Tabela::new(Celsius, 0.0, 10.0, -1.0).unwrap_err(), ErroTabela::PassoInvalido(-1.0));
+    assert!(matches!(Tabela::new(Celsius, 0.0, 1e6, 1.0), Err(ErroTabela::LinhasDemais(_))));
+}
+
+#[test]
+fn test_formato_texto() {
+    let esperado = "\
+┌──────────────┬─────────────────┬────────────┐
+│ Celsius (°C) │ Fahrenheit (°F) │ Kelvin (K) │
+├──────────────┼─────────────────┼────────────┤
+│         0.00 │           32.00 │     273.15 │
+│        50.00 │          122.00 │     323.15 │
+│       100.00 │          212.00 │     373.15 │
+└──────────────┴─────────────────┴────────────┘
+";
+    assert_eq!(tabela_pequena().gerar(FormatoTabela::Texto), esperado);
+}
+
+#[test]
+fn test_formato_markdown_e_csv() {
+    let tabela = tabela_pequena().com_casas(1);
+    assert_eq!(
+        tabela.gerar(FormatoTabela::Markdown),
+        "| Celsius (°C) | Fahrenheit (°F) | Kelvin (K) |\n|---:|---:|---:|\n\
+         | 0.0 | 32.0 | 273.1 |\n| 50.0 | 122.0 | 323.1 |\n| 100.0 | 212.0 | 373.1 |\n"
+    );
+    assert_eq!(
+        tabela.com_casas(0).gerar(FormatoTabela::Csv),
+        "Celsius (°C),Fahrenheit (°F),Kelvin (K)\n0,32,273\n50,122,323\n100,212,373\n"
+    );
+}
+
+#[test]
+fn test_casas_demais_param_no_maximo() {
+    let csv = tabela_pequena().com_casas(300).gerar(FormatoTabela::Csv);
+    assert_eq!(csv, tabela_pequena().com_casas(MAXIMO_CASAS).gerar(FormatoTabela::Csv));
+    let primeiro_valor = csv.lines().nth(1).unwrap().split(',').next().unwrap();
+    assert_eq!(primeiro_valor.split('.').nth(1).unwrap().len(), MAXIMO_CASAS);
+}
+
+#[test]
+fn test_formato_html() {
+    let html = Tabela::new(Celsius, 0.0, 0.0, 1.0).unwrap().com_escalas(&[Kelvin]).gerar(FormatoTabela::Html);
+    assert_eq!(
+        html,
+        "<table>\n  <thead>\n    <tr><th>Celsius (°C)</th><th>Kelvin (K)</th></tr>\n  </thead>\n  \
+         <tbody>\n    <tr><td>0.00</td><td>273.15</td></tr>\n  </tbody>\n</table>\n"
+    );
+}
+
+#[test]
+fn test_display_respeita_a_precisao() {
+    let t = Temperatura::new(36.666, Celsius);
+    assert_eq!(format!("{}", t), "36.67°C");
+    assert_eq!(format!("{:.0}", t), "37°C");
+    assert_eq!(format!("{:.4}", t), "36.6660°C");
+    assert_eq!(format!("{:.1}", DiferencaTemperatura::new(2.25, Kelvin)), "Δ2.2K");
+    assert_eq!(format!("{:.3}", Medida::new(1.5, Celsius)), "1.500°C");
+}
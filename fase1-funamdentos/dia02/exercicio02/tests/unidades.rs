@@ -0,0 +1,147 @@
+use exercicio02::{
+    Conversao, ErroConversao, EscalaTemperatura, Grandeza, Medida, RegistroUnidades, Temperatura, TemperaturaError,
+    Unidade, UnidadeRegistrada, assert_quase_igual,
+};
+
+#[test]
+fn test_conversoes_lineares_do_registro_padrao() {
+    let registro = RegistroUnidades::padrao();
+    let casos = [
+        (1.0, "mi", "km", 1.609344),
+        (12.0, "in", "ft", 1.0),
+        (1.0, "lb", "g", 453.59237),
+        (1.0, "gal", "L", 3.785411784),
+        (1.0, "atm", "kPa", 101.325),
+        (1.0, "kWh", "kcal", 3.6e6 / 4184.0),
+        (100.0, "km/h", "m/s", 100.0 / 3.6),
+        (1.0, "GiB", "MB", 1073.741824),
+        (8.0, "bit", "B", 1.0),
+    ];
+    for (valor, origem, destino, esperado) in casos {
+        let obtido = registro.converter(valor, origem, destino).unwrap();
+        assert_quase_igual!(obtido, esperado);
+    }
+}
+
+#[test]
+fn test_temperatura_e_uma_unidade_do_registro() {
+    let registro = RegistroUnidades::padrao();
+    assert_quase_igual!(registro.converter(100.0, "°C", "°F").unwrap(), 212.0);
+    assert_quase_igual!(registro.converter(0.0, "K", "°R").unwrap(), 0.0);
+    assert_eq!(registro.da_grandeza(Grandeza::Temperatura).count(), EscalaTemperatura::TODAS.len());
+
+    // A mesma conversão pelo trait, direto na escala
+    let f = EscalaTemperatura::Celsius.converter(37.0, &EscalaTemperatura::Fahrenheit).unwrap();
+    assert_quase_igual!(f, Temperatura::new(37.0, EscalaTemperatura::Celsius).para_fahrenheit());
+}
+
+#[test]
+fn test_erros_de_conversao() {
+    let registro = RegistroUnidades::padrao();
+    assert_eq!(
+        registro.converter(1.0, "m", "kg"),
+        Err(ErroConversao::GrandezasIncompativeis { origem: Grandeza::Comprimento, destino: Grandeza::Massa })
+    );
+    assert_eq!(registro.converter(1.0, "furlong", "m"), Err(ErroConversao::UnidadeDesconhecida(String::from("furlong"))));
+    assert_eq!(
+        registro.converter(1.0, "°C", "J").unwrap_err().to_string(),
+        "Não é possível converter temperatura em energia"
+    );
+}
+
+#[test]
+fn test_registrar_unidades_novas() {
+    let mut registro = RegistroUnidades::padrao();
+    registro
+        .registrar(UnidadeRegistrada::linear("furlong", "furlong", Grandeza::Comprimento, 201.168).unwrap())
+        .unwrap();
+    // Escala afim inventada: 0 no congelamento da água, 10 na ebulição
+    registro
+        .registrar(UnidadeRegistrada::afim("°X", "escala X", Grandeza::Temperatura, 10.0, 273.15).unwrap())
+        .unwrap();
+
+    assert_quase_igual!(registro.converter(1.0, "furlong", "yd").unwrap(), 220.0);
+    assert_quase_igual!(registro.converter(100.0, "°C", "°X").unwrap(), 10.0);
+    assert_eq!(
+        registro.registrar(UnidadeRegistrada::linear("m", "outro metro", Grandeza::Comprimento, 1.0).unwrap()),
+        Err(ErroConversao::SimboloDuplicado(String::from("m")))
+    );
+}
+
+#[test]
+fn test_grandeza_personalizada() {
+    const LUMINOSIDADE: Grandeza = Grandeza::Personalizada("luminosidade");
+
+    let mut registro = RegistroUnidades::padrao();
+    registro.registrar(UnidadeRegistrada::linear("lm", "lúmen", LUMINOSIDADE, 1.0).unwrap()).unwrap();
+    registro.registrar(UnidadeRegistrada::linear("klm", "quilolúmen", LUMINOSIDADE, 1000.0).unwrap()).unwrap();
+
+    assert_quase_igual!(registro.converter(2.5, "klm", "lm").unwrap(), 2500.0);
+    assert_eq!(registro.da_grandeza(LUMINOSIDADE).count(), 2);
+    assert_eq!(
+        registro.converter(1.0, "lm", "m"),
+        Err(ErroConversao::GrandezasIncompativeis { origem: LUMINOSIDADE, destino: Grandeza::Comprimento })
+    );
+    assert_eq!(LUMINOSIDADE.to_string(), "luminosidade");
+    assert_ne!(LUMINOSIDADE, Grandeza::Personalizada("iluminância"));
+}
+
+#[test]
+fn test_fator_invalido() {
+    for fator in [0.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            UnidadeRegistrada::linear("x", "x", Grandeza::Comprimento, fator),
+            Err(ErroConversao::FatorInvalido { .. })
+        ));
+    }
+    assert!(matches!(
+        UnidadeRegistrada::afim("x", "x", Grandeza::Temperatura, 0.0, 1.0),
+        Err(ErroConversao::FatorInvalido { fator: 0.0, .. })
+    ));
+    for deslocamento in [f64::NAN, f64::NEG_INFINITY] {
+        let erro = UnidadeRegistrada::afim("x", "x", Grandeza::Temperatura, 1.0, deslocamento).unwrap_err();
+        assert!(matches!(erro, ErroConversao::DeslocamentoInvalido { .. }));
+        assert!(erro.to_string().starts_with("Deslocamento inválido para 'x'"));
+    }
+    assert!(UnidadeRegistrada::linear("x", "x", Grandeza::Comprimento, -2.0).is_ok());
+}
+
+#[test]
+fn test_medida_generica() {
+    let registro = RegistroUnidades::padrao();
+    let maratona = Medida::new(42.195, registro.buscar("km").unwrap());
+    let em_milhas = maratona.converter_para(registro.buscar("mi").unwrap()).unwrap();
+    assert_quase_igual!(em_milhas.valor, 26.218757456454306);
+    assert_eq!(em_milhas.to_string(), "26.22 mi");
+    assert!(maratona.converter_para(registro.buscar("kg").unwrap()).is_err());
+
+    let corpo = Medida::new(37.0, EscalaTemperatura::Celsius);
+    let em_kelvin = corpo.converter_para(EscalaTemperatura::Kelvin).unwrap();
+    assert_eq!(em_kelvin.to_string(), "310.15K");
+    assert_eq!(corpo.to_string(), "37.00°C");
+    assert_eq!(Temperatura::from(em_kelvin).to_string(), em_kelvin.to_string());
+    assert_eq!(format!("{:.1}", Temperatura::from(em_kelvin)), format!("{:.1}", em_kelvin));
+}
+
+#[test]
+fn test_medida_abaixo_do_zero_absoluto() {
+    let impossivel = Medida::new(-500.0, EscalaTemperatura::Celsius);
+    let erro = TemperaturaError::AbaixoDoZeroAbsoluto { valor: -500.0, escala: EscalaTemperatura::Celsius };
+    assert_eq!(impossivel.converter_para(EscalaTemperatura::Kelvin), Err(ErroConversao::Temperatura(erro)));
+    assert_eq!(
+        Temperatura::new(-500.0, EscalaTemperatura::Celsius).converter_para(EscalaTemperatura::Kelvin),
+        Err(erro)
+    );
+    assert!(Medida::new(f64::NAN, EscalaTemperatura::Kelvin).converter_para(EscalaTemperatura::Celsius).is_err());
+    assert!(Medida::new(-459.67, EscalaTemperatura::Fahrenheit).converter_para(EscalaTemperatura::Kelvin).is_ok());
+}
+
+#[test]
+fn test_conversao_afim_e_linear() {
+    let linear = Conversao::Linear(0.3048);
+    assert_quase_igual!(linear.de_referencia(linear.para_referencia(10.0)), 10.0);
+
+    let delisle = EscalaTemperatura::Delisle.conversao();
+    assert_eq!(delisle.de_referencia(373.15), 0.0);
+    assert!(delisle.de_referencia(373.15).is_sign_positive());
+}
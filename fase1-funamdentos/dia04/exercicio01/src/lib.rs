@@ -0,0 +1,250 @@
+// O código veio do main.rs como estava, com `n % d == 0`
+#![allow(clippy::manual_is_multiple_of)]
+
+// ========================================
+// BIBLIOTECA MATEMÁTICA
+// ========================================
+
+/// Calcula o fatorial de um número
+/// Exemplo: fatorial(5) = 5 * 4 * 3 * 2 * 1 = 120
+pub fn fatorial(n: u64) -> u64 {
+    if n == 0 || n == 1 {
+        return 1; // Caso base
+    }
+    
+    n * fatorial(n - 1) // Recursão + retorno implícito
+}
+
+/// Fatorial iterativo (mais eficiente)
+pub fn fatorial_iterativo(n: u64) -> u64 {
+    let mut resultado = 1;
+    
+    for i in 2..=n {
+        resultado *= i;
+    }
+    
+    resultado // Retorno implícito
+}
+
+// ========================================
+
+/// Fibonacci recursivo (LENTO para n > 40)
+pub fn fibonacci_recursivo(n: u32) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => fibonacci_recursivo(n - 1) + fibonacci_recursivo(n - 2)
+    }
+}
+
+/// Fibonacci iterativo (RÁPIDO)
+pub fn fibonacci_iterativo(n: u32) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    if n == 1 {
+        return 1;
+    }
+    
+    let mut anterior = 0;
+    let mut atual = 1;
+    
+    for _ in 2..=n {
+        let proximo = anterior + atual;
+        anterior = atual;
+        atual = proximo;
+    }
+    
+    atual
+}
+
+/// Retorna (valor, tempo_ms) para comparar performance
+pub fn fibonacci_com_tempo(n: u32, usar_recursivo: bool) -> (u64, u128) {
+    use std::time::Instant;
+    
+    let inicio = Instant::now();
+    
+    let resultado = if usar_recursivo {
+        fibonacci_recursivo(n)
+    } else {
+        fibonacci_iterativo(n)
+    };
+    
+    let duracao = inicio.elapsed().as_millis();
+    
+    (resultado, duracao) // Tupla com resultado e tempo
+}
+
+// ========================================
+
+/// Verifica se um número é primo
+pub fn eh_primo(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if n == 2 {
+        return true;
+    }
+    if n % 2 == 0 {
+        return false; // Pares (exceto 2) não são primos
+    }
+    
+    // Só precisa testar até raiz quadrada de n
+    let limite = (n as f64).sqrt() as u64;
+    
+    for divisor in (3..=limite).step_by(2) {
+        if n % divisor == 0 {
+            return false;
+        }
+    }
+    
+    true
+}
+
+/// Retorna (eh_primo: bool, divisores: Vec<u64>)
+pub fn analisar_numero(n: u64) -> (bool, Vec<u64>) {
+    let primo = eh_primo(n);
+    
+    let mut divisores = Vec::new();
+    
+    if !primo {
+        for i in 2..=n {
+            if n % i == 0 {
+                divisores.push(i);
+            }
+        }
+    }
+    
+    (primo, divisores)
+}
+
+// ========================================
+
+/// Calcula o MDC (Máximo Divisor Comum) usando algoritmo de Euclides
+pub fn mdc(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        mdc(b, a % b) // Recursão elegante!
+    }
+}
+
+/// MDC iterativo (alternativa)
+pub fn mdc_iterativo(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+/// Calcula o MMC (Mínimo Múltiplo Comum)
+/// Fórmula: MMC(a, b) = (a * b) / MDC(a, b)
+pub fn mmc(a: u64, b: u64) -> u64 {
+    (a * b) / mdc(a, b)
+}
+
+/// Retorna (mdc, mmc) de uma vez
+pub fn mdc_mmc(a: u64, b: u64) -> (u64, u64) {
+    let divisor_comum = mdc(a, b);
+    let multiplo_comum = (a * b) / divisor_comum;
+    (divisor_comum, multiplo_comum)
+}
+
+// ========================================
+
+/// Encontra todos os primos até n (Crivo de Eratóstenes)
+pub fn primos_ate(n: u64) -> Vec<u64> {
+    if n < 2 {
+        return Vec::new();
+    }
+    
+    let mut eh_primo = vec![true; (n + 1) as usize];
+    eh_primo[0] = false;
+    eh_primo[1] = false;
+    
+    let limite = (n as f64).sqrt() as u64;
+    
+    for i in 2..=limite {
+        if eh_primo[i as usize] {
+            let mut multiplo = i * i;
+            while multiplo <= n {
+                eh_primo[multiplo as usize] = false;
+                multiplo += i;
+            }
+        }
+    }
+    
+    // Coleta os primos
+    let mut primos = Vec::new();
+    for (num, &primo) in eh_primo.iter().enumerate() {
+        if primo {
+            primos.push(num as u64);
+        }
+    }
+    
+    primos
+}
+
+// ========================================
+// TESTES AUTOMATIZADOS
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_fatorial() {
+        assert_eq!(fatorial(0), 1);
+        assert_eq!(fatorial(1), 1);
+        assert_eq!(fatorial(5), 120);
+        assert_eq!(fatorial(10), 3628800);
+    }
+    
+    #[test]
+    fn test_fatorial_iterativo() {
+        assert_eq!(fatorial_iterativo(0), 1);
+        assert_eq!(fatorial_iterativo(5), 120);
+        assert_eq!(fatorial_iterativo(10), 3628800);
+    }
+    
+    #[test]
+    fn test_fibonacci() {
+        assert_eq!(fibonacci_iterativo(0), 0);
+        assert_eq!(fibonacci_iterativo(1), 1);
+        assert_eq!(fibonacci_iterativo(10), 55);
+        assert_eq!(fibonacci_iterativo(20), 6765);
+    }
+    
+    #[test]
+    fn test_eh_primo() {
+        assert!(eh_primo(2));
+        assert!(eh_primo(3));
+        assert!(!eh_primo(4));
+        assert!(eh_primo(17));
+        assert!(eh_primo(97));
+        assert!(!eh_primo(100));
+    }
+    
+    #[test]
+    fn test_mdc() {
+        assert_eq!(mdc(12, 18), 6);
+        assert_eq!(mdc(24, 36), 12);
+        assert_eq!(mdc(7, 13), 1);
+    }
+    
+    #[test]
+    fn test_mmc() {
+        assert_eq!(mmc(12, 18), 36);
+        assert_eq!(mmc(4, 6), 12);
+        assert_eq!(mmc(7, 13), 91);
+    }
+    
+    #[test]
+    fn test_primos_ate() {
+        let primos = primos_ate(20);
+        assert_eq!(primos, vec![2, 3, 5, 7, 11, 13, 17, 19]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use crate::cientifica::{self, ModoAngular};
+use crate::expressao::{self, Comando, ErroExpressao, Expr};
+use crate::matriz::{self, Valor};
+
+/// Função definida pelo usuário, ex.: `f(a, b) = a^2 + b`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuncaoUsuario {
+    pub parametros: Vec<String>,
+    pub corpo: Expr,
+}
+
+/// O que aconteceu ao executar uma linha do REPL
+#[derive(Debug, Clone, PartialEq)]
+pub enum Resultado {
+    Valor(Valor),
+    Atribuido { nome: String, valor: Valor },
+    FuncaoDefinida { nome: String, parametros: Vec<String> },
+}
+
+/// Variáveis, funções, o último resultado (`ans`) e o modo angular de uma sessão
+pub struct Ambiente {
+    variaveis: HashMap<String, Valor>,
+    funcoes: HashMap<String, FuncaoUsuario>,
+    ans: Option<Valor>,
+    modo_angular: ModoAngular,
+}
+
+impl Ambiente {
+    pub fn novo() -> Self {
+        Ambiente {
+            variaveis: HashMap::new(),
+            funcoes: HashMap::new(),
+            ans: None,
+            modo_angular: ModoAngular::Graus,
+        }
+    }
+
+    pub fn modo_angular(&self) -> ModoAngular {
+        self.modo_angular
+    }
+
+    pub fn definir_modo_angular(&mut self, modo: ModoAngular) {
+        self.modo_angular = modo;
+    }
+
+    /// Procura uma variável; `ans` é o último resultado
+    pub fn variavel(&self, nome: &str) -> Option<Valor> {
+        match nome {
+            "ans" => self.ans.clone(),
+            _ => self.variaveis.get(nome).cloned(),
+        }
+    }
+
+    pub fn funcao(&self, nome: &str) -> Option<&FuncaoUsuario> {
+        self.funcoes.get(nome)
+    }
+
+    /// Registra o resultado de uma operação feita fora do REPL
+    pub fn definir_ans(&mut self, valor: f64) {
+        self.ans = Some(Valor::numero(valor));
+    }
+
+    /// Executa uma linha: avalia, atribui ou define uma função
+    pub fn executar(&mut self, entrada: &str) -> Result<Resultado, ErroExpressao> {
+        match expressao::analisar_comando(entrada)? {
+            Comando::Expressao(expr) => {
+                let valor = expressao::avaliar(&expr, self)?;
+                self.ans = Some(valor.clone());
+                Ok(Resultado::Valor(valor))
+            }
+            Comando::Atribuicao { nome, expr, posicao } => {
+                if nome == "ans" {
+                    return Err(ErroExpressao {
+                        posicao,
+                        mensagem: String::from("'ans' é reservado"),
+                    });
+                }
+                if cientifica::constante(&nome).is_some() {
+                    return Err(ErroExpressao {
+                        posicao,
+                        mensagem: format!("'{}' é uma constante", nome),
+                    });
+                }
+
+                let valor = expressao::avaliar(&expr, self)?;
+                self.variaveis.insert(nome.clone(), valor.clone());
+                self.ans = Some(valor.clone());
+                Ok(Resultado::Atribuido { nome, valor })
+            }
+            Comando::Definicao { nome, parametros, corpo, posicao } => {
+                if cientifica::aridade(&nome).is_some() || matriz::aridade(&nome).is_some() {
+                    return Err(ErroExpressao {
+                        posicao,
+                        mensagem: format!("'{}' é uma função embutida", nome),
+                    });
+                }
+
+                self.funcoes.insert(
+                    nome.clone(),
+                    FuncaoUsuario {
+                        parametros: parametros.clone(),
+                        corpo,
+                    },
+                );
+                Ok(Resultado::FuncaoDefinida { nome, parametros })
+            }
+        }
+    }
+
+    /// Lista variáveis e funções definidas na sessão
+    pub fn exibir(&self) {
+        if self.variaveis.is_empty() && self.funcoes.is_empty() && self.ans.is_none() {
+            println!("📦 Nenhuma variável ou função definida");
+            return;
+        }
+
+        println!("\n📦 AMBIENTE (ângulos em {}):", self.modo_angular);
+        if let Some(ans) = &self.ans {
+            println!("  ans = {}", resumir(ans));
+        }
+
+        let mut variaveis: Vec<_> = self.variaveis.iter().collect();
+        variaveis.sort_by(|a, b| a.0.cmp(b.0));
+        for (nome, valor) in variaveis {
+            println!("  {} = {}", nome, resumir(valor));
+        }
+
+        let mut funcoes: Vec<_> = self.funcoes.iter().collect();
+        funcoes.sort_by(|a, b| a.0.cmp(b.0));
+        for (nome, funcao) in funcoes {
+            println!("  {}({})", nome, funcao.parametros.join(", "));
+        }
+    }
+}
+
+/// Matrizes numa linha só, para caberem na listagem
+fn resumir(valor: &Valor) -> String {
+    match valor {
+        Valor::Matriz(m) => m.em_linha(),
+        escalar => escalar.to_string(),
+    }
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atribuicao_e_uso() {
+        let mut ambiente = Ambiente::novo();
+        assert_eq!(
+            ambiente.executar("x = 12.5"),
+            Ok(Resultado::Atribuido { nome: String::from("x"), valor: Valor::numero(12.5) })
+        );
+        assert_eq!(ambiente.executar("x * 2"), Ok(Resultado::Valor(Valor::numero(25.0))));
+    }
+
+    #[test]
+    fn test_ans() {
+        let mut ambiente = Ambiente::novo();
+        assert!(ambiente.executar("ans + 1").is_err());
+
+        ambiente.executar("2 + 3").unwrap();
+        assert_eq!(ambiente.executar("ans * 10"), Ok(Resultado::Valor(Valor::numero(50.0))));
+        assert_eq!(ambiente.executar("ans / 2"), Ok(Resultado::Valor(Valor::numero(25.0))));
+        assert!(ambiente.executar("ans = 3").is_err());
+    }
+
+    #[test]
+    fn test_funcao_usuario() {
+        let mut ambiente = Ambiente::novo();
+        ambiente.executar("f(a, b) = a^2 + b").unwrap();
+        assert_eq!(ambiente.executar("f(3, 1)"), Ok(Resultado::Valor(Valor::numero(10.0))));
+
+        // Parâmetros escondem variáveis globais com o mesmo nome
+        ambiente.executar("a = 100").unwrap();
+        assert_eq!(ambiente.executar("f(2, a)"), Ok(Resultado::Valor(Valor::numero(104.0))));
+
+        // Funções podem chamar outras funções
+        ambiente.executar("g(x) = f(x, x) * 2").unwrap();
+        assert_eq!(ambiente.executar("g(2)"), Ok(Resultado::Valor(Valor::numero(12.0))));
+    }
+
+    #[test]
+    fn test_modo_angular() {
+        let mut ambiente = Ambiente::novo();
+        assert_eq!(ambiente.executar("cos(180)"), Ok(Resultado::Valor(Valor::numero(-1.0))));
+
+        ambiente.definir_modo_angular(ModoAngular::Radianos);
+        assert_eq!(ambiente.executar("cos(pi)"), Ok(Resultado::Valor(Valor::numero(-1.0))));
+    }
+
+    #[test]
+    fn test_variaveis_com_unidade() {
+        let mut ambiente = Ambiente::novo();
+        ambiente.executar("d = 3 km").unwrap();
+        ambiente.executar("v(t) = d / t").unwrap();
+        assert_eq!(ambiente.executar("d + 500 m").unwrap(), ambiente.executar("3.5 km").unwrap());
+        match ambiente.executar("v(10 min)") {
+            Ok(Resultado::Valor(velocidade)) => assert_eq!(velocidade.to_string(), "5 m/s"),
+            outro => panic!("esperava valor, obteve {:?}", outro),
+        }
+
+        // Variável com o nome de uma unidade esconde a unidade solta, mas não `2 m`
+        ambiente.executar("m = 10").unwrap();
+        assert_eq!(ambiente.executar("m * 2"), Ok(Resultado::Valor(Valor::numero(20.0))));
+        assert!(ambiente.executar("2 m + 1").is_err());
+    }
+
+    #[test]
+    fn test_variaveis_matriz() {
+        let mut ambiente = Ambiente::novo();
+        ambiente.executar("A = [2, 1; 1, 3]").unwrap();
+        ambiente.executar("b = [3; 5]").unwrap();
+        match ambiente.executar("x = solve(A, b)") {
+            Ok(Resultado::Atribuido { valor: Valor::Matriz(x), .. }) => assert_eq!(x.em_linha(), "[0.8; 1.4]"),
+            outro => panic!("esperava matriz, obteve {:?}", outro),
+        }
+        assert_eq!(ambiente.executar("det(2 * A)"), Ok(Resultado::Valor(Valor::numero(20.0))));
+        assert!(ambiente.executar("A + x").is_err());
+        assert!(ambiente.executar("det(a) = a").is_err());
+    }
+
+    #[test]
+    fn test_erros_funcao_usuario() {
+        let mut ambiente = Ambiente::novo();
+        ambiente.executar("f(a) = a + 1").unwrap();
+        assert!(ambiente.executar("f(1, 2)").is_err());
+        assert!(ambiente.executar("sqrt(x) = x").is_err());
+        assert!(ambiente.executar("nCr(a, b) = a").is_err());
+        assert!(ambiente.executar("pi = 3").is_err());
+
+        ambiente.executar("loop(x) = loop(x)").unwrap();
+        assert_eq!(ambiente.executar("loop(1)").unwrap_err().mensagem, "Recursão profunda demais");
+
+        // Corpo fundo e recursão juntos também param com erro, não com a pilha estourada
+        let corpo = format!("{}fundo(x){}", "(".repeat(200), " + 1)".repeat(200));
+        ambiente.executar(&format!("fundo(x) = {}", corpo)).unwrap();
+        assert_eq!(ambiente.executar("fundo(1)").unwrap_err().mensagem, "Recursão profunda demais");
+    }
+}
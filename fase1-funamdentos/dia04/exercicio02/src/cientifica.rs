@@ -0,0 +1,287 @@
+use std::f64::consts;
+use std::fmt;
+
+use exercicio01::{fatorial, mdc, mmc};
+
+use crate::erro::{CalcError, verificar};
+use crate::raiz_quadrada;
+
+// ========================================
+// MODO ANGULAR
+// ========================================
+
+/// Unidade dos ângulos das funções trigonométricas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModoAngular {
+    Graus,
+    Radianos,
+}
+
+impl fmt::Display for ModoAngular {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ModoAngular::Graus => write!(f, "graus"),
+            ModoAngular::Radianos => write!(f, "radianos"),
+        }
+    }
+}
+
+// ========================================
+// CATÁLOGO
+// ========================================
+
+/// (nome, quantidade de argumentos, descrição)
+pub const FUNCOES: [(&str, usize, &str); 19] = [
+    ("sin", 1, "seno"),
+    ("cos", 1, "cosseno"),
+    ("tan", 1, "tangente"),
+    ("asin", 1, "arco seno"),
+    ("acos", 1, "arco cosseno"),
+    ("atan", 1, "arco tangente"),
+    ("log", 1, "logaritmo na base 10"),
+    ("ln", 1, "logaritmo natural"),
+    ("exp", 1, "e elevado a x"),
+    ("sqrt", 1, "raiz quadrada"),
+    ("fact", 1, "fatorial"),
+    ("ncr", 2, "combinações de n, r a r"),
+    ("npr", 2, "arranjos de n, r a r"),
+    ("gcd", 2, "máximo divisor comum (MDC)"),
+    ("lcm", 2, "mínimo múltiplo comum (MMC)"),
+    ("abs", 1, "valor absoluto"),
+    ("floor", 1, "arredonda para baixo"),
+    ("ceil", 1, "arredonda para cima"),
+    ("round", 1, "arredonda para o mais próximo"),
+];
+
+pub const CONSTANTES: [(&str, f64); 2] = [("pi", consts::PI), ("e", consts::E)];
+
+/// Nomes são aceitos em qualquer caixa (`nCr`, `SIN`...)
+fn normalizar(nome: &str) -> String {
+    nome.to_lowercase()
+}
+
+/// Quantos argumentos a função embutida espera, ou `None` se ela não existir
+pub fn aridade(nome: &str) -> Option<usize> {
+    let nome = normalizar(nome);
+    FUNCOES.iter().find(|(n, _, _)| *n == nome).map(|(_, aridade, _)| *aridade)
+}
+
+pub fn constante(nome: &str) -> Option<f64> {
+    let nome = normalizar(nome);
+    CONSTANTES.iter().find(|(n, _)| *n == nome).map(|(_, valor)| *valor)
+}
+
+// ========================================
+// AUXILIARES
+// ========================================
+
+/// Converte para `u64` se for inteiro não negativo. `u64::MAX as f64` arredonda
+/// para 2^64, que já não cabe: por isso `>=`
+fn natural(x: f64, funcao: &'static str) -> Result<u64, CalcError> {
+    if x < 0.0 || x.fract() != 0.0 || x >= u64::MAX as f64 {
+        Err(CalcError::ForaDoDominio(funcao))
+    } else {
+        Ok(x as u64)
+    }
+}
+
+/// Fatorial em f64: usa `fatorial` (u64) até 20! e continua em f64 depois disso
+fn fatorial_real(n: u64) -> Result<f64, CalcError> {
+    const MAIOR_EM_U64: u64 = 20;
+
+    if n <= MAIOR_EM_U64 {
+        return Ok(fatorial(n) as f64);
+    }
+
+    let mut resultado = fatorial(MAIOR_EM_U64) as f64;
+    for i in MAIOR_EM_U64 + 1..=n {
+        resultado = verificar(resultado * i as f64)?;
+    }
+    Ok(resultado)
+}
+
+fn arranjos(n: u64, r: u64) -> Result<f64, CalcError> {
+    if r > n {
+        return Err(CalcError::ForaDoDominio("npr"));
+    }
+    let mut resultado = 1.0;
+    for i in 0..r {
+        resultado = verificar(resultado * (n - i) as f64)?;
+    }
+    Ok(resultado)
+}
+
+fn combinacoes(n: u64, r: u64) -> Result<f64, CalcError> {
+    if r > n {
+        return Err(CalcError::ForaDoDominio("ncr"));
+    }
+    // C(n, r) = C(n, n - r); usar o menor deixa o laço curto
+    let r = r.min(n - r);
+    let mut resultado = 1.0;
+    for i in 1..=r {
+        resultado = verificar(resultado * (n - r + i) as f64 / i as f64)?;
+    }
+    Ok(resultado.round())
+}
+
+fn minimo_multiplo(a: u64, b: u64) -> Result<f64, CalcError> {
+    if a == 0 || b == 0 {
+        return Ok(0.0);
+    }
+    // `mmc` calcula a * b antes de dividir
+    a.checked_mul(b).ok_or(CalcError::Overflow)?;
+    Ok(mmc(a, b) as f64)
+}
+
+/// Seno e cosseno exatos nos múltiplos de 90° (evita `sin(180) = 1.2e-16`)
+fn seno_cosseno_graus(graus: f64) -> Option<(f64, f64)> {
+    if graus % 90.0 != 0.0 {
+        return None;
+    }
+    match (graus / 90.0).rem_euclid(4.0) as u8 {
+        0 => Some((0.0, 1.0)),
+        1 => Some((1.0, 0.0)),
+        2 => Some((0.0, -1.0)),
+        _ => Some((-1.0, 0.0)),
+    }
+}
+
+fn dominio(condicao: bool, funcao: &'static str) -> Result<(), CalcError> {
+    if condicao {
+        Ok(())
+    } else {
+        Err(CalcError::ForaDoDominio(funcao))
+    }
+}
+
+// ========================================
+// APLICAÇÃO
+// ========================================
+
+/// Aplica a função embutida `nome`; `None` se a função não existir.
+/// A quantidade de argumentos deve ter sido conferida com `aridade`.
+pub fn aplicar(nome: &str, args: &[f64], modo: ModoAngular) -> Option<Result<f64, CalcError>> {
+    let para_radianos = |x: f64| match modo {
+        ModoAngular::Graus => x.to_radians(),
+        ModoAngular::Radianos => x,
+    };
+    let do_radianos = |x: f64| match modo {
+        ModoAngular::Graus => x.to_degrees(),
+        ModoAngular::Radianos => x,
+    };
+    let exato = |x: f64| match modo {
+        ModoAngular::Graus => seno_cosseno_graus(x),
+        ModoAngular::Radianos => None,
+    };
+
+    let resultado = match (normalizar(nome).as_str(), args) {
+        ("sin", [x]) => Ok(exato(*x).map_or_else(|| para_radianos(*x).sin(), |(s, _)| s)),
+        ("cos", [x]) => Ok(exato(*x).map_or_else(|| para_radianos(*x).cos(), |(_, c)| c)),
+        ("tan", [x]) => match exato(*x) {
+            Some((_, 0.0)) => Err(CalcError::Infinito),
+            Some((s, c)) => Ok(s / c),
+            None => verificar(para_radianos(*x).tan()),
+        },
+        ("asin", [x]) => dominio((-1.0..=1.0).contains(x), "asin").map(|_| do_radianos(x.asin())),
+        ("acos", [x]) => dominio((-1.0..=1.0).contains(x), "acos").map(|_| do_radianos(x.acos())),
+        ("atan", [x]) => Ok(do_radianos(x.atan())),
+        ("log", [x]) => dominio(*x > 0.0, "log").map(|_| x.log10()),
+        ("ln", [x]) => dominio(*x > 0.0, "ln").map(|_| x.ln()),
+        ("exp", [x]) => verificar(x.exp()),
+        ("sqrt", [x]) => raiz_quadrada(*x),
+        ("fact", [n]) => natural(*n, "fact").and_then(fatorial_real),
+        ("ncr", [n, r]) => natural(*n, "ncr").and_then(|n| combinacoes(n, natural(*r, "ncr")?)),
+        ("npr", [n, r]) => natural(*n, "npr").and_then(|n| arranjos(n, natural(*r, "npr")?)),
+        ("gcd", [a, b]) => natural(*a, "gcd").and_then(|a| Ok(mdc(a, natural(*b, "gcd")?) as f64)),
+        ("lcm", [a, b]) => natural(*a, "lcm").and_then(|a| minimo_multiplo(a, natural(*b, "lcm")?)),
+        ("abs", [x]) => Ok(x.abs()),
+        ("floor", [x]) => Ok(x.floor()),
+        ("ceil", [x]) => Ok(x.ceil()),
+        ("round", [x]) => Ok(x.round()),
+        _ => return None,
+    };
+
+    Some(resultado)
+}
+
+/// Lista as funções e constantes disponíveis
+pub fn exibir_catalogo() {
+    println!("\n📚 FUNÇÕES DISPONÍVEIS:");
+    for (nome, aridade, descricao) in FUNCOES.iter() {
+        let assinatura = if *aridade == 2 {
+            format!("{}(a, b)", nome)
+        } else {
+            format!("{}(x)", nome)
+        };
+        println!("  {:<12} {}", assinatura, descricao);
+    }
+    println!("  Constantes: pi, e");
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-9;
+
+    fn calc(nome: &str, args: &[f64], modo: ModoAngular) -> Result<f64, CalcError> {
+        aplicar(nome, args, modo).expect("função deveria existir")
+    }
+
+    #[test]
+    fn test_trigonometria_graus_e_radianos() {
+        assert_eq!(calc("sin", &[180.0], ModoAngular::Graus), Ok(0.0));
+        assert_eq!(calc("cos", &[-90.0], ModoAngular::Graus), Ok(0.0));
+        assert!((calc("sin", &[30.0], ModoAngular::Graus).unwrap() - 0.5).abs() < EPS);
+        assert!((calc("sin", &[consts::FRAC_PI_6], ModoAngular::Radianos).unwrap() - 0.5).abs() < EPS);
+        assert_eq!(calc("tan", &[90.0], ModoAngular::Graus), Err(CalcError::Infinito));
+        assert!((calc("asin", &[1.0], ModoAngular::Graus).unwrap() - 90.0).abs() < EPS);
+        assert_eq!(calc("acos", &[2.0], ModoAngular::Graus), Err(CalcError::ForaDoDominio("acos")));
+    }
+
+    #[test]
+    fn test_logaritmos() {
+        assert_eq!(calc("log", &[1000.0], ModoAngular::Radianos), Ok(3.0));
+        assert_eq!(calc("ln", &[1.0], ModoAngular::Radianos), Ok(0.0));
+        assert_eq!(calc("ln", &[0.0], ModoAngular::Radianos), Err(CalcError::ForaDoDominio("ln")));
+        assert_eq!(calc("exp", &[1000.0], ModoAngular::Radianos), Err(CalcError::Overflow));
+    }
+
+    #[test]
+    fn test_fatorial_e_combinatoria() {
+        assert_eq!(calc("fact", &[5.0], ModoAngular::Radianos), Ok(120.0));
+        assert_eq!(calc("fact", &[25.0], ModoAngular::Radianos), Ok(1.5511210043330986e25));
+        assert_eq!(calc("fact", &[171.0], ModoAngular::Radianos), Err(CalcError::Overflow));
+        assert_eq!(calc("fact", &[2.5], ModoAngular::Radianos), Err(CalcError::ForaDoDominio("fact")));
+        assert_eq!(calc("nCr", &[5.0, 2.0], ModoAngular::Radianos), Ok(10.0));
+        assert_eq!(calc("nPr", &[5.0, 2.0], ModoAngular::Radianos), Ok(20.0));
+        assert_eq!(calc("ncr", &[2.0, 5.0], ModoAngular::Radianos), Err(CalcError::ForaDoDominio("ncr")));
+    }
+
+    #[test]
+    fn test_mdc_mmc() {
+        assert_eq!(calc("gcd", &[12.0, 18.0], ModoAngular::Radianos), Ok(6.0));
+        assert_eq!(calc("lcm", &[4.0, 6.0], ModoAngular::Radianos), Ok(12.0));
+        assert_eq!(calc("lcm", &[0.0, 6.0], ModoAngular::Radianos), Ok(0.0));
+        assert_eq!(calc("lcm", &[1e18, 1e18], ModoAngular::Radianos), Err(CalcError::Overflow));
+
+        let dois_a_64 = 2f64.powi(64);
+        assert_eq!(calc("gcd", &[dois_a_64, 2.0], ModoAngular::Radianos), Err(CalcError::ForaDoDominio("gcd")));
+        assert_eq!(calc("gcd", &[2f64.powi(63), 2.0], ModoAngular::Radianos), Ok(2.0));
+    }
+
+    #[test]
+    fn test_arredondamento_e_catalogo() {
+        assert_eq!(calc("round", &[2.5], ModoAngular::Radianos), Ok(3.0));
+        assert_eq!(calc("floor", &[-2.5], ModoAngular::Radianos), Ok(-3.0));
+        assert_eq!(calc("abs", &[-2.5], ModoAngular::Radianos), Ok(2.5));
+        assert_eq!(aridade("gcd"), Some(2));
+        assert_eq!(aridade("xyz"), None);
+        assert!(aplicar("xyz", &[1.0], ModoAngular::Radianos).is_none());
+        assert_eq!(constante("PI"), Some(consts::PI));
+    }
+}
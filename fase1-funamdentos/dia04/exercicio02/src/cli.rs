@@ -0,0 +1,402 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use serde::Serialize;
+
+use crate::ambiente::{Ambiente, Resultado};
+use crate::cientifica::ModoAngular;
+use crate::matriz::Valor;
+
+// ========================================
+// MODO NÃO INTERATIVO
+// ========================================
+
+/// Todas as expressões foram avaliadas
+pub const SUCESSO: u8 = 0;
+/// Alguma expressão falhou (sintaxe, domínio, overflow...)
+pub const FALHA_EXPRESSAO: u8 = 1;
+/// Argumentos inválidos ou arquivo ilegível
+pub const FALHA_USO: u8 = 2;
+
+const USO: &str = "\
+Uso: calc [opções] <expressão>...
+     calc [opções] --file <arquivo>
+     calc                          (sem argumentos abre o menu interativo)
+
+Opções:
+  -f, --file <arquivo>   avalia uma expressão por linha (linhas vazias e '#' são ignoradas)
+      --format <fmt>     'text' (padrão) ou 'json' (um objeto JSON por expressão)
+      --radianos         ângulos em radianos (padrão: graus)
+  -h, --help             mostra esta ajuda";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FormatoSaida {
+    Texto,
+    Json,
+}
+
+/// De onde vêm as expressões
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fonte {
+    Argumentos(Vec<String>),
+    Arquivo(PathBuf),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Opcoes {
+    pub fonte: Fonte,
+    pub formato: FormatoSaida,
+    pub modo_angular: ModoAngular,
+}
+
+/// O que a linha de comando pediu
+#[derive(Debug, Clone, PartialEq)]
+pub enum Acao {
+    Avaliar(Opcoes),
+    Ajuda,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErroArgumentos(String);
+
+impl fmt::Display for ErroArgumentos {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Interpreta os argumentos (sem o nome do programa)
+pub fn analisar_argumentos<I>(argumentos: I) -> Result<Acao, ErroArgumentos>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut argumentos = argumentos.into_iter();
+    let mut expressoes = Vec::new();
+    let mut arquivo = None;
+    let mut formato = FormatoSaida::Texto;
+    let mut modo_angular = ModoAngular::Graus;
+    let mut apenas_expressoes = false;
+
+    while let Some(argumento) = argumentos.next() {
+        if apenas_expressoes {
+            expressoes.push(argumento);
+            continue;
+        }
+
+        match argumento.as_str() {
+            "-h" | "--help" => return Ok(Acao::Ajuda),
+            "-f" | "--file" => {
+                let caminho = argumentos
+                    .next()
+                    .ok_or_else(|| ErroArgumentos(format!("'{}' precisa de um arquivo", argumento)))?;
+                arquivo = Some(PathBuf::from(caminho));
+            }
+            "--format" => {
+                formato = match argumentos.next().as_deref() {
+                    Some("text") => FormatoSaida::Texto,
+                    Some("json") => FormatoSaida::Json,
+                    Some(outro) => {
+                        return Err(ErroArgumentos(format!("Formato desconhecido '{}' (use text ou json)", outro)));
+                    }
+                    None => return Err(ErroArgumentos(String::from("'--format' precisa de text ou json"))),
+                };
+            }
+            "--radianos" => modo_angular = ModoAngular::Radianos,
+            // Depois de `--` tudo é expressão, mesmo começando com '-'
+            "--" => apenas_expressoes = true,
+            _ if argumento.starts_with("--") => {
+                return Err(ErroArgumentos(format!("Opção desconhecida '{}'", argumento)));
+            }
+            _ => expressoes.push(argumento),
+        }
+    }
+
+    let fonte = match (arquivo, expressoes.is_empty()) {
+        (Some(caminho), true) => Fonte::Arquivo(caminho),
+        (Some(_), false) => {
+            return Err(ErroArgumentos(String::from("Use '--file' ou expressões na linha de comando, não os dois")));
+        }
+        (None, false) => Fonte::Argumentos(expressoes),
+        (None, true) => return Err(ErroArgumentos(String::from("Nenhuma expressão informada"))),
+    };
+
+    Ok(Acao::Avaliar(Opcoes {
+        fonte,
+        formato,
+        modo_angular,
+    }))
+}
+
+/// Linha de saída no formato JSON
+#[derive(Debug, Serialize)]
+struct SaidaJson<'a> {
+    linha: usize,
+    entrada: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resultado: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unidade: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    matriz: Option<Vec<Vec<f64>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variavel: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    funcao: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    erro: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    posicao: Option<usize>,
+}
+
+impl<'a> SaidaJson<'a> {
+    fn nova(linha: usize, entrada: &'a str) -> Self {
+        SaidaJson {
+            linha,
+            entrada,
+            resultado: None,
+            unidade: None,
+            matriz: None,
+            variavel: None,
+            funcao: None,
+            erro: None,
+            posicao: None,
+        }
+    }
+
+    /// Número em `resultado` (com `unidade`, se houver) ou linhas em `matriz`
+    fn preencher(&mut self, valor: &Valor) {
+        match valor {
+            Valor::Escalar(q) => {
+                self.resultado = Some(q.valor_exibido());
+                self.unidade = q.unidade_exibida();
+            }
+            Valor::Matriz(m) => self.matriz = Some(m.para_linhas()),
+        }
+    }
+}
+
+/// Avalia as linhas em sequência, num mesmo ambiente (atribuições valem para as
+/// linhas seguintes). Resultados vão para `saida`; no formato texto, erros vão
+/// para `erros`. Continua depois de um erro e devolve o código de saída.
+pub fn avaliar_linhas<'a, I, S, E>(
+    linhas: I,
+    formato: FormatoSaida,
+    ambiente: &mut Ambiente,
+    saida: &mut S,
+    erros: &mut E,
+) -> io::Result<u8>
+where
+    I: IntoIterator<Item = (usize, &'a str)>,
+    S: Write,
+    E: Write,
+{
+    let mut codigo = SUCESSO;
+
+    for (numero, entrada) in linhas {
+        let resultado = ambiente.executar(entrada);
+        if resultado.is_err() {
+            codigo = FALHA_EXPRESSAO;
+        }
+
+        match formato {
+            FormatoSaida::Texto => match resultado {
+                Ok(Resultado::Valor(valor)) => writeln!(saida, "{}", valor)?,
+                Ok(Resultado::Atribuido { nome, valor }) => writeln!(saida, "{} = {}", nome, valor)?,
+                Ok(Resultado::FuncaoDefinida { nome, parametros }) => {
+                    writeln!(saida, "{}({}) definida", nome, parametros.join(", "))?
+                }
+                Err(erro) => {
+                    writeln!(erros, "linha {}: {}", numero, erro)?;
+                    writeln!(erros, "{}", erro.destacar(entrada))?;
+                }
+            },
+            FormatoSaida::Json => {
+                let mut linha = SaidaJson::nova(numero, entrada);
+                match &resultado {
+                    Ok(Resultado::Valor(valor)) => linha.preencher(valor),
+                    Ok(Resultado::Atribuido { nome, valor }) => {
+                        linha.variavel = Some(nome);
+                        linha.preencher(valor);
+                    }
+                    Ok(Resultado::FuncaoDefinida { nome, .. }) => linha.funcao = Some(nome),
+                    Err(erro) => {
+                        linha.erro = Some(erro.mensagem.clone());
+                        linha.posicao = Some(erro.posicao + 1);
+                    }
+                }
+                let json = serde_json::to_string(&linha).map_err(io::Error::other)?;
+                writeln!(saida, "{}", json)?;
+            }
+        }
+    }
+
+    Ok(codigo)
+}
+
+/// Linhas de um arquivo de expressões, numeradas a partir de 1, sem vazias e comentários
+fn linhas_do_arquivo(conteudo: &str) -> impl Iterator<Item = (usize, &str)> {
+    conteudo
+        .lines()
+        .enumerate()
+        .map(|(i, linha)| (i + 1, linha.trim()))
+        .filter(|(_, linha)| !linha.is_empty() && !linha.starts_with('#'))
+}
+
+/// Ponto de entrada do modo não interativo
+pub fn executar<I>(argumentos: I) -> ExitCode
+where
+    I: IntoIterator<Item = String>,
+{
+    let opcoes = match analisar_argumentos(argumentos) {
+        Ok(Acao::Avaliar(opcoes)) => opcoes,
+        Ok(Acao::Ajuda) => {
+            println!("{}", USO);
+            return ExitCode::from(SUCESSO);
+        }
+        Err(erro) => {
+            eprintln!("calc: {}\n\n{}", erro, USO);
+            return ExitCode::from(FALHA_USO);
+        }
+    };
+
+    let mut ambiente = Ambiente::novo();
+    ambiente.definir_modo_angular(opcoes.modo_angular);
+
+    let conteudo;
+    let resultado = match &opcoes.fonte {
+        Fonte::Argumentos(expressoes) => {
+            let linhas = expressoes.iter().enumerate().map(|(i, e)| (i + 1, e.as_str()));
+            avaliar_linhas(linhas, opcoes.formato, &mut ambiente, &mut io::stdout(), &mut io::stderr())
+        }
+        Fonte::Arquivo(caminho) => {
+            conteudo = match fs::read_to_string(caminho) {
+                Ok(conteudo) => conteudo,
+                Err(erro) => {
+                    eprintln!("calc: não foi possível ler {}: {}", caminho.display(), erro);
+                    return ExitCode::from(FALHA_USO);
+                }
+            };
+            avaliar_linhas(
+                linhas_do_arquivo(&conteudo),
+                opcoes.formato,
+                &mut ambiente,
+                &mut io::stdout(),
+                &mut io::stderr(),
+            )
+        }
+    };
+
+    match resultado {
+        Ok(codigo) => ExitCode::from(codigo),
+        Err(erro) => {
+            eprintln!("calc: erro de escrita: {}", erro);
+            ExitCode::from(FALHA_USO)
+        }
+    }
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argumentos(lista: &[&str]) -> Vec<String> {
+        lista.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn avaliar(linhas: &[&str], formato: FormatoSaida) -> (u8, String, String) {
+        let mut saida = Vec::new();
+        let mut erros = Vec::new();
+        let codigo = avaliar_linhas(
+            linhas.iter().enumerate().map(|(i, l)| (i + 1, *l)),
+            formato,
+            &mut Ambiente::novo(),
+            &mut saida,
+            &mut erros,
+        )
+        .unwrap();
+        (codigo, String::from_utf8(saida).unwrap(), String::from_utf8(erros).unwrap())
+    }
+
+    #[test]
+    fn test_analisar_argumentos() {
+        assert_eq!(
+            analisar_argumentos(argumentos(&["2 + 3 * 4"])),
+            Ok(Acao::Avaliar(Opcoes {
+                fonte: Fonte::Argumentos(argumentos(&["2 + 3 * 4"])),
+                formato: FormatoSaida::Texto,
+                modo_angular: ModoAngular::Graus,
+            }))
+        );
+        assert_eq!(
+            analisar_argumentos(argumentos(&["--format", "json", "--radianos", "--file", "exprs.txt"])),
+            Ok(Acao::Avaliar(Opcoes {
+                fonte: Fonte::Arquivo(PathBuf::from("exprs.txt")),
+                formato: FormatoSaida::Json,
+                modo_angular: ModoAngular::Radianos,
+            }))
+        );
+        assert_eq!(analisar_argumentos(argumentos(&["-h"])), Ok(Acao::Ajuda));
+
+        // `--` permite expressões que começam com '-'
+        let Ok(Acao::Avaliar(opcoes)) = analisar_argumentos(argumentos(&["--", "-2 * 3"])) else {
+            panic!("esperava expressões");
+        };
+        assert_eq!(opcoes.fonte, Fonte::Argumentos(argumentos(&["-2 * 3"])));
+    }
+
+    #[test]
+    fn test_argumentos_invalidos() {
+        assert!(analisar_argumentos(argumentos(&[])).is_err());
+        assert!(analisar_argumentos(argumentos(&["--file"])).is_err());
+        assert!(analisar_argumentos(argumentos(&["--format", "xml", "1"])).is_err());
+        assert!(analisar_argumentos(argumentos(&["--verbose", "1"])).is_err());
+        assert!(analisar_argumentos(argumentos(&["--file", "a.txt", "1 + 1"])).is_err());
+    }
+
+    #[test]
+    fn test_avaliar_texto() {
+        let (codigo, saida, erros) = avaliar(&["x = 2", "2 + 3 * x", "f(a) = a * a", "f(ans)"], FormatoSaida::Texto);
+        assert_eq!(codigo, SUCESSO);
+        assert_eq!(saida, "x = 2\n8\nf(a) definida\n64\n");
+        assert!(erros.is_empty());
+    }
+
+    #[test]
+    fn test_erro_continua_e_falha() {
+        let (codigo, saida, erros) = avaliar(&["1 / 0", "1 + 1"], FormatoSaida::Texto);
+        assert_eq!(codigo, FALHA_EXPRESSAO);
+        assert_eq!(saida, "2\n");
+        assert!(erros.starts_with("linha 1: Divisão por zero"));
+    }
+
+    #[test]
+    fn test_avaliar_json() {
+        let (codigo, saida, erros) = avaliar(&["y = 1.5", "sqrt(-1)", "3 km + 200 m", "[1, 2; 3, 4]"], FormatoSaida::Json);
+        assert_eq!(codigo, FALHA_EXPRESSAO);
+        assert!(erros.is_empty());
+
+        let linhas: Vec<serde_json::Value> = saida.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(linhas[0], serde_json::json!({"linha": 1, "entrada": "y = 1.5", "variavel": "y", "resultado": 1.5}));
+        assert_eq!(linhas[1]["linha"], 2);
+        assert_eq!(linhas[1]["posicao"], 1);
+        assert!(linhas[1]["erro"].is_string());
+        assert!(linhas[1].get("resultado").is_none());
+        assert_eq!(linhas[2]["resultado"], 3.2);
+        assert_eq!(linhas[2]["unidade"], "km");
+        assert_eq!(linhas[3]["matriz"], serde_json::json!([[1.0, 2.0], [3.0, 4.0]]));
+    }
+
+    #[test]
+    fn test_linhas_do_arquivo() {
+        let conteudo = "# comentário\n1 + 1\n\n  2 * 3  \n";
+        let linhas: Vec<_> = linhas_do_arquivo(conteudo).collect();
+        assert_eq!(linhas, vec![(2, "1 + 1"), (4, "2 * 3")]);
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+
+/// Erro de uma operação da calculadora
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalcError {
+    // --- Domínio: a operação não é definida para esses operandos ---
+    DivisaoPorZero,
+    RestoPorZero,
+    RaizNegativa,
+    /// Argumento fora do domínio da função científica (ex.: `ln(0)`, `asin(2)`, `fact(2.5)`)
+    ForaDoDominio(&'static str),
+    /// O modo numérico atual não representa o resultado (ex.: `2^0.5` em racional)
+    NaoRepresentavel,
+    /// Operandos com dimensões que não combinam (ex.: `3 m + 2 s`, `sin(2 m)`)
+    UnidadesIncompativeis,
+    /// Operação entre matriz e número que não existe (ex.: `[1, 2] + 1`, `sin([1, 2])`, `det(3)`)
+    TiposIncompativeis,
+    /// Dimensões das matrizes não combinam (ex.: `[1, 2] + [1, 2, 3]`)
+    DimensoesIncompativeis {
+        operacao: &'static str,
+        esquerda: (usize, usize),
+        direita: (usize, usize),
+    },
+    /// Determinante, inversa e potência só existem para matrizes quadradas
+    MatrizNaoQuadrada { linhas: usize, colunas: usize },
+    /// A matriz não tem inversa (determinante zero)
+    MatrizSingular,
+
+    // --- Resultado que não é um número finito ---
+    /// O resultado passou de `f64::MAX` (ex.: `10^400`)
+    Overflow,
+    /// O resultado é infinito por definição (ex.: `0^-1`)
+    Infinito,
+    /// O resultado não é um número (NaN, ex.: `(-8)^(1/3)`)
+    NaoNumerico,
+
+    // --- Entrada ---
+    OperadorDesconhecido(char),
+}
+
+/// Agrupa os erros para o menu poder reagir a cada tipo
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CategoriaErro {
+    Dominio,
+    ResultadoNaoFinito,
+    Operador,
+}
+
+impl CalcError {
+    pub fn categoria(&self) -> CategoriaErro {
+        match self {
+            CalcError::DivisaoPorZero
+            | CalcError::RestoPorZero
+            | CalcError::RaizNegativa
+            | CalcError::ForaDoDominio(_)
+            | CalcError::NaoRepresentavel
+            | CalcError::UnidadesIncompativeis
+            | CalcError::TiposIncompativeis
+            | CalcError::DimensoesIncompativeis { .. }
+            | CalcError::MatrizNaoQuadrada { .. }
+            | CalcError::MatrizSingular => CategoriaErro::Dominio,
+            CalcError::Overflow | CalcError::Infinito | CalcError::NaoNumerico => {
+                CategoriaErro::ResultadoNaoFinito
+            }
+            CalcError::OperadorDesconhecido(_) => CategoriaErro::Operador,
+        }
+    }
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalcError::DivisaoPorZero => write!(f, "Divisão por zero"),
+            CalcError::RestoPorZero => write!(f, "Resto por zero"),
+            CalcError::RaizNegativa => write!(f, "Raiz quadrada de número negativo"),
+            CalcError::ForaDoDominio(funcao) => write!(f, "Argumento fora do domínio de {}", funcao),
+            CalcError::NaoRepresentavel => write!(f, "Resultado não representável neste modo"),
+            CalcError::UnidadesIncompativeis => write!(f, "Unidades incompatíveis"),
+            CalcError::TiposIncompativeis => write!(f, "Tipos incompatíveis"),
+            CalcError::DimensoesIncompativeis { operacao, esquerda, direita } => write!(
+                f,
+                "Dimensões incompatíveis em {}: {}×{} e {}×{}",
+                operacao, esquerda.0, esquerda.1, direita.0, direita.1
+            ),
+            CalcError::MatrizNaoQuadrada { linhas, colunas } => {
+                write!(f, "Matriz {}×{} não é quadrada", linhas, colunas)
+            }
+            CalcError::MatrizSingular => write!(f, "Matriz singular (sem inversa)"),
+            CalcError::Overflow => write!(f, "Resultado grande demais (overflow)"),
+            CalcError::Infinito => write!(f, "Resultado infinito"),
+            CalcError::NaoNumerico => write!(f, "Resultado indefinido (NaN)"),
+            CalcError::OperadorDesconhecido(op) => write!(f, "Operador desconhecido '{}'", op),
+        }
+    }
+}
+
+impl std::error::Error for CalcError {}
+
+/// Garante que o resultado de uma operação com f64 é finito
+pub fn verificar(resultado: f64) -> Result<f64, CalcError> {
+    if resultado.is_nan() {
+        Err(CalcError::NaoNumerico)
+    } else if resultado.is_infinite() {
+        Err(CalcError::Overflow)
+    } else {
+        Ok(resultado)
+    }
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verificar() {
+        assert_eq!(verificar(1.5), Ok(1.5));
+        assert_eq!(verificar(f64::INFINITY), Err(CalcError::Overflow));
+        assert_eq!(verificar(f64::NEG_INFINITY), Err(CalcError::Overflow));
+        assert_eq!(verificar(f64::NAN), Err(CalcError::NaoNumerico));
+    }
+
+    #[test]
+    fn test_categoria() {
+        assert_eq!(CalcError::DivisaoPorZero.categoria(), CategoriaErro::Dominio);
+        assert_eq!(CalcError::NaoNumerico.categoria(), CategoriaErro::ResultadoNaoFinito);
+        assert_eq!(CalcError::OperadorDesconhecido('&').categoria(), CategoriaErro::Operador);
+    }
+}
@@ -0,0 +1,927 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::ambiente::Ambiente;
+use crate::cientifica;
+use crate::erro::CalcError;
+use crate::matriz::{self, Matriz, Valor};
+use crate::unidades::{self, Quantidade, Unidade};
+
+// ========================================
+// ERROS
+// ========================================
+
+/// Erro de análise ou avaliação, com a posição (em caracteres) do token culpado
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErroExpressao {
+    pub posicao: usize,
+    pub mensagem: String,
+}
+
+impl ErroExpressao {
+    fn novo(posicao: usize, mensagem: impl Into<String>) -> Self {
+        ErroExpressao {
+            posicao,
+            mensagem: mensagem.into(),
+        }
+    }
+
+    /// Erro de uma operação, apontando para o operador ou função que falhou
+    fn de_calculo(posicao: usize, erro: CalcError) -> Self {
+        ErroExpressao::novo(posicao, erro.to_string())
+    }
+
+    /// Mostra a expressão com um `^` apontando para o erro
+    pub fn destacar(&self, entrada: &str) -> String {
+        format!("{}\n{}^", entrada, " ".repeat(self.posicao))
+    }
+}
+
+impl fmt::Display for ErroExpressao {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (posição {})", self.mensagem, self.posicao + 1)
+    }
+}
+
+// ========================================
+// TOKENIZADOR
+// ========================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Numero(f64),
+    Identificador(String),
+    Operador(char),
+    AbreParenteses,
+    FechaParenteses,
+    Virgula,
+    Igual,
+    AbreColchete,
+    FechaColchete,
+    PontoEVirgula,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Numero(n) => write!(f, "{}", n),
+            Token::Identificador(nome) => write!(f, "{}", nome),
+            Token::Operador(op) => write!(f, "{}", op),
+            Token::AbreParenteses => write!(f, "("),
+            Token::FechaParenteses => write!(f, ")"),
+            Token::Virgula => write!(f, ","),
+            Token::Igual => write!(f, "="),
+            Token::AbreColchete => write!(f, "["),
+            Token::FechaColchete => write!(f, "]"),
+            Token::PontoEVirgula => write!(f, ";"),
+        }
+    }
+}
+
+/// Token com a posição onde começa na entrada
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenPosicionado {
+    pub token: Token,
+    pub posicao: usize,
+}
+
+/// Quebra a entrada em tokens
+pub fn tokenizar(entrada: &str) -> Result<Vec<TokenPosicionado>, ErroExpressao> {
+    let caracteres: Vec<char> = entrada.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < caracteres.len() {
+        let c = caracteres[i];
+        let inicio = i;
+
+        let token = if c.is_whitespace() {
+            i += 1;
+            continue;
+        } else if c.is_ascii_digit() || c == '.' {
+            while i < caracteres.len() && (caracteres[i].is_ascii_digit() || caracteres[i] == '.') {
+                i += 1;
+            }
+            let texto: String = caracteres[inicio..i].iter().collect();
+            match texto.parse::<f64>() {
+                Ok(n) => Token::Numero(n),
+                Err(_) => return Err(ErroExpressao::novo(inicio, format!("Número inválido '{}'", texto))),
+            }
+        } else if c.is_alphabetic() || c == '_' || c == '°' {
+            i += 1;
+            while i < caracteres.len() && (caracteres[i].is_alphanumeric() || caracteres[i] == '_') {
+                i += 1;
+            }
+            Token::Identificador(caracteres[inicio..i].iter().collect())
+        } else {
+            i += 1;
+            match c {
+                '+' | '-' | '*' | '/' | '%' | '^' => Token::Operador(c),
+                '(' => Token::AbreParenteses,
+                ')' => Token::FechaParenteses,
+                ',' => Token::Virgula,
+                '=' => Token::Igual,
+                '[' => Token::AbreColchete,
+                ']' => Token::FechaColchete,
+                ';' => Token::PontoEVirgula,
+                _ => return Err(ErroExpressao::novo(inicio, format!("Caractere inesperado '{}'", c))),
+            }
+        };
+
+        tokens.push(TokenPosicionado { token, posicao: inicio });
+    }
+
+    Ok(tokens)
+}
+
+// ========================================
+// ÁRVORE SINTÁTICA (AST)
+// ========================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Numero(f64),
+    /// Número seguido de unidade, ex.: `3 km`, `20 °C`, `4 m^2`
+    Medida {
+        valor: f64,
+        unidade: &'static Unidade,
+        expoente: i32,
+        posicao: usize,
+    },
+    Variavel {
+        nome: String,
+        posicao: usize,
+    },
+    Negacao {
+        expr: Box<Expr>,
+        posicao: usize,
+    },
+    Binaria {
+        op: char,
+        esquerda: Box<Expr>,
+        direita: Box<Expr>,
+        posicao: usize,
+    },
+    Chamada {
+        nome: String,
+        argumentos: Vec<Expr>,
+        posicao: usize,
+    },
+    /// `[1, 2; 3, 4]`: linhas separadas por ';', colunas por ','
+    Matriz {
+        linhas: Vec<Vec<Expr>>,
+        posicao: usize,
+    },
+}
+
+/// Uma linha digitada no REPL
+#[derive(Debug, Clone, PartialEq)]
+pub enum Comando {
+    /// `2 * x + 1`
+    Expressao(Expr),
+    /// `x = 12.5`
+    Atribuicao { nome: String, expr: Expr, posicao: usize },
+    /// `f(a, b) = a^2 + b`
+    Definicao {
+        nome: String,
+        parametros: Vec<String>,
+        corpo: Expr,
+        posicao: usize,
+    },
+}
+
+// ========================================
+// PARSER (descida recursiva)
+// ========================================
+//
+// Precedência, da menor para a maior:
+//   expressao := termo (('+' | '-') termo)*
+//   termo     := unario (('*' | '/' | '%') unario)*
+//   unario    := ('-' | '+') unario | potencia
+//   potencia  := primario ('^' unario)?        (associativa à direita)
+//   primario  := numero [unidade ['^' ['-'] inteiro]] | nome | nome '(' args ')' | '(' expressao ')' | matriz
+//   matriz    := '[' linha (';' linha)* ']'     linha := expressao (',' expressao)*
+//
+// Comandos do REPL:
+//   comando   := nome '=' expressao | nome '(' params ')' '=' expressao | expressao
+
+/// Limite de profundidade da árvore. Análise, avaliação e descarte da árvore são
+/// recursivos: sem limite, `((((…1…))))` ou `1+1+…+1` bem longos estouram a pilha
+const ANINHAMENTO_MAXIMO: usize = 256;
+
+struct Parser {
+    tokens: Vec<TokenPosicionado>,
+    atual: usize,
+    fim: usize,
+    /// Níveis da árvore abertos até aqui (ver `descer`)
+    aninhamento: usize,
+}
+
+impl Parser {
+    fn novo(entrada: &str) -> Result<Self, ErroExpressao> {
+        Ok(Parser {
+            tokens: tokenizar(entrada)?,
+            atual: 0,
+            fim: entrada.chars().count(),
+            aninhamento: 0,
+        })
+    }
+
+    /// Garante que não sobrou nenhum token depois da expressão
+    fn terminar(&self) -> Result<(), ErroExpressao> {
+        match self.tokens.get(self.atual) {
+            Some(t) => Err(ErroExpressao::novo(t.posicao, format!("Token inesperado '{}'", t.token))),
+            None => Ok(()),
+        }
+    }
+
+    fn espiar(&self) -> Option<&Token> {
+        self.tokens.get(self.atual).map(|t| &t.token)
+    }
+
+    fn posicao(&self) -> usize {
+        self.tokens.get(self.atual).map_or(self.fim, |t| t.posicao)
+    }
+
+    fn avancar(&mut self) -> Option<TokenPosicionado> {
+        let token = self.tokens.get(self.atual).cloned();
+        self.atual += 1;
+        token
+    }
+
+    fn consumir_operador(&mut self, ops: &str) -> Option<(char, usize)> {
+        match self.espiar() {
+            Some(Token::Operador(op)) if ops.contains(*op) => {
+                let op = *op;
+                let posicao = self.posicao();
+                self.atual += 1;
+                Some((op, posicao))
+            }
+            _ => None,
+        }
+    }
+
+    fn esperar(&mut self, esperado: Token) -> Result<(), ErroExpressao> {
+        let posicao = self.posicao();
+        match self.avancar() {
+            Some(t) if t.token == esperado => Ok(()),
+            Some(t) => Err(ErroExpressao::novo(
+                posicao,
+                format!("Esperado '{}', encontrado '{}'", esperado, t.token),
+            )),
+            None => Err(ErroExpressao::novo(posicao, format!("Esperado '{}' no fim da expressão", esperado))),
+        }
+    }
+
+    fn expressao(&mut self) -> Result<Expr, ErroExpressao> {
+        self.encadear("+-", Parser::termo)
+    }
+
+    fn termo(&mut self) -> Result<Expr, ErroExpressao> {
+        self.encadear("*/%", Parser::unario)
+    }
+
+    /// `operando (op operando)*`, associativa à esquerda. Cada operador deixa a
+    /// árvore um nível mais funda, então conta para o limite de aninhamento
+    fn encadear(
+        &mut self,
+        ops: &str,
+        operando: fn(&mut Parser) -> Result<Expr, ErroExpressao>,
+    ) -> Result<Expr, ErroExpressao> {
+        let mut esquerda = operando(self)?;
+        let mut niveis = 0;
+        while let Some((op, posicao)) = self.consumir_operador(ops) {
+            self.descer(posicao)?;
+            niveis += 1;
+            let direita = operando(self)?;
+            esquerda = Expr::Binaria {
+                op,
+                esquerda: Box::new(esquerda),
+                direita: Box::new(direita),
+                posicao,
+            };
+        }
+        self.aninhamento -= niveis;
+        Ok(esquerda)
+    }
+
+    /// Todo ciclo da descida (parênteses, sinais, potências, argumentos) passa por aqui
+    fn unario(&mut self) -> Result<Expr, ErroExpressao> {
+        self.descer(self.posicao())?;
+        let expr = match self.consumir_operador("+-") {
+            Some(('-', posicao)) => self.unario().map(|expr| Expr::Negacao {
+                expr: Box::new(expr),
+                posicao,
+            }),
+            Some(_) => self.unario(),
+            None => self.potencia(),
+        };
+        self.aninhamento -= 1;
+        expr
+    }
+
+    /// Entra em mais um nível da árvore; passar do limite é erro em `posicao`
+    fn descer(&mut self, posicao: usize) -> Result<(), ErroExpressao> {
+        if self.aninhamento >= ANINHAMENTO_MAXIMO {
+            return Err(ErroExpressao::novo(
+                posicao,
+                format!("Expressão aninhada demais (máximo de {} níveis)", ANINHAMENTO_MAXIMO),
+            ));
+        }
+        self.aninhamento += 1;
+        Ok(())
+    }
+
+    fn potencia(&mut self) -> Result<Expr, ErroExpressao> {
+        let base = self.primario()?;
+        match self.consumir_operador("^") {
+            Some((op, posicao)) => Ok(Expr::Binaria {
+                op,
+                esquerda: Box::new(base),
+                direita: Box::new(self.unario()?),
+                posicao,
+            }),
+            None => Ok(base),
+        }
+    }
+
+    fn primario(&mut self) -> Result<Expr, ErroExpressao> {
+        let posicao = self.posicao();
+        match self.avancar() {
+            Some(TokenPosicionado { token: Token::Numero(n), .. }) => Ok(self.medida(n).unwrap_or(Expr::Numero(n))),
+            Some(TokenPosicionado { token: Token::Identificador(nome), .. }) => {
+                if self.espiar() == Some(&Token::AbreParenteses) {
+                    self.atual += 1;
+                    let argumentos = self.argumentos()?;
+                    Ok(Expr::Chamada { nome, argumentos, posicao })
+                } else {
+                    Ok(Expr::Variavel { nome, posicao })
+                }
+            }
+            Some(TokenPosicionado { token: Token::AbreParenteses, .. }) => {
+                let expr = self.expressao()?;
+                self.esperar(Token::FechaParenteses)?;
+                Ok(expr)
+            }
+            Some(TokenPosicionado { token: Token::AbreColchete, .. }) => self.matriz(posicao),
+            Some(t) => Err(ErroExpressao::novo(posicao, format!("Token inesperado '{}'", t.token))),
+            None => Err(ErroExpressao::novo(posicao, "Expressão incompleta")),
+        }
+    }
+
+    /// Unidade logo depois de um número (`3 km`, `9.8 m^2`). Um nome seguido de '('
+    /// é chamada de função, não unidade; se não houver unidade nada é consumido
+    fn medida(&mut self, valor: f64) -> Option<Expr> {
+        let posicao = self.posicao();
+        let unidade = match self.espiar() {
+            Some(Token::Identificador(nome)) => unidades::unidade_por_simbolo(nome)?,
+            _ => return None,
+        };
+        if self.tokens.get(self.atual + 1).map(|t| &t.token) == Some(&Token::AbreParenteses) {
+            return None;
+        }
+        self.atual += 1;
+
+        let expoente = self.expoente_inteiro().unwrap_or(1);
+        Some(Expr::Medida { valor, unidade, expoente, posicao })
+    }
+
+    /// Consome `^n` ou `^-n` com `n` inteiro; outros expoentes ficam para `potencia`
+    fn expoente_inteiro(&mut self) -> Option<i32> {
+        let resto: Vec<&Token> = self.tokens[self.atual..].iter().take(3).map(|t| &t.token).collect();
+        let (sinal, numero, consumidos) = match resto.as_slice() {
+            [Token::Operador('^'), Token::Numero(n), ..] => (1, *n, 2),
+            [Token::Operador('^'), Token::Operador('-'), Token::Numero(n)] => (-1, *n, 3),
+            _ => return None,
+        };
+        if numero.fract() != 0.0 || numero > i32::MAX as f64 {
+            return None;
+        }
+        self.atual += consumidos;
+        Some(sinal * numero as i32)
+    }
+
+    /// Lê as linhas de uma matriz; o '[' já foi consumido
+    fn matriz(&mut self, posicao: usize) -> Result<Expr, ErroExpressao> {
+        if self.espiar() == Some(&Token::FechaColchete) {
+            return Err(ErroExpressao::novo(posicao, "Matriz vazia"));
+        }
+
+        let mut linhas: Vec<Vec<Expr>> = Vec::new();
+        let mut linha = Vec::new();
+        loop {
+            linha.push(self.expressao()?);
+
+            let posicao_separador = self.posicao();
+            let fim = match self.espiar() {
+                Some(Token::Virgula) => {
+                    self.atual += 1;
+                    continue;
+                }
+                Some(Token::PontoEVirgula) => {
+                    self.atual += 1;
+                    false
+                }
+                _ => {
+                    self.esperar(Token::FechaColchete)?;
+                    true
+                }
+            };
+
+            if let Some(primeira) = linhas.first()
+                && linha.len() != primeira.len()
+            {
+                return Err(ErroExpressao::novo(
+                    posicao_separador,
+                    format!("Linha com {} elemento(s), esperados {}", linha.len(), primeira.len()),
+                ));
+            }
+            linhas.push(std::mem::take(&mut linha));
+
+            if fim {
+                return Ok(Expr::Matriz { linhas, posicao });
+            }
+        }
+    }
+
+    /// Lê os argumentos de uma chamada; o '(' já foi consumido
+    fn argumentos(&mut self) -> Result<Vec<Expr>, ErroExpressao> {
+        let mut argumentos = Vec::new();
+        if self.espiar() == Some(&Token::FechaParenteses) {
+            self.atual += 1;
+            return Ok(argumentos);
+        }
+
+        loop {
+            argumentos.push(self.expressao()?);
+            if self.espiar() == Some(&Token::Virgula) {
+                self.atual += 1;
+            } else {
+                self.esperar(Token::FechaParenteses)?;
+                return Ok(argumentos);
+            }
+        }
+    }
+
+    /// Lê `nome` ou `nome(a, b, ...)` do lado esquerdo de um '='
+    fn cabecalho(&mut self) -> Result<(String, Option<Vec<String>>, usize), ErroExpressao> {
+        let posicao = self.posicao();
+        let nome = match self.avancar() {
+            Some(TokenPosicionado { token: Token::Identificador(nome), .. }) => nome,
+            _ => return Err(ErroExpressao::novo(posicao, "Esperado um nome antes de '='")),
+        };
+
+        if self.espiar() != Some(&Token::AbreParenteses) {
+            return Ok((nome, None, posicao));
+        }
+        self.atual += 1;
+
+        let mut parametros: Vec<String> = Vec::new();
+        if self.espiar() == Some(&Token::FechaParenteses) {
+            self.atual += 1;
+            return Ok((nome, Some(parametros), posicao));
+        }
+
+        loop {
+            let posicao_param = self.posicao();
+            match self.avancar() {
+                Some(TokenPosicionado { token: Token::Identificador(param), .. }) => {
+                    if parametros.contains(&param) {
+                        return Err(ErroExpressao::novo(posicao_param, format!("Parâmetro '{}' repetido", param)));
+                    }
+                    parametros.push(param);
+                }
+                _ => return Err(ErroExpressao::novo(posicao_param, "Esperado o nome de um parâmetro")),
+            }
+
+            if self.espiar() == Some(&Token::Virgula) {
+                self.atual += 1;
+            } else {
+                self.esperar(Token::FechaParenteses)?;
+                return Ok((nome, Some(parametros), posicao));
+            }
+        }
+    }
+}
+
+/// Analisa uma linha do REPL: atribuição, definição de função ou expressão
+pub fn analisar_comando(entrada: &str) -> Result<Comando, ErroExpressao> {
+    let mut parser = Parser::novo(entrada)?;
+
+    if !parser.tokens.iter().any(|t| t.token == Token::Igual) {
+        let expr = parser.expressao()?;
+        parser.terminar()?;
+        return Ok(Comando::Expressao(expr));
+    }
+
+    let (nome, parametros, posicao) = parser.cabecalho()?;
+    parser.esperar(Token::Igual)?;
+    let corpo = parser.expressao()?;
+    parser.terminar()?;
+
+    Ok(match parametros {
+        None => Comando::Atribuicao { nome, expr: corpo, posicao },
+        Some(parametros) => Comando::Definicao { nome, parametros, corpo, posicao },
+    })
+}
+
+// ========================================
+// AVALIADOR
+// ========================================
+
+/// Limite de níveis da árvore na avaliação, somando os corpos das funções do usuário
+/// chamadas no caminho (evita `f(x) = f(x)`, ou funções fundas chamando umas às outras,
+/// estourar a pilha). Uma expressão sozinha fica bem abaixo disso (`ANINHAMENTO_MAXIMO`)
+const PROFUNDIDADE_MAXIMA: usize = 512;
+
+/// Avalia a AST usando as operações da calculadora
+pub fn avaliar(expr: &Expr, ambiente: &Ambiente) -> Result<Valor, ErroExpressao> {
+    avaliar_em(expr, ambiente, &HashMap::new(), 0)
+}
+
+/// Avalia com os parâmetros da função em execução (`locais`) visíveis;
+/// `profundidade` é o nível de `expr` contando desde a expressão original
+fn avaliar_em(
+    expr: &Expr,
+    ambiente: &Ambiente,
+    locais: &HashMap<String, Valor>,
+    profundidade: usize,
+) -> Result<Valor, ErroExpressao> {
+    let avaliar = |e: &Expr| avaliar_em(e, ambiente, locais, profundidade + 1);
+
+    match expr {
+        Expr::Numero(n) => Ok(Valor::numero(*n)),
+        Expr::Medida { valor, unidade, expoente, posicao } => avaliar_medida(*valor, unidade, *expoente, *posicao),
+        Expr::Variavel { nome, posicao } => avaliar_variavel(nome, *posicao, ambiente, locais),
+        Expr::Negacao { expr, posicao } => avaliar(expr)?
+            .negar()
+            .map_err(|erro| ErroExpressao::de_calculo(*posicao, erro)),
+        Expr::Binaria { op, esquerda, direita, posicao } => {
+            let a = avaliar(esquerda)?;
+            let b = avaliar(direita)?;
+            operar(*op, &a, &b, *posicao)
+        }
+        Expr::Matriz { linhas, posicao } => avaliar_matriz(linhas, *posicao, avaliar),
+        Expr::Chamada { nome, argumentos, posicao } => {
+            avaliar_chamada(nome, argumentos, *posicao, ambiente, locais, profundidade)
+        }
+    }
+}
+
+// Os casos maiores ficam fora de `avaliar_em`: ela é recursiva, e cada variável
+// dela ocupa espaço na pilha em todos os níveis da árvore
+
+/// Aplica o operador, descrevendo os operandos quando os tipos não combinam
+fn operar(op: char, a: &Valor, b: &Valor, posicao: usize) -> Result<Valor, ErroExpressao> {
+    Valor::operar(op, a, b).map_err(|erro| match erro {
+        CalcError::UnidadesIncompativeis | CalcError::TiposIncompativeis => {
+            ErroExpressao::novo(posicao, format!("{}: {} {} {}", erro, a.descrever(), op, b.descrever()))
+        }
+        _ => ErroExpressao::de_calculo(posicao, erro),
+    })
+}
+
+/// Número com unidade, explicando o erro quando a unidade não aceita o expoente
+fn avaliar_medida(
+    valor: f64,
+    unidade: &'static Unidade,
+    expoente: i32,
+    posicao: usize,
+) -> Result<Valor, ErroExpressao> {
+    Quantidade::de_unidade_elevada(valor, unidade, expoente)
+        .map(Valor::Escalar)
+        .map_err(|erro| match erro {
+            CalcError::UnidadesIncompativeis => ErroExpressao::novo(
+                posicao,
+                format!("{}: {} não pode ser elevado a {}", erro, unidade.simbolo, expoente),
+            ),
+            _ => ErroExpressao::de_calculo(posicao, erro),
+        })
+}
+
+/// Parâmetro, variável, constante ou unidade solta, nessa ordem. Unidades
+/// soltas (`km / h`) só valem se não houver variável com o mesmo nome
+fn avaliar_variavel(
+    nome: &str,
+    posicao: usize,
+    ambiente: &Ambiente,
+    locais: &HashMap<String, Valor>,
+) -> Result<Valor, ErroExpressao> {
+    locais
+        .get(nome)
+        .cloned()
+        .or_else(|| ambiente.variavel(nome))
+        .or_else(|| cientifica::constante(nome).map(Valor::numero))
+        .or_else(|| {
+            unidades::unidade_por_simbolo(nome)
+                .and_then(|u| Quantidade::de_unidade(1.0, u).ok())
+                .map(Valor::Escalar)
+        })
+        .ok_or_else(|| match nome {
+            "ans" => ErroExpressao::novo(posicao, "'ans' ainda não tem valor"),
+            _ => ErroExpressao::novo(posicao, format!("Variável desconhecida '{}'", nome)),
+        })
+}
+
+/// Monta a matriz; os elementos têm de ser números puros
+fn avaliar_matriz(
+    linhas: &[Vec<Expr>],
+    posicao: usize,
+    avaliar: impl Fn(&Expr) -> Result<Valor, ErroExpressao>,
+) -> Result<Valor, ErroExpressao> {
+    let mut numeros = Vec::with_capacity(linhas.len());
+    for linha in linhas {
+        let mut valores = Vec::with_capacity(linha.len());
+        for elemento in linha {
+            match avaliar(elemento)? {
+                Valor::Escalar(q) if q.adimensional() => valores.push(q.valor),
+                outro => {
+                    return Err(ErroExpressao::novo(
+                        posicao,
+                        format!("Elementos de matriz devem ser números puros, recebeu {}", outro.descrever()),
+                    ));
+                }
+            }
+        }
+        numeros.push(valores);
+    }
+    let matriz = Matriz::de_linhas(numeros).expect("o parser garante linhas do mesmo tamanho");
+    Ok(Valor::Matriz(matriz))
+}
+
+/// Chama uma função embutida ou do usuário, com os argumentos avaliados em `locais`
+fn avaliar_chamada(
+    nome: &str,
+    argumentos: &[Expr],
+    posicao: usize,
+    ambiente: &Ambiente,
+    locais: &HashMap<String, Valor>,
+    profundidade: usize,
+) -> Result<Valor, ErroExpressao> {
+    let valores = argumentos
+        .iter()
+        .map(|e| avaliar_em(e, ambiente, locais, profundidade + 1))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let aridade = cientifica::aridade(nome)
+        .or_else(|| matriz::aridade(nome))
+        .or_else(|| ambiente.funcao(nome).map(|f| f.parametros.len()))
+        .ok_or_else(|| ErroExpressao::novo(posicao, format!("Função desconhecida '{}'", nome)))?;
+
+    if valores.len() != aridade {
+        return Err(ErroExpressao::novo(
+            posicao,
+            format!("{} espera {} argumento(s), recebeu {}", nome, aridade, valores.len()),
+        ));
+    }
+
+    // Funções embutidas têm prioridade sobre as do usuário
+    if let Some(resultado) = aplicar_embutida(nome, &valores, ambiente) {
+        return resultado.map_err(|erro| match erro {
+            CalcError::UnidadesIncompativeis | CalcError::TiposIncompativeis => {
+                let tipos: Vec<String> = valores.iter().map(Valor::descrever).collect();
+                ErroExpressao::novo(posicao, format!("{}: {}({})", erro, nome, tipos.join(", ")))
+            }
+            _ => ErroExpressao::de_calculo(posicao, erro),
+        });
+    }
+
+    if profundidade >= PROFUNDIDADE_MAXIMA {
+        return Err(ErroExpressao::novo(posicao, "Recursão profunda demais"));
+    }
+
+    let funcao = ambiente.funcao(nome).expect("aridade só é encontrada se a função existir");
+    let locais_funcao = funcao.parametros.iter().cloned().zip(valores).collect();
+    avaliar_em(&funcao.corpo, ambiente, &locais_funcao, profundidade + 1)
+}
+
+/// Funções embutidas: as de matrizes, e as do catálogo científico. Destas,
+/// `sqrt` e `abs` aceitam unidades; as demais só números puros (`sin(2 m)` não faz sentido)
+fn aplicar_embutida(nome: &str, valores: &[Valor], ambiente: &Ambiente) -> Option<Result<Valor, CalcError>> {
+    if let Some(resultado) = matriz::aplicar(nome, valores) {
+        return Some(resultado);
+    }
+    cientifica::aridade(nome)?;
+
+    let mut quantidades = Vec::with_capacity(valores.len());
+    for valor in valores {
+        match valor {
+            Valor::Escalar(q) => quantidades.push(*q),
+            Valor::Matriz(_) => return Some(Err(CalcError::TiposIncompativeis)),
+        }
+    }
+
+    if let [x] = quantidades.as_slice()
+        && !x.adimensional()
+    {
+        match nome.to_lowercase().as_str() {
+            "sqrt" => return Some(x.raiz_quadrada().map(Valor::Escalar)),
+            "abs" => return Some(x.abs().map(Valor::Escalar)),
+            _ => {}
+        }
+    }
+    if quantidades.iter().any(|q| !q.adimensional()) {
+        return Some(Err(CalcError::UnidadesIncompativeis));
+    }
+
+    let numeros: Vec<f64> = quantidades.iter().map(|q| q.valor).collect();
+    cientifica::aplicar(nome, &numeros, ambiente.modo_angular()).map(|r| r.map(Valor::numero))
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Analisa e avalia uma expressão num ambiente vazio
+    fn avaliar_texto(entrada: &str) -> Result<Valor, ErroExpressao> {
+        match analisar_comando(entrada)? {
+            Comando::Expressao(expr) => avaliar(&expr, &Ambiente::novo()),
+            outro => panic!("esperava expressão, obteve {:?}", outro),
+        }
+    }
+
+    /// Valor de uma expressão sem unidades
+    fn calcular(entrada: &str) -> Result<f64, ErroExpressao> {
+        match avaliar_texto(entrada)? {
+            Valor::Escalar(q) if q.adimensional() => Ok(q.valor),
+            outro => panic!("{} deu {:?}, esperava número puro", entrada, outro),
+        }
+    }
+
+    /// Resultado como a calculadora mostra, ex.: `3.2 km`
+    fn exibir(entrada: &str) -> String {
+        avaliar_texto(entrada).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_tokenizar() {
+        let tokens = tokenizar("3 + sqrt(16)").unwrap();
+        let esperado = vec![
+            (Token::Numero(3.0), 0),
+            (Token::Operador('+'), 2),
+            (Token::Identificador(String::from("sqrt")), 4),
+            (Token::AbreParenteses, 8),
+            (Token::Numero(16.0), 9),
+            (Token::FechaParenteses, 11),
+        ];
+        let obtido: Vec<_> = tokens.into_iter().map(|t| (t.token, t.posicao)).collect();
+        assert_eq!(obtido, esperado);
+    }
+
+    #[test]
+    fn test_precedencia() {
+        assert_eq!(calcular("2 + 3 * 4"), Ok(14.0));
+        assert_eq!(calcular("(2 + 3) * 4"), Ok(20.0));
+        assert_eq!(calcular("10 - 4 - 3"), Ok(3.0));
+        assert_eq!(calcular("7 % 4 * 2"), Ok(6.0));
+    }
+
+    #[test]
+    fn test_potencia_associativa_a_direita() {
+        assert_eq!(calcular("2 ^ 3 ^ 2"), Ok(512.0));
+        assert_eq!(calcular("-2 ^ 2"), Ok(-4.0));
+        assert_eq!(calcular("2 ^ -1"), Ok(0.5));
+    }
+
+    #[test]
+    fn test_expressao_completa() {
+        assert_eq!(calcular("(3 + 4) * 2 ^ 3 - sqrt(16) % 5"), Ok(52.0));
+    }
+
+    #[test]
+    fn test_erro_caractere_invalido() {
+        let erro = calcular("2 + $").unwrap_err();
+        assert_eq!(erro.posicao, 4);
+    }
+
+    #[test]
+    fn test_erro_parenteses() {
+        let erro = calcular("(1 + 2").unwrap_err();
+        assert_eq!(erro.posicao, 6);
+
+        let erro = calcular("1 + 2)").unwrap_err();
+        assert_eq!(erro.posicao, 5);
+    }
+
+    #[test]
+    fn test_erro_divisao_por_zero_aponta_operador() {
+        let erro = calcular("1 + 4 / (2 - 2)").unwrap_err();
+        assert_eq!(erro.posicao, 6);
+        assert_eq!(erro.mensagem, "Divisão por zero");
+    }
+
+    #[test]
+    fn test_funcoes_cientificas_e_constantes() {
+        assert_eq!(calcular("fact(5) / nCr(5, 2)"), Ok(12.0));
+        assert_eq!(calcular("gcd(12, 18) + lcm(4, 6)"), Ok(18.0));
+        assert_eq!(calcular("round(e * 100) / 100"), Ok(2.72));
+        assert_eq!(calcular("ln(e)"), Ok(1.0));
+        assert_eq!(calcular("sin(90)"), Ok(1.0));
+        assert_eq!(calcular("1 + ln(0)").unwrap_err().posicao, 4);
+        assert!(calcular("ncr(5)").is_err());
+    }
+
+    #[test]
+    fn test_erro_resultado_nao_finito() {
+        let erro = calcular("1 + 10 ^ 400").unwrap_err();
+        assert_eq!(erro.posicao, 7);
+        assert_eq!(erro.mensagem, CalcError::Overflow.to_string());
+    }
+
+    #[test]
+    fn test_erro_funcao() {
+        assert_eq!(calcular("sqrt(-4)").unwrap_err().posicao, 0);
+        assert_eq!(calcular("1 + foo(2)").unwrap_err().posicao, 4);
+        assert!(calcular("sqrt(1, 2)").is_err());
+    }
+
+    #[test]
+    fn test_unidades() {
+        assert_eq!(exibir("3 km + 200 m"), "3.2 km");
+        assert_eq!(exibir("72 kg / (1.8 m)^2"), format!("{} kg/m^2", 72.0 / (1.8 * 1.8)));
+        assert_eq!(exibir("100 km / h * 2 h"), "200000 m");
+        assert_eq!(exibir("3 m^2 / 1.5 m"), "2 m");
+        assert_eq!(exibir("2 s^-1 * 3 s"), "6");
+        assert_eq!(exibir("sqrt(16 m^2)"), "4 m");
+        assert_eq!(exibir("20 °C + 5 degC"), "25 °C");
+        assert_eq!(exibir("-(40 °F) + 0 °F"), "-40 °F");
+        assert_eq!(exibir("1 h / 1 min"), "60");
+    }
+
+    #[test]
+    fn test_erro_unidades_incompativeis() {
+        let erro = avaliar_texto("3 m + 2 s").unwrap_err();
+        assert_eq!(erro.posicao, 4);
+        assert_eq!(erro.mensagem, "Unidades incompatíveis: m + s");
+
+        assert_eq!(avaliar_texto("sin(2 m)").unwrap_err().mensagem, "Unidades incompatíveis: sin(m)");
+        assert_eq!(avaliar_texto("(2 m)^0.5").unwrap_err().mensagem, "Unidades incompatíveis: m ^ 0.5");
+        assert!(avaliar_texto("1 °C^2").is_err());
+    }
+
+    #[test]
+    fn test_matrizes() {
+        assert_eq!(exibir("[1, 2; 3, 4] * [5; 6]"), "[ 17 ]\n[ 39 ]");
+        assert_eq!(exibir("det([1, 2; 3, 4])"), "-2");
+        assert_eq!(exibir("transpose([1, 2, 3]) * 2"), "[ 2 ]\n[ 4 ]\n[ 6 ]");
+        assert_eq!(exibir("inv([2, 0; 0, 4]) + [0, 1; 1, 0]"), "[ 0.5     1 ]\n[   1  0.25 ]");
+        assert_eq!(exibir("solve([1, 1; 1, -1], [3; 1])"), "[ 2 ]\n[ 1 ]");
+        assert_eq!(exibir("-[1, 2 + 3]"), "[ -1  -5 ]");
+    }
+
+    #[test]
+    fn test_erros_matrizes() {
+        let erro = avaliar_texto("[1, 2] + [1, 2, 3]").unwrap_err();
+        assert_eq!(erro.posicao, 7);
+        assert_eq!(erro.mensagem, "Dimensões incompatíveis em +: 1×2 e 1×3");
+
+        assert_eq!(avaliar_texto("[1, 2] + 1").unwrap_err().mensagem, "Tipos incompatíveis: [1×2] + 1");
+        assert_eq!(avaliar_texto("det(3)").unwrap_err().mensagem, "Tipos incompatíveis: det(3)");
+        assert_eq!(avaliar_texto("sin([1])").unwrap_err().mensagem, "Tipos incompatíveis: sin([1×1])");
+        assert_eq!(avaliar_texto("inv([1, 2; 2, 4])").unwrap_err().mensagem, "Matriz singular (sem inversa)");
+        assert_eq!(avaliar_texto("[1, 2; 3]").unwrap_err().posicao, 8);
+        assert_eq!(avaliar_texto("[]").unwrap_err().mensagem, "Matriz vazia");
+        assert!(avaliar_texto("[1 m, 2]").is_err());
+    }
+
+    #[test]
+    fn test_analisar_comando() {
+        assert!(matches!(analisar_comando("x = 12.5"), Ok(Comando::Atribuicao { .. })));
+        assert!(matches!(analisar_comando("2 * x"), Ok(Comando::Expressao(_))));
+
+        match analisar_comando("f(a, b) = a^2 + b") {
+            Ok(Comando::Definicao { nome, parametros, .. }) => {
+                assert_eq!(nome, "f");
+                assert_eq!(parametros, vec!["a", "b"]);
+            }
+            outro => panic!("esperava definição, obteve {:?}", outro),
+        }
+    }
+
+    #[test]
+    fn test_erro_comando() {
+        assert_eq!(analisar_comando("2 = 3").unwrap_err().posicao, 0);
+        assert_eq!(analisar_comando("f(a, a) = a").unwrap_err().posicao, 5);
+        assert_eq!(analisar_comando("x = 1 = 2").unwrap_err().posicao, 6);
+        assert_eq!(calcular("y + 1").unwrap_err().posicao, 0);
+    }
+
+    #[test]
+    fn test_aninhamento_maximo() {
+        let parenteses = |n: usize| format!("{}1{}", "(".repeat(n), ")".repeat(n));
+        assert_eq!(calcular(&parenteses(200)), Ok(1.0));
+        assert_eq!(calcular(&vec!["1"; 200].join("+")), Ok(200.0));
+
+        let erro = calcular(&parenteses(20_000)).unwrap_err();
+        assert!(erro.mensagem.starts_with("Expressão aninhada demais"), "{}", erro);
+        assert_eq!(erro.posicao, ANINHAMENTO_MAXIMO);
+        assert!(calcular(&vec!["1"; 20_000].join("+")).is_err());
+        assert!(calcular(&format!("{}1", "-".repeat(20_000))).is_err());
+        assert!(calcular(&vec!["2"; 20_000].join("^")).is_err());
+        assert!(calcular(&format!("{}1{}", "sqrt(".repeat(20_000), ")".repeat(20_000))).is_err());
+    }
+}
@@ -0,0 +1,587 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+// ========================================
+// REGISTRO
+// ========================================
+
+/// Uma operação feita na calculadora
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Registro {
+    pub operandos: Vec<f64>,
+    /// `+`, `-`, `*`, `/`, `%`, `^`, `expr` ou o nome de uma função (`sqrt`, `sin`...)
+    pub operador: String,
+    pub resultado: f64,
+    /// Segundos desde 1970-01-01 00:00:00 UTC
+    pub timestamp: u64,
+    /// Texto digitado, só para expressões do REPL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expressao: Option<String>,
+    /// Operação com os valores exatos (modos inteiro, racional e decimal);
+    /// `operandos` e `resultado` guardam só a aproximação em f64
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exato: Option<String>,
+    /// Unidade do resultado de uma expressão com unidades (ex.: `km`);
+    /// `resultado` está nessa unidade
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unidade: Option<String>,
+}
+
+impl Registro {
+    /// Operação com dois operandos, ex.: `3 + 4`
+    pub fn binario(op: char, a: f64, b: f64, resultado: f64) -> Self {
+        Registro {
+            operandos: vec![a, b],
+            operador: op.to_string(),
+            resultado,
+            timestamp: agora(),
+            expressao: None,
+            exato: None,
+            unidade: None,
+        }
+    }
+
+    /// Função de um operando, ex.: `sqrt`
+    pub fn unario(operador: &str, n: f64, resultado: f64) -> Self {
+        Registro {
+            operandos: vec![n],
+            operador: operador.to_string(),
+            resultado,
+            timestamp: agora(),
+            expressao: None,
+            exato: None,
+            unidade: None,
+        }
+    }
+
+    /// Função científica chamada pelo nome, ex.: `ncr(5, 2)`
+    pub fn funcao(nome: &str, operandos: Vec<f64>, resultado: f64) -> Self {
+        Registro {
+            operandos,
+            operador: nome.to_string(),
+            resultado,
+            timestamp: agora(),
+            expressao: None,
+            exato: None,
+            unidade: None,
+        }
+    }
+
+    /// Expressão avaliada no REPL
+    pub fn expressao(texto: &str, resultado: f64) -> Self {
+        Registro {
+            operandos: Vec::new(),
+            operador: String::from("expr"),
+            resultado,
+            timestamp: agora(),
+            expressao: Some(texto.to_string()),
+            exato: None,
+            unidade: None,
+        }
+    }
+
+    /// Guarda o texto exato da operação, ex.: `0.1 + 0.2 = 3/10`
+    pub fn com_texto_exato(mut self, texto: String) -> Self {
+        self.exato = Some(texto);
+        self
+    }
+
+    /// Marca a unidade do resultado, ex.: `3 km + 200 m = 3.2 km`
+    pub fn com_unidade(mut self, unidade: String) -> Self {
+        self.unidade = Some(unidade);
+        self
+    }
+}
+
+/// Mesmo formato das mensagens de `processar_operacao`
+impl fmt::Display for Registro {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(exato) = &self.exato {
+            return write!(f, "{}", exato);
+        }
+
+        match (self.operador.as_str(), self.operandos.as_slice()) {
+            ("*", [a, b]) => write!(f, "{} × {} = {}", a, b, self.resultado),
+            ("/", [a, b]) => write!(f, "{} ÷ {} = {}", a, b, self.resultado),
+            ("^", [a, b]) => write!(f, "{}^{} = {}", a, b, self.resultado),
+            ("expr", _) => {
+                write!(f, "{} = {}", self.expressao.as_deref().unwrap_or(&self.operador), self.resultado)?;
+                match &self.unidade {
+                    Some(unidade) => write!(f, " {}", unidade),
+                    None => Ok(()),
+                }
+            }
+            (op, [a, b]) if op.chars().count() == 1 => write!(f, "{} {} {} = {}", a, op, b, self.resultado),
+            ("sqrt", [n]) => write!(f, "√{} = {}", n, self.resultado),
+            (nome, operandos) => {
+                let operandos: Vec<String> = operandos.iter().map(|n| n.to_string()).collect();
+                write!(f, "{}({}) = {}", nome, operandos.join(", "), self.resultado)
+            }
+        }
+    }
+}
+
+fn agora() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Formata segundos Unix como `AAAA-MM-DD HH:MM:SS` (UTC)
+pub fn formatar_timestamp(segundos: u64) -> String {
+    let dias = (segundos / 86_400) as i64;
+    let resto = segundos % 86_400;
+
+    // Algoritmo "civil_from_days" de Howard Hinnant
+    let z = dias + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let dia = doy - (153 * mp + 2) / 5 + 1;
+    let mes = if mp < 10 { mp + 3 } else { mp - 9 };
+    let ano = yoe + era * 400 + if mes <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        ano,
+        mes,
+        dia,
+        resto / 3_600,
+        resto % 3_600 / 60,
+        resto % 60
+    )
+}
+
+// ========================================
+// ERROS E FORMATOS
+// ========================================
+
+#[derive(Debug)]
+pub enum ErroHistorico {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Csv { linha: usize, mensagem: String },
+    FormatoDesconhecido(PathBuf),
+}
+
+impl fmt::Display for ErroHistorico {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErroHistorico::Io(e) => write!(f, "Erro de arquivo: {}", e),
+            ErroHistorico::Json(e) => write!(f, "JSON inválido: {}", e),
+            ErroHistorico::Csv { linha, mensagem } => write!(f, "CSV inválido na linha {}: {}", linha, mensagem),
+            ErroHistorico::FormatoDesconhecido(caminho) => {
+                write!(f, "Formato desconhecido para '{}' (use .csv ou .json)", caminho.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ErroHistorico {}
+
+impl From<io::Error> for ErroHistorico {
+    fn from(e: io::Error) -> Self {
+        ErroHistorico::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ErroHistorico {
+    fn from(e: serde_json::Error) -> Self {
+        ErroHistorico::Json(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Formato {
+    Csv,
+    Json,
+}
+
+impl Formato {
+    /// Deduz o formato pela extensão do arquivo
+    pub fn do_caminho(caminho: &Path) -> Result<Formato, ErroHistorico> {
+        match caminho.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()) {
+            Some(ext) if ext == "csv" => Ok(Formato::Csv),
+            Some(ext) if ext == "json" => Ok(Formato::Json),
+            _ => Err(ErroHistorico::FormatoDesconhecido(caminho.to_path_buf())),
+        }
+    }
+}
+
+// ========================================
+// CSV
+// ========================================
+
+const CABECALHO_CSV: &str = "timestamp,operador,operandos,resultado,expressao,exato,unidade";
+
+/// Coloca aspas no campo se ele tiver separadores ou aspas
+fn escapar_csv(campo: &str) -> String {
+    if campo.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", campo.replace('"', "\"\""))
+    } else {
+        campo.to_string()
+    }
+}
+
+/// Divide uma linha CSV respeitando campos entre aspas
+fn dividir_csv(linha: &str) -> Result<Vec<String>, String> {
+    let mut campos = Vec::new();
+    let mut atual = String::new();
+    let mut entre_aspas = false;
+    let mut caracteres = linha.chars().peekable();
+
+    while let Some(c) = caracteres.next() {
+        match (c, entre_aspas) {
+            ('"', true) if caracteres.peek() == Some(&'"') => {
+                atual.push('"');
+                caracteres.next();
+            }
+            ('"', true) => entre_aspas = false,
+            ('"', false) if atual.is_empty() => entre_aspas = true,
+            (',', false) => campos.push(std::mem::take(&mut atual)),
+            _ => atual.push(c),
+        }
+    }
+
+    if entre_aspas {
+        return Err(String::from("aspas não fechadas"));
+    }
+    campos.push(atual);
+    Ok(campos)
+}
+
+fn registro_para_csv(r: &Registro) -> String {
+    let operandos: Vec<String> = r.operandos.iter().map(|n| n.to_string()).collect();
+    format!(
+        "{},{},{},{},{},{},{}",
+        r.timestamp,
+        escapar_csv(&r.operador),
+        operandos.join(";"),
+        r.resultado,
+        escapar_csv(r.expressao.as_deref().unwrap_or("")),
+        escapar_csv(r.exato.as_deref().unwrap_or("")),
+        escapar_csv(r.unidade.as_deref().unwrap_or(""))
+    )
+}
+
+fn registro_de_csv(linha: &str) -> Result<Registro, String> {
+    let campos = dividir_csv(linha)?;
+    // Arquivos exportados antes do modo exato não têm a coluna `exato`,
+    // e os anteriores às unidades não têm a coluna `unidade`
+    let (timestamp, operador, operandos, resultado, expressao, exato, unidade) = match campos.as_slice() {
+        [t, op, ops, r, e] => (t, op, ops, r, e, "", ""),
+        [t, op, ops, r, e, x] => (t, op, ops, r, e, x.as_str(), ""),
+        [t, op, ops, r, e, x, u] => (t, op, ops, r, e, x.as_str(), u.as_str()),
+        _ => return Err(format!("esperados de 5 a 7 campos, encontrados {}", campos.len())),
+    };
+
+    let operandos = if operandos.is_empty() {
+        Vec::new()
+    } else {
+        operandos
+            .split(';')
+            .map(|n| n.parse::<f64>().map_err(|_| format!("operando inválido '{}'", n)))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    Ok(Registro {
+        operandos,
+        operador: operador.clone(),
+        resultado: resultado
+            .parse()
+            .map_err(|_| format!("resultado inválido '{}'", resultado))?,
+        timestamp: timestamp
+            .parse()
+            .map_err(|_| format!("timestamp inválido '{}'", timestamp))?,
+        expressao: if expressao.is_empty() { None } else { Some(expressao.clone()) },
+        exato: if exato.is_empty() { None } else { Some(exato.to_string()) },
+        unidade: if unidade.is_empty() { None } else { Some(unidade.to_string()) },
+    })
+}
+
+// ========================================
+// HISTÓRICO
+// ========================================
+
+/// Diretório de dados do usuário, conforme o sistema operacional
+fn diretorio_dados() -> PathBuf {
+    if let Some(dir) = env::var_os("XDG_DATA_HOME") {
+        return PathBuf::from(dir);
+    }
+    if cfg!(windows)
+        && let Some(dir) = env::var_os("APPDATA")
+    {
+        return PathBuf::from(dir);
+    }
+    match env::var_os("HOME") {
+        Some(home) if cfg!(target_os = "macos") => PathBuf::from(home).join("Library/Application Support"),
+        Some(home) => PathBuf::from(home).join(".local/share"),
+        None => PathBuf::from("."),
+    }
+}
+
+pub struct Historico {
+    registros: Vec<Registro>,
+    /// Arquivo onde o histórico é salvo a cada mudança (`None` = só em memória)
+    arquivo: Option<PathBuf>,
+}
+
+impl Historico {
+    /// Histórico só em memória
+    pub fn novo() -> Self {
+        Historico {
+            registros: Vec::new(),
+            arquivo: None,
+        }
+    }
+
+    /// `<dados do usuário>/calculadora-rust/historico.json`
+    pub fn arquivo_padrao() -> PathBuf {
+        diretorio_dados().join("calculadora-rust").join("historico.json")
+    }
+
+    /// Carrega o histórico do arquivo (se existir) e passa a salvar nele
+    pub fn carregar(arquivo: PathBuf) -> Result<Self, ErroHistorico> {
+        let registros = match fs::read_to_string(&arquivo) {
+            Ok(conteudo) => serde_json::from_str(&conteudo)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Historico {
+            registros,
+            arquivo: Some(arquivo),
+        })
+    }
+
+    fn salvar(&self) {
+        let Some(arquivo) = &self.arquivo else { return };
+
+        let resultado = arquivo
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .map_err(ErroHistorico::from)
+            .and_then(|_| escrever(arquivo, &self.registros, Formato::Json));
+
+        if let Err(e) = resultado {
+            eprintln!("⚠️  Não foi possível salvar o histórico: {}", e);
+        }
+    }
+
+    pub fn adicionar(&mut self, registro: Registro) {
+        self.registros.push(registro);
+        self.salvar();
+    }
+
+    pub fn exibir(&self) {
+        if self.registros.is_empty() {
+            println!("📝 Histórico vazio");
+        } else {
+            println!("\n📝 HISTÓRICO DE OPERAÇÕES:");
+            exibir_registros(self.registros.iter());
+        }
+    }
+
+    pub fn limpar(&mut self) {
+        self.registros.clear();
+        self.salvar();
+        println!("🗑️  Histórico limpo!");
+    }
+
+    pub fn buscar_por_operador(&self, operador: &str) -> Vec<&Registro> {
+        self.registros.iter().filter(|r| r.operador == operador).collect()
+    }
+
+    /// Registros com resultado em `[minimo, maximo]`
+    pub fn buscar_por_resultado(&self, minimo: f64, maximo: f64) -> Vec<&Registro> {
+        self.registros
+            .iter()
+            .filter(|r| r.resultado >= minimo && r.resultado <= maximo)
+            .collect()
+    }
+
+    /// Exporta em CSV ou JSON, conforme a extensão; retorna quantos registros foram escritos
+    pub fn exportar(&self, caminho: &Path) -> Result<usize, ErroHistorico> {
+        escrever(caminho, &self.registros, Formato::do_caminho(caminho)?)?;
+        Ok(self.registros.len())
+    }
+
+    /// Acrescenta os registros de um arquivo CSV ou JSON; retorna quantos foram importados
+    pub fn importar(&mut self, caminho: &Path) -> Result<usize, ErroHistorico> {
+        let formato = Formato::do_caminho(caminho)?;
+        let conteudo = fs::read_to_string(caminho)?;
+
+        let novos: Vec<Registro> = match formato {
+            Formato::Json => serde_json::from_str(&conteudo)?,
+            Formato::Csv => conteudo
+                .lines()
+                .enumerate()
+                .skip(1)
+                .filter(|(_, linha)| !linha.trim().is_empty())
+                .map(|(i, linha)| registro_de_csv(linha).map_err(|mensagem| ErroHistorico::Csv { linha: i + 1, mensagem }))
+                .collect::<Result<_, _>>()?,
+        };
+
+        let quantidade = novos.len();
+        self.registros.extend(novos);
+        self.salvar();
+        Ok(quantidade)
+    }
+}
+
+/// Grava num arquivo temporário ao lado do destino e só então o renomeia por cima:
+/// se o programa cair no meio da escrita, o arquivo antigo continua inteiro
+fn escrever(caminho: &Path, registros: &[Registro], formato: Formato) -> Result<(), ErroHistorico> {
+    let conteudo = match formato {
+        Formato::Json => serde_json::to_string_pretty(registros)?,
+        Formato::Csv => {
+            let mut linhas = vec![CABECALHO_CSV.to_string()];
+            linhas.extend(registros.iter().map(registro_para_csv));
+            linhas.join("\n") + "\n"
+        }
+    };
+
+    let temporario = caminho_temporario(caminho);
+    let resultado = gravar_em_disco(&temporario, conteudo.as_bytes()).and_then(|_| fs::rename(&temporario, caminho));
+    if resultado.is_err() {
+        let _ = fs::remove_file(&temporario);
+    }
+    Ok(resultado?)
+}
+
+/// `historico.json` → `historico.json.tmp`, na mesma pasta (`rename` não atravessa discos)
+fn caminho_temporario(caminho: &Path) -> PathBuf {
+    let mut nome = caminho.as_os_str().to_owned();
+    nome.push(".tmp");
+    PathBuf::from(nome)
+}
+
+/// Escreve e espera o conteúdo chegar ao disco antes de devolver
+fn gravar_em_disco(caminho: &Path, conteudo: &[u8]) -> io::Result<()> {
+    let mut arquivo = fs::File::create(caminho)?;
+    arquivo.write_all(conteudo)?;
+    arquivo.sync_all()
+}
+
+/// Lista registros numerados, com data e hora
+pub fn exibir_registros<'a>(registros: impl Iterator<Item = &'a Registro>) {
+    for (i, r) in registros.enumerate() {
+        println!("  {}. [{}] {}", i + 1, formatar_timestamp(r.timestamp), r);
+    }
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arquivo_temporario(nome: &str) -> PathBuf {
+        env::temp_dir().join(format!("calculadora-teste-{}-{}", std::process::id(), nome))
+    }
+
+    fn exemplo() -> Historico {
+        let mut historico = Historico::novo();
+        historico.adicionar(Registro::binario('+', 2.0, 3.0, 5.0));
+        historico.adicionar(Registro::binario('/', 10.0, 4.0, 2.5));
+        historico.adicionar(Registro::unario("sqrt", 16.0, 4.0));
+        historico.adicionar(Registro::expressao("f(1, \"a\", 2)", 42.0));
+        historico.adicionar(Registro::expressao("3 km + 200 m", 3.2).com_unidade(String::from("km")));
+        historico.adicionar(Registro::binario('+', 0.1, 0.2, 0.3).com_texto_exato(String::from("0.1 + 0.2 = 3/10")));
+        historico
+    }
+
+    #[test]
+    fn test_display_registro() {
+        assert_eq!(Registro::binario('+', 2.0, 3.0, 5.0).to_string(), "2 + 3 = 5");
+        assert_eq!(Registro::binario('*', 2.0, 3.0, 6.0).to_string(), "2 × 3 = 6");
+        assert_eq!(Registro::unario("sqrt", 16.0, 4.0).to_string(), "√16 = 4");
+        assert_eq!(Registro::expressao("1 + 1", 2.0).to_string(), "1 + 1 = 2");
+        assert_eq!(
+            Registro::expressao("3 km + 200 m", 3.2).com_unidade(String::from("km")).to_string(),
+            "3 km + 200 m = 3.2 km"
+        );
+        assert_eq!(Registro::funcao("ncr", vec![5.0, 2.0], 10.0).to_string(), "ncr(5, 2) = 10");
+    }
+
+    #[test]
+    fn test_formatar_timestamp() {
+        assert_eq!(formatar_timestamp(0), "1970-01-01 00:00:00");
+        assert_eq!(formatar_timestamp(951_782_400), "2000-02-29 00:00:00");
+        assert_eq!(formatar_timestamp(1_700_000_000), "2023-11-14 22:13:20");
+    }
+
+    #[test]
+    fn test_busca() {
+        let historico = exemplo();
+        assert_eq!(historico.buscar_por_operador("/").len(), 1);
+        assert_eq!(historico.buscar_por_operador("sqrt")[0].resultado, 4.0);
+        assert_eq!(historico.buscar_por_resultado(2.5, 5.0).len(), 4);
+        assert!(historico.buscar_por_resultado(100.0, 200.0).is_empty());
+    }
+
+    #[test]
+    fn test_exportar_importar_csv_e_json() {
+        let historico = exemplo();
+
+        for nome in ["export.csv", "export.json"] {
+            let caminho = arquivo_temporario(nome);
+            assert_eq!(historico.exportar(&caminho).unwrap(), 6);
+
+            let mut importado = Historico::novo();
+            assert_eq!(importado.importar(&caminho).unwrap(), 6);
+            assert_eq!(importado.registros, historico.registros);
+
+            fs::remove_file(caminho).unwrap();
+        }
+
+        assert!(matches!(
+            historico.exportar(Path::new("historico.txt")),
+            Err(ErroHistorico::FormatoDesconhecido(_))
+        ));
+    }
+
+    #[test]
+    fn test_importar_csv_invalido() {
+        let caminho = arquivo_temporario("invalido.csv");
+        fs::write(&caminho, format!("{}\n1,+,1;2,3,\n2,+,x,3,\n", CABECALHO_CSV)).unwrap();
+
+        let erro = Historico::novo().importar(&caminho).unwrap_err();
+        assert!(matches!(erro, ErroHistorico::Csv { linha: 3, .. }));
+
+        fs::remove_file(caminho).unwrap();
+    }
+
+    #[test]
+    fn test_persistencia() {
+        let arquivo = arquivo_temporario("persistencia").join("historico.json");
+
+        let mut historico = Historico::carregar(arquivo.clone()).unwrap();
+        assert!(historico.registros.is_empty());
+        historico.adicionar(Registro::binario('^', 2.0, 10.0, 1024.0));
+
+        let recarregado = Historico::carregar(arquivo.clone()).unwrap();
+        assert_eq!(recarregado.registros, historico.registros);
+        assert!(!caminho_temporario(&arquivo).exists());
+
+        // Sobra de uma escrita interrompida: não atrapalha a leitura e é substituída
+        fs::write(caminho_temporario(&arquivo), "[{\"operandos\": [1").unwrap();
+        let mut recarregado = Historico::carregar(arquivo.clone()).unwrap();
+        recarregado.adicionar(Registro::binario('+', 1.0, 1.0, 2.0));
+        assert_eq!(Historico::carregar(arquivo.clone()).unwrap().registros.len(), 2);
+        assert!(!caminho_temporario(&arquivo).exists());
+
+        fs::remove_dir_all(arquivo.parent().unwrap()).unwrap();
+    }
+}
@@ -1,88 +1,100 @@
+mod ambiente;
+mod cientifica;
+mod cli;
+mod erro;
+mod expressao;
+mod historico;
+mod matriz;
+mod numeros;
+mod programador;
+mod unidades;
+
+use std::env;
 use std::io::{self, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use ambiente::{Ambiente, Resultado};
+use cientifica::ModoAngular;
+use erro::{CalcError, CategoriaErro, verificar};
+use historico::{Historico, Registro};
+use matriz::Valor;
+use numeros::{Decimal, Inteiro, ModoNumerico, Numero, Racional};
+use programador::{Base, ModoProgramador, PoliticaOverflow, TipoInteiro};
 
 // ========================================
 // OPERAÇÕES MATEMÁTICAS
 // ========================================
 
-fn somar(a: f64, b: f64) -> f64 {
-    a + b
+// Todas retornam `Err` em vez de deixar escapar infinito ou NaN.
+
+fn somar(a: f64, b: f64) -> Result<f64, CalcError> {
+    verificar(a + b)
 }
 
-fn subtrair(a: f64, b: f64) -> f64 {
-    a - b
+fn subtrair(a: f64, b: f64) -> Result<f64, CalcError> {
+    verificar(a - b)
 }
 
-fn multiplicar(a: f64, b: f64) -> f64 {
-    a * b
+fn multiplicar(a: f64, b: f64) -> Result<f64, CalcError> {
+    verificar(a * b)
 }
 
-/// Retorna (sucesso: bool, resultado: f64)
-fn dividir(a: f64, b: f64) -> (bool, f64) {
+fn dividir(a: f64, b: f64) -> Result<f64, CalcError> {
     if b == 0.0 {
-        (false, 0.0)
+        Err(CalcError::DivisaoPorZero)
     } else {
-        (true, a / b)
+        verificar(a / b)
     }
 }
 
-fn resto(a: f64, b: f64) -> (bool, f64) {
+fn resto(a: f64, b: f64) -> Result<f64, CalcError> {
     if b == 0.0 {
-        (false, 0.0)
+        Err(CalcError::RestoPorZero)
     } else {
-        (true, a % b)
+        verificar(a % b)
     }
 }
 
-fn potencia(base: f64, expoente: f64) -> f64 {
-    base.powf(expoente)
+fn potencia(base: f64, expoente: f64) -> Result<f64, CalcError> {
+    if base == 0.0 && expoente < 0.0 {
+        Err(CalcError::Infinito)
+    } else {
+        verificar(base.powf(expoente))
+    }
 }
 
-fn raiz_quadrada(n: f64) -> (bool, f64) {
+fn raiz_quadrada(n: f64) -> Result<f64, CalcError> {
     if n < 0.0 {
-        (false, 0.0)
+        Err(CalcError::RaizNegativa)
     } else {
-        (true, n.sqrt())
+        verificar(n.sqrt())
     }
 }
 
 // ========================================
 
-/// Processa uma operação e retorna (sucesso, resultado, mensagem)
-fn processar_operacao(op: char, a: f64, b: f64) -> (bool, f64, String) {
+/// Aplica o operador `op`; funciona com qualquer backend numérico
+/// (f64, inteiro, racional, decimal).
+fn processar_operacao<N: Numero>(op: char, a: &N, b: &N) -> Result<N, CalcError> {
     match op {
-        '+' => {
-            let resultado = somar(a, b);
-            (true, resultado, format!("{} + {} = {}", a, b, resultado))
-        },
-        '-' => {
-            let resultado = subtrair(a, b);
-            (true, resultado, format!("{} - {} = {}", a, b, resultado))
-        },
-        '*' => {
-            let resultado = multiplicar(a, b);
-            (true, resultado, format!("{} × {} = {}", a, b, resultado))
-        },
-        '/' => {
-            let (ok, resultado) = dividir(a, b);
-            if ok {
-                (true, resultado, format!("{} ÷ {} = {}", a, b, resultado))
-            } else {
-                (false, 0.0, String::from("❌ Erro: Divisão por zero!"))
-            }
-        },
-        '%' => {
-            let (ok, resultado) = resto(a, b);
-            if ok {
-                (true, resultado, format!("{} % {} = {}", a, b, resultado))
-            } else {
-                (false, 0.0, String::from("❌ Erro: Resto por zero!"))
-            }
-        },
-        '^' => {
-            let resultado = potencia(a, b);
-            (true, resultado, format!("{}^{} = {}", a, b, resultado))
-        },
-        _ => (false, 0.0, String::from("❌ Operação inválida!"))
+        '+' => a.somar(b),
+        '-' => a.subtrair(b),
+        '*' => a.multiplicar(b),
+        '/' => a.dividir(b),
+        '%' => a.resto(b),
+        '^' => a.potencia(b),
+        _ => Err(CalcError::OperadorDesconhecido(op))
+    }
+}
+
+/// Texto da operação para exibir e guardar no histórico, ex.: `3 × 4 = 12`
+fn descrever_operacao<N: Numero>(op: char, a: &N, b: &N, resultado: &N) -> String {
+    match op {
+        '*' => format!("{} × {} = {}", a, b, resultado),
+        '/' => format!("{} ÷ {} = {}", a, b, resultado),
+        '^' => format!("{}^{} = {}", a, b, resultado),
+        _ => format!("{} {} {} = {}", a, op, b, resultado),
     }
 }
 
@@ -112,9 +124,27 @@ fn ler_numero(prompt: &str) -> f64 {
     }
 }
 
-fn ler_operacao() -> char {
+fn ler_valor<N: Numero>(prompt: &str) -> N {
     loop {
-        print!("Operação (+, -, *, /, %, ^): ");
+        print!("{}", prompt);
+        io::stdout().flush().unwrap();
+        
+        match N::de_texto(&ler_linha()) {
+            Some(valor) => return valor,
+            None => println!("❌ Por favor, digite um número válido para o modo atual!")
+        }
+    }
+}
+
+/// Operação escolhida no menu: um operador ou uma função científica pelo nome
+enum Operacao {
+    Operador(char),
+    Funcao { nome: String, aridade: usize },
+}
+
+fn ler_operacao() -> Operacao {
+    loop {
+        print!("Operação (+, -, *, /, %, ^ ou função, ex.: sin, log, ncr): ");
         io::stdout().flush().unwrap();
         
         let input = ler_linha();
@@ -122,48 +152,20 @@ fn ler_operacao() -> char {
         if input.len() == 1 {
             let op = input.chars().next().unwrap();
             if "+-*/%^".contains(op) {
-                return op;
+                return Operacao::Operador(op);
             }
         }
         
-        println!("❌ Operação inválida! Use: +, -, *, /, %, ^");
-    }
-}
-
-// ========================================
-// HISTÓRICO
-// ========================================
-
-struct Historico {
-    operacoes: Vec<String>
-}
-
-impl Historico {
-    fn novo() -> Self {
-        Historico {
-            operacoes: Vec::new()
+        if let Some(aridade) = cientifica::aridade(&input) {
+            return Operacao::Funcao { nome: input.to_lowercase(), aridade };
         }
-    }
-    
-    fn adicionar(&mut self, operacao: String) {
-        self.operacoes.push(operacao);
-    }
-    
-    fn exibir(&self) {
-        if self.operacoes.is_empty() {
-            println!("📝 Histórico vazio");
+        
+        if input == "funcoes" {
+            cientifica::exibir_catalogo();
         } else {
-            println!("\n📝 HISTÓRICO DE OPERAÇÕES:");
-            for (i, op) in self.operacoes.iter().enumerate() {
-                println!("  {}. {}", i + 1, op);
-            }
+            println!("❌ Operação inválida! Use: +, -, *, /, %, ^ ou uma função ('funcoes' lista todas)");
         }
     }
-    
-    fn limpar(&mut self) {
-        self.operacoes.clear();
-        println!("🗑️  Histórico limpo!");
-    }
 }
 
 // ========================================
@@ -178,43 +180,349 @@ fn exibir_menu() {
     println!("║ 2. Raiz quadrada             ║");
     println!("║ 3. Ver histórico             ║");
     println!("║ 4. Limpar histórico          ║");
+    println!("║ 5. Expressões (REPL)         ║");
+    println!("║ 6. Buscar no histórico       ║");
+    println!("║ 7. Exportar histórico        ║");
+    println!("║ 8. Importar histórico        ║");
+    println!("║ 9. Modo numérico             ║");
+    println!("║ 10. Graus ⇄ radianos         ║");
+    println!("║ 11. Modo programador         ║");
     println!("║ 0. Sair                      ║");
     println!("╚══════════════════════════════╝");
 }
 
-fn executar_operacao(historico: &mut Historico) {
+/// Cada categoria de erro é apresentada de um jeito
+fn reportar_erro(erro: CalcError) {
+    match erro.categoria() {
+        CategoriaErro::Dominio => {
+            println!("\n❌ Erro de domínio: {}!", erro);
+            if erro == CalcError::NaoRepresentavel {
+                println!("   Dica: troque o modo numérico (opção 9) para Real");
+            }
+        },
+        CategoriaErro::ResultadoNaoFinito => {
+            println!("\n⚠️  Resultado inválido: {}!", erro);
+            println!("   O resultado não é um número finito e não foi guardado");
+        },
+        CategoriaErro::Operador => {
+            println!("\n❓ {}! Use: +, -, *, /, %, ^", erro);
+        },
+    }
+}
+
+fn executar_operacao(modo: ModoNumerico, ambiente: &mut Ambiente, historico: &mut Historico) {
+    match modo {
+        ModoNumerico::Real => executar_operacao_com::<f64>(ambiente, historico),
+        ModoNumerico::Inteiro => executar_operacao_com::<Inteiro>(ambiente, historico),
+        ModoNumerico::Racional => executar_operacao_com::<Racional>(ambiente, historico),
+        ModoNumerico::Decimal2 => executar_operacao_com::<Decimal<2>>(ambiente, historico),
+        ModoNumerico::Decimal10 => executar_operacao_com::<Decimal<10>>(ambiente, historico),
+    }
+}
+
+fn executar_operacao_com<N: Numero>(ambiente: &mut Ambiente, historico: &mut Historico) {
     println!("\n➕ NOVA OPERAÇÃO");
     
-    let a = ler_numero("Primeiro número: ");
-    let operacao = ler_operacao();
-    let b = ler_numero("Segundo número: ");
+    let a: N = ler_valor("Primeiro número: ");
+    let operacao = match ler_operacao() {
+        Operacao::Operador(op) => op,
+        Operacao::Funcao { nome, aridade } => {
+            executar_funcao(&nome, aridade, &a, ambiente, historico);
+            return;
+        }
+    };
+    let b: N = ler_valor("Segundo número: ");
     
-    let (sucesso, _resultado, mensagem) = processar_operacao(operacao, a, b);
+    match processar_operacao(operacao, &a, &b) {
+        Ok(resultado) => {
+            let mensagem = descrever_operacao(operacao, &a, &b, &resultado);
+            println!("\n{}", mensagem);
+            
+            ambiente.definir_ans(resultado.para_f64());
+            let registro = Registro::binario(operacao, a.para_f64(), b.para_f64(), resultado.para_f64());
+            historico.adicionar(if N::EXATO { registro.com_texto_exato(mensagem) } else { registro });
+        },
+        Err(erro) => reportar_erro(erro),
+    }
+}
+
+/// Funções científicas trabalham em f64, então só existem no modo Real
+fn executar_funcao<N: Numero>(nome: &str, aridade: usize, a: &N, ambiente: &mut Ambiente, historico: &mut Historico) {
+    if N::EXATO {
+        reportar_erro(CalcError::NaoRepresentavel);
+        return;
+    }
+    
+    let mut argumentos = vec![a.para_f64()];
+    if aridade == 2 {
+        argumentos.push(ler_valor("Segundo número: "));
+    }
     
-    println!("\n{}", mensagem);
+    let resultado = cientifica::aplicar(nome, &argumentos, ambiente.modo_angular())
+        .expect("ler_operacao só aceita funções do catálogo");
     
-    if sucesso {
-        historico.adicionar(mensagem);
+    match resultado {
+        Ok(resultado) => {
+            let registro = Registro::funcao(nome, argumentos, resultado);
+            println!("\n{}", registro);
+            ambiente.definir_ans(resultado);
+            historico.adicionar(registro);
+        },
+        Err(erro) => reportar_erro(erro),
+    }
+}
+
+fn executar_raiz(modo: ModoNumerico, ambiente: &mut Ambiente, historico: &mut Historico) {
+    match modo {
+        ModoNumerico::Real => executar_raiz_com::<f64>(ambiente, historico),
+        ModoNumerico::Inteiro => executar_raiz_com::<Inteiro>(ambiente, historico),
+        ModoNumerico::Racional => executar_raiz_com::<Racional>(ambiente, historico),
+        ModoNumerico::Decimal2 => executar_raiz_com::<Decimal<2>>(ambiente, historico),
+        ModoNumerico::Decimal10 => executar_raiz_com::<Decimal<10>>(ambiente, historico),
     }
 }
 
-fn executar_raiz(historico: &mut Historico) {
+fn executar_raiz_com<N: Numero>(ambiente: &mut Ambiente, historico: &mut Historico) {
     println!("\n√ RAIZ QUADRADA");
     
-    let n = ler_numero("Número: ");
-    let (ok, resultado) = raiz_quadrada(n);
+    let n: N = ler_valor("Número: ");
     
-    if ok {
-        let mensagem = format!("√{} = {}", n, resultado);
-        println!("\n{}", mensagem);
-        historico.adicionar(mensagem);
+    match n.raiz_quadrada() {
+        Ok(resultado) => {
+            let mensagem = format!("√{} = {}", n, resultado);
+            println!("\n{}", mensagem);
+            ambiente.definir_ans(resultado.para_f64());
+            let registro = Registro::unario("sqrt", n.para_f64(), resultado.para_f64());
+            historico.adicionar(if N::EXATO { registro.com_texto_exato(mensagem) } else { registro });
+        },
+        Err(erro) => reportar_erro(erro),
+    }
+}
+
+fn alternar_modo_angular(ambiente: &mut Ambiente, escolha: &str) {
+    let modo = match escolha {
+        "graus" => ModoAngular::Graus,
+        "radianos" => ModoAngular::Radianos,
+        _ => match ambiente.modo_angular() {
+            ModoAngular::Graus => ModoAngular::Radianos,
+            ModoAngular::Radianos => ModoAngular::Graus,
+        },
+    };
+    ambiente.definir_modo_angular(modo);
+    println!("✓ Ângulos em {}", modo);
+}
+
+fn escolher_modo(modo: &mut ModoNumerico) {
+    println!("\n🔧 MODO NUMÉRICO (atual: {})", modo);
+    for (i, opcao) in ModoNumerico::TODOS.iter().enumerate() {
+        println!("  {}. {}", i + 1, opcao);
+    }
+    print!("Escolha: ");
+    io::stdout().flush().unwrap();
+
+    match ler_linha().parse::<usize>() {
+        Ok(i) if (1..=ModoNumerico::TODOS.len()).contains(&i) => {
+            *modo = ModoNumerico::TODOS[i - 1];
+            println!("✓ Modo alterado para {}", modo);
+        },
+        _ => println!("❌ Opção inválida!"),
+    }
+}
+
+fn executar_repl(ambiente: &mut Ambiente, historico: &mut Historico) {
+    println!("\n🧮 MODO EXPRESSÕES (REPL)");
+    println!("Exemplos: (3 + 4) * 2 ^ 3 - sqrt(16) % 5 | x = 12.5 | f(a, b) = a^2 + b | f(x, ans)");
+    println!("          3 km + 200 m | A = [1, 2; 3, 4] | det(A) | solve(A, [5; 6])");
+    println!("Comandos: 'vars' lista o ambiente, 'funcoes' e 'unidades' listam os catálogos, 'graus'/'radianos' troca o modo angular,");
+    println!("          linha vazia ou 'sair' volta ao menu");
+
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+
+        let entrada = ler_linha();
+
+        match entrada.as_str() {
+            "" | "sair" => break,
+            "vars" => {
+                ambiente.exibir();
+                continue;
+            },
+            "funcoes" => {
+                cientifica::exibir_catalogo();
+                matriz::exibir_catalogo();
+                continue;
+            },
+            "unidades" => {
+                unidades::exibir_catalogo();
+                continue;
+            },
+            "graus" | "radianos" => {
+                alternar_modo_angular(ambiente, &entrada);
+                continue;
+            },
+            _ => {}
+        }
+
+        match ambiente.executar(&entrada) {
+            Ok(Resultado::Valor(valor)) => {
+                exibir_valor("=", &valor);
+                // O histórico guarda números; matrizes ficam só na tela
+                if let Valor::Escalar(q) = valor {
+                    let registro = Registro::expressao(&entrada, q.valor_exibido());
+                    historico.adicionar(match q.unidade_exibida() {
+                        Some(unidade) => registro.com_unidade(unidade),
+                        None => registro,
+                    });
+                }
+            },
+            Ok(Resultado::Atribuido { nome, valor }) => {
+                exibir_valor(&format!("{} =", nome), &valor);
+            },
+            Ok(Resultado::FuncaoDefinida { nome, parametros }) => {
+                println!("✓ Função {}({}) definida", nome, parametros.join(", "));
+            },
+            Err(erro) => {
+                println!("{}", erro.destacar(&entrada));
+                println!("❌ Erro: {}", erro);
+            }
+        }
+    }
+}
+
+fn executar_programador(programador: &mut ModoProgramador, historico: &mut Historico) {
+    println!("\n💻 MODO PROGRAMADOR");
+    println!("Exemplos: 0xFF & 0b1010 | 1 << 4 | ~0 | -128 >> 2 | ans ^ 0x0F");
+    println!("Comandos: 'tipo i8..u128', 'base dec|hex|oct|bin', 'overflow circular|verificar|saturar',");
+    println!("          linha vazia ou 'sair' volta ao menu");
+
+    loop {
+        print!("[{}]> ", programador);
+        io::stdout().flush().unwrap();
+
+        let entrada = ler_linha();
+        let (comando, argumento) = entrada.split_once(' ').unwrap_or((entrada.as_str(), ""));
+
+        match (comando, argumento.trim()) {
+            ("" | "sair", _) => break,
+            ("tipo", nome) => match TipoInteiro::de_nome(nome) {
+                Some(tipo) => {
+                    programador.definir_tipo(tipo);
+                    println!("✓ Inteiros do tipo {}", tipo);
+                },
+                None => println!("❌ Tipo inválido! Use i8, i16, i32, i64, i128 ou u8..u128"),
+            },
+            ("base", nome) => match Base::de_nome(nome) {
+                Some(base) => {
+                    programador.base = base;
+                    println!("✓ Resultados em {}", base);
+                },
+                None => println!("❌ Base inválida! Use dec, hex, oct ou bin"),
+            },
+            ("overflow", nome) => match PoliticaOverflow::de_nome(nome) {
+                Some(politica) => {
+                    programador.politica = politica;
+                    println!("✓ Overflow: {}", politica);
+                },
+                None => println!("❌ Política inválida! Use circular, verificar ou saturar"),
+            },
+            _ => match programador.avaliar(&entrada) {
+                Ok(palavra) => {
+                    let texto = programador.formatar(&palavra);
+                    println!("= {}", texto);
+                    historico.adicionar(
+                        Registro::expressao(&entrada, palavra.para_f64())
+                            .com_texto_exato(format!("{} = {} [{}]", entrada, texto, palavra.tipo())),
+                    );
+                },
+                Err(erro) => {
+                    println!("{}", erro.destacar(&entrada));
+                    println!("❌ Erro: {}", erro);
+                }
+            },
+        }
+    }
+}
+
+/// Números na mesma linha; matrizes como tabela, recuadas como o histórico
+fn exibir_valor(prefixo: &str, valor: &Valor) {
+    match valor {
+        Valor::Escalar(q) => println!("{} {}", prefixo, q),
+        Valor::Matriz(m) => {
+            println!("{}", prefixo);
+            for linha in m.to_string().lines() {
+                println!("  {}", linha);
+            }
+        }
+    }
+}
+
+fn buscar_historico(historico: &Historico) {
+    println!("\n🔎 BUSCAR NO HISTÓRICO");
+    println!("  1. Por operador (+, -, *, /, %, ^, sqrt, expr)");
+    println!("  2. Por faixa de resultado");
+    print!("Escolha: ");
+    io::stdout().flush().unwrap();
+
+    let encontrados = match ler_linha().as_str() {
+        "1" => {
+            print!("Operador: ");
+            io::stdout().flush().unwrap();
+            historico.buscar_por_operador(&ler_linha())
+        },
+        "2" => {
+            let minimo = ler_numero("Resultado mínimo: ");
+            let maximo = ler_numero("Resultado máximo: ");
+            historico.buscar_por_resultado(minimo, maximo)
+        },
+        _ => {
+            println!("❌ Opção inválida!");
+            return;
+        }
+    };
+
+    if encontrados.is_empty() {
+        println!("\n📝 Nenhuma operação encontrada");
     } else {
-        println!("\n❌ Erro: Raiz quadrada de número negativo!");
+        println!("\n📝 {} OPERAÇÃO(ÕES) ENCONTRADA(S):", encontrados.len());
+        historico::exibir_registros(encontrados.into_iter());
     }
 }
 
-fn main() {
-    let mut historico = Historico::novo();
+fn exportar_historico(historico: &Historico) {
+    print!("\nArquivo de destino (.csv ou .json): ");
+    io::stdout().flush().unwrap();
+
+    match historico.exportar(Path::new(&ler_linha())) {
+        Ok(quantidade) => println!("✓ {} operação(ões) exportada(s)", quantidade),
+        Err(e) => println!("❌ {}", e),
+    }
+}
+
+fn importar_historico(historico: &mut Historico) {
+    print!("\nArquivo de origem (.csv ou .json): ");
+    io::stdout().flush().unwrap();
+
+    match historico.importar(Path::new(&ler_linha())) {
+        Ok(quantidade) => println!("✓ {} operação(ões) importada(s)", quantidade),
+        Err(e) => println!("❌ {}", e),
+    }
+}
+
+fn main() -> ExitCode {
+    // Com argumentos a calculadora avalia e sai; sem, abre o menu
+    let argumentos: Vec<String> = env::args().skip(1).collect();
+    if !argumentos.is_empty() {
+        return cli::executar(argumentos);
+    }
+
+    let mut historico = Historico::carregar(Historico::arquivo_padrao()).unwrap_or_else(|e| {
+        eprintln!("⚠️  Histórico salvo não pôde ser lido ({}), começando vazio", e);
+        Historico::novo()
+    });
+    let mut ambiente = Ambiente::novo();
+    let mut modo = ModoNumerico::Real;
+    let mut programador = ModoProgramador::novo();
     
     println!("🦀 Bem-vindo à Calculadora Rust!");
     
@@ -226,10 +534,17 @@ fn main() {
         let opcao = ler_linha();
         
         match opcao.as_str() {
-            "1" => executar_operacao(&mut historico),
-            "2" => executar_raiz(&mut historico),
+            "1" => executar_operacao(modo, &mut ambiente, &mut historico),
+            "2" => executar_raiz(modo, &mut ambiente, &mut historico),
             "3" => historico.exibir(),
             "4" => historico.limpar(),
+            "5" => executar_repl(&mut ambiente, &mut historico),
+            "6" => buscar_historico(&historico),
+            "7" => exportar_historico(&historico),
+            "8" => importar_historico(&mut historico),
+            "9" => escolher_modo(&mut modo),
+            "10" => alternar_modo_angular(&mut ambiente, ""),
+            "11" => executar_programador(&mut programador, &mut historico),
             "0" => {
                 println!("\n👋 Até logo!");
                 break;
@@ -237,6 +552,8 @@ fn main() {
             _ => println!("❌ Opção inválida!")
         }
     }
+
+    ExitCode::SUCCESS
 }
 
 // ========================================
@@ -249,37 +566,61 @@ mod tests {
     
     #[test]
     fn test_somar() {
-        assert_eq!(somar(10.0, 5.0), 15.0);
-        assert_eq!(somar(-5.0, 3.0), -2.0);
+        assert_eq!(somar(10.0, 5.0), Ok(15.0));
+        assert_eq!(somar(-5.0, 3.0), Ok(-2.0));
+        assert_eq!(somar(f64::MAX, f64::MAX), Err(CalcError::Overflow));
     }
     
     #[test]
     fn test_subtrair() {
-        assert_eq!(subtrair(10.0, 5.0), 5.0);
-        assert_eq!(subtrair(3.0, 5.0), -2.0);
+        assert_eq!(subtrair(10.0, 5.0), Ok(5.0));
+        assert_eq!(subtrair(3.0, 5.0), Ok(-2.0));
     }
     
     #[test]
     fn test_multiplicar() {
-        assert_eq!(multiplicar(4.0, 5.0), 20.0);
-        assert_eq!(multiplicar(-2.0, 3.0), -6.0);
+        assert_eq!(multiplicar(4.0, 5.0), Ok(20.0));
+        assert_eq!(multiplicar(-2.0, 3.0), Ok(-6.0));
     }
     
     #[test]
     fn test_dividir() {
-        assert_eq!(dividir(10.0, 2.0), (true, 5.0));
-        assert_eq!(dividir(10.0, 0.0), (false, 0.0));
+        assert_eq!(dividir(10.0, 2.0), Ok(5.0));
+        assert_eq!(dividir(10.0, 0.0), Err(CalcError::DivisaoPorZero));
+        assert_eq!(resto(10.0, 0.0), Err(CalcError::RestoPorZero));
     }
     
     #[test]
     fn test_potencia() {
-        assert_eq!(potencia(2.0, 3.0), 8.0);
-        assert_eq!(potencia(5.0, 2.0), 25.0);
+        assert_eq!(potencia(2.0, 3.0), Ok(8.0));
+        assert_eq!(potencia(5.0, 2.0), Ok(25.0));
+        assert_eq!(potencia(10.0, 400.0), Err(CalcError::Overflow));
+        assert_eq!(potencia(0.0, -1.0), Err(CalcError::Infinito));
+        assert_eq!(potencia(-8.0, 1.0 / 3.0), Err(CalcError::NaoNumerico));
+    }
+    
+    #[test]
+    fn test_processar_operacao_em_todos_os_modos() {
+        assert_eq!(processar_operacao('+', &0.1f64, &0.2f64), Ok(0.30000000000000004));
+
+        let a = Racional::de_texto("0.1").unwrap();
+        let b = Racional::de_texto("0.2").unwrap();
+        let resultado = processar_operacao('+', &a, &b).unwrap();
+        assert_eq!(descrever_operacao('+', &a, &b, &resultado), "1/10 + 1/5 = 3/10");
+
+        let a = Decimal::<2>::de_texto("0.1").unwrap();
+        let b = Decimal::<2>::de_texto("0.2").unwrap();
+        let resultado = processar_operacao('*', &a, &b).unwrap();
+        assert_eq!(descrever_operacao('*', &a, &b, &resultado), "0.10 × 0.20 = 0.02");
+
+        let zero = Inteiro::de_texto("0").unwrap();
+        assert_eq!(processar_operacao('/', &zero, &zero), Err(CalcError::DivisaoPorZero));
+        assert_eq!(processar_operacao('?', &zero, &zero), Err(CalcError::OperadorDesconhecido('?')));
     }
     
     #[test]
     fn test_raiz_quadrada() {
-        assert_eq!(raiz_quadrada(16.0), (true, 4.0));
-        assert_eq!(raiz_quadrada(-4.0).0, false);
+        assert_eq!(raiz_quadrada(16.0), Ok(4.0));
+        assert_eq!(raiz_quadrada(-4.0), Err(CalcError::RaizNegativa));
     }
 }
\ No newline at end of file
@@ -0,0 +1,583 @@
+use std::fmt;
+
+use crate::erro::{CalcError, verificar};
+use crate::unidades::{self, Quantidade};
+use crate::{dividir, multiplicar, somar, subtrair};
+
+// ========================================
+// MATRIZ
+// ========================================
+
+/// Matriz de números puros, guardada linha a linha. Vetores são matrizes
+/// de uma linha (`[1, 2, 3]`) ou de uma coluna (`[1; 2; 3]`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matriz {
+    linhas: usize,
+    colunas: usize,
+    dados: Vec<f64>,
+}
+
+impl Matriz {
+    /// Monta a matriz a partir das linhas; `None` se vazia ou com linhas de tamanhos diferentes
+    pub fn de_linhas(linhas: Vec<Vec<f64>>) -> Option<Matriz> {
+        let colunas = linhas.first()?.len();
+        if colunas == 0 || linhas.iter().any(|l| l.len() != colunas) {
+            return None;
+        }
+        Some(Matriz {
+            linhas: linhas.len(),
+            colunas,
+            dados: linhas.into_iter().flatten().collect(),
+        })
+    }
+
+    fn identidade(n: usize) -> Matriz {
+        Matriz {
+            linhas: n,
+            colunas: n,
+            dados: (0..n * n).map(|k| if k / n == k % n { 1.0 } else { 0.0 }).collect(),
+        }
+    }
+
+    pub fn dimensoes(&self) -> (usize, usize) {
+        (self.linhas, self.colunas)
+    }
+
+    pub fn para_linhas(&self) -> Vec<Vec<f64>> {
+        self.dados.chunks(self.colunas).map(|linha| linha.to_vec()).collect()
+    }
+
+    fn exigir_quadrada(&self) -> Result<usize, CalcError> {
+        if self.linhas == self.colunas {
+            Ok(self.linhas)
+        } else {
+            Err(CalcError::MatrizNaoQuadrada { linhas: self.linhas, colunas: self.colunas })
+        }
+    }
+
+    fn incompativel(&self, outra: &Matriz, operacao: &'static str) -> CalcError {
+        CalcError::DimensoesIncompativeis {
+            operacao,
+            esquerda: self.dimensoes(),
+            direita: outra.dimensoes(),
+        }
+    }
+
+    /// Aplica `f` elemento a elemento
+    fn mapear(&self, f: impl Fn(f64) -> Result<f64, CalcError>) -> Result<Matriz, CalcError> {
+        Ok(Matriz {
+            linhas: self.linhas,
+            colunas: self.colunas,
+            dados: self.dados.iter().map(|&v| f(v)).collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Combina elemento a elemento duas matrizes de mesmas dimensões
+    fn combinar(
+        &self,
+        outra: &Matriz,
+        operacao: &'static str,
+        f: fn(f64, f64) -> Result<f64, CalcError>,
+    ) -> Result<Matriz, CalcError> {
+        if self.dimensoes() != outra.dimensoes() {
+            return Err(self.incompativel(outra, operacao));
+        }
+        Ok(Matriz {
+            linhas: self.linhas,
+            colunas: self.colunas,
+            dados: self
+                .dados
+                .iter()
+                .zip(&outra.dados)
+                .map(|(&a, &b)| f(a, b))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn somar(&self, outra: &Matriz) -> Result<Matriz, CalcError> {
+        self.combinar(outra, "+", somar)
+    }
+
+    pub fn subtrair(&self, outra: &Matriz) -> Result<Matriz, CalcError> {
+        self.combinar(outra, "-", subtrair)
+    }
+
+    pub fn escalar(&self, k: f64) -> Result<Matriz, CalcError> {
+        self.mapear(|v| multiplicar(v, k))
+    }
+
+    pub fn dividir_por(&self, k: f64) -> Result<Matriz, CalcError> {
+        self.mapear(|v| dividir(v, k))
+    }
+
+    /// Produto de matrizes: colunas da esquerda = linhas da direita
+    pub fn multiplicar(&self, outra: &Matriz) -> Result<Matriz, CalcError> {
+        if self.colunas != outra.linhas {
+            return Err(self.incompativel(outra, "*"));
+        }
+
+        let mut dados = Vec::with_capacity(self.linhas * outra.colunas);
+        for i in 0..self.linhas {
+            for j in 0..outra.colunas {
+                let soma: f64 = (0..self.colunas)
+                    .map(|k| self.dados[i * self.colunas + k] * outra.dados[k * outra.colunas + j])
+                    .sum();
+                dados.push(verificar(soma)?);
+            }
+        }
+        Ok(Matriz { linhas: self.linhas, colunas: outra.colunas, dados })
+    }
+
+    pub fn transposta(&self) -> Matriz {
+        let dados = (0..self.colunas)
+            .flat_map(|j| (0..self.linhas).map(move |i| (i, j)))
+            .map(|(i, j)| self.dados[i * self.colunas + j])
+            .collect();
+        Matriz { linhas: self.colunas, colunas: self.linhas, dados }
+    }
+
+    /// Eliminação de Gauss com pivotamento parcial
+    pub fn determinante(&self) -> Result<f64, CalcError> {
+        let n = self.exigir_quadrada()?;
+        let mut a = self.dados.clone();
+        let mut escalas = escalas_das_linhas(&a, n);
+        let mut det = 1.0;
+
+        for col in 0..n {
+            let Some(pivo) = linha_do_pivo(&a, &escalas, n, col) else {
+                return Ok(0.0);
+            };
+            if pivo != col {
+                trocar_linhas(&mut a, n, pivo, col);
+                escalas.swap(pivo, col);
+                det = -det;
+            }
+
+            let p = a[col * n + col];
+            det *= p;
+            for lin in col + 1..n {
+                let fator = a[lin * n + col] / p;
+                for k in col..n {
+                    a[lin * n + k] -= fator * a[col * n + k];
+                }
+            }
+        }
+
+        verificar(unidades::arredondar(det))
+    }
+
+    /// Gauss-Jordan em `[self | b]`: devolve `x` tal que `self · x = b`
+    fn eliminar(&self, b: &Matriz) -> Result<Matriz, CalcError> {
+        let n = self.exigir_quadrada()?;
+        let m = b.colunas;
+        let mut a = self.dados.clone();
+        let mut x = b.dados.clone();
+        let mut escalas = escalas_das_linhas(&a, n);
+        // Maior parcela que já entrou em cada elemento de `x` (ver `limpar`)
+        let mut parcelas: Vec<f64> = x.iter().map(|v| v.abs()).collect();
+
+        for col in 0..n {
+            let pivo = linha_do_pivo(&a, &escalas, n, col).ok_or(CalcError::MatrizSingular)?;
+            trocar_linhas(&mut a, n, pivo, col);
+            trocar_linhas(&mut x, m, pivo, col);
+            trocar_linhas(&mut parcelas, m, pivo, col);
+            escalas.swap(pivo, col);
+
+            let p = a[col * n + col];
+            a[col * n..(col + 1) * n].iter_mut().for_each(|v| *v /= p);
+            x[col * m..(col + 1) * m].iter_mut().for_each(|v| *v /= p);
+            parcelas[col * m..(col + 1) * m].iter_mut().for_each(|v| *v /= p.abs());
+
+            for lin in (0..n).filter(|&lin| lin != col) {
+                let fator = a[lin * n + col];
+                if fator == 0.0 {
+                    continue;
+                }
+                for k in 0..n {
+                    a[lin * n + k] -= fator * a[col * n + k];
+                }
+                for k in 0..m {
+                    x[lin * m + k] -= fator * x[col * m + k];
+                    parcelas[lin * m + k] = parcelas[lin * m + k].max((fator * parcelas[col * m + k]).abs());
+                }
+            }
+        }
+
+        limpar(&mut x, &parcelas)?;
+        Ok(Matriz { linhas: n, colunas: m, dados: x })
+    }
+
+    pub fn inversa(&self) -> Result<Matriz, CalcError> {
+        let n = self.exigir_quadrada()?;
+        self.eliminar(&Matriz::identidade(n))
+    }
+
+    /// Resolve o sistema `self · x = b`, com `b` em coluna (ou várias colunas)
+    pub fn resolver(&self, b: &Matriz) -> Result<Matriz, CalcError> {
+        let n = self.exigir_quadrada()?;
+        if b.linhas != n {
+            return Err(self.incompativel(b, "solve"));
+        }
+        self.eliminar(b)
+    }
+
+    /// Potência inteira de matriz quadrada; expoente negativo usa a inversa
+    pub fn potencia(&self, expoente: f64) -> Result<Matriz, CalcError> {
+        let n = self.exigir_quadrada()?;
+        if expoente.fract() != 0.0 {
+            return Err(CalcError::ForaDoDominio("^"));
+        }
+        if expoente < 0.0 {
+            return self.inversa()?.potencia(-expoente);
+        }
+
+        // Exponenciação por quadrados
+        let mut resultado = Matriz::identidade(n);
+        let mut base = self.clone();
+        let mut restante = expoente as u64;
+        while restante > 0 {
+            if restante % 2 == 1 {
+                resultado = resultado.multiplicar(&base)?;
+            }
+            restante /= 2;
+            if restante > 0 {
+                base = base.multiplicar(&base)?;
+            }
+        }
+        Ok(resultado)
+    }
+
+    /// Forma compacta, como se digita: `[1, 2; 3, 4]`
+    pub fn em_linha(&self) -> String {
+        let linhas: Vec<String> = self
+            .para_linhas()
+            .iter()
+            .map(|linha| linha.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", "))
+            .collect();
+        format!("[{}]", linhas.join("; "))
+    }
+}
+
+/// Erro relativo abaixo do qual um valor é só resíduo de arredondamento
+const RESIDUO_RELATIVO: f64 = 1e-12;
+
+/// Maior valor absoluto de cada linha, a régua de cada uma na eliminação
+fn escalas_das_linhas(a: &[f64], n: usize) -> Vec<f64> {
+    a.chunks(n).map(|linha| linha.iter().fold(0.0_f64, |maior, v| maior.max(v.abs()))).collect()
+}
+
+/// Linha, a partir de `col`, com o maior valor na coluna `col` relativo à escala da
+/// própria linha (pivotamento parcial escalado). `None` se todos os candidatos são
+/// resíduo perto da sua escala: a matriz é singular. Comparar com a própria linha,
+/// e não com o maior elemento da matriz, mantém `[1e15, 0; 0, 1]` inversível
+fn linha_do_pivo(a: &[f64], escalas: &[f64], n: usize, col: usize) -> Option<usize> {
+    let relativo = |i: usize| match escalas[i] {
+        0.0 => 0.0,
+        escala => a[i * n + col].abs() / escala,
+    };
+    (col..n).filter(|&i| relativo(i) > RESIDUO_RELATIVO).max_by(|&i, &j| relativo(i).total_cmp(&relativo(j)))
+}
+
+fn trocar_linhas(dados: &mut [f64], largura: usize, i: usize, j: usize) {
+    if i != j {
+        for k in 0..largura {
+            dados.swap(i * largura + k, j * largura + k);
+        }
+    }
+}
+
+/// Zera resíduos da eliminação (`5.5e-17` em vez de 0) e confere que tudo é finito.
+/// Resíduo é o que sobrou de um cancelamento: pequeno perto da maior parcela que
+/// entrou naquele elemento (`parcelas`), nunca perto dos outros elementos do resultado
+fn limpar(dados: &mut [f64], parcelas: &[f64]) -> Result<(), CalcError> {
+    for (v, parcela) in dados.iter_mut().zip(parcelas) {
+        *v = if v.abs() <= parcela * RESIDUO_RELATIVO { 0.0 } else { unidades::arredondar(verificar(*v)?) };
+    }
+    Ok(())
+}
+
+/// Uma linha por linha da matriz, com as colunas alinhadas à direita:
+/// ```text
+/// [ 1  -2 ]
+/// [ 3  10 ]
+/// ```
+impl fmt::Display for Matriz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let textos: Vec<String> = self.dados.iter().map(|v| v.to_string()).collect();
+        let larguras: Vec<usize> = (0..self.colunas)
+            .map(|j| {
+                (0..self.linhas)
+                    .map(|i| textos[i * self.colunas + j].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let linhas: Vec<String> = textos
+            .chunks(self.colunas)
+            .map(|linha| {
+                let celulas: Vec<String> = linha
+                    .iter()
+                    .zip(&larguras)
+                    .map(|(texto, largura)| format!("{:>largura$}", texto, largura = largura))
+                    .collect();
+                format!("[ {} ]", celulas.join("  "))
+            })
+            .collect();
+        write!(f, "{}", linhas.join("\n"))
+    }
+}
+
+// ========================================
+// VALOR DO AVALIADOR
+// ========================================
+
+/// Resultado de uma expressão: número (com ou sem unidade) ou matriz
+#[derive(Debug, Clone, PartialEq)]
+pub enum Valor {
+    Escalar(Quantidade),
+    Matriz(Matriz),
+}
+
+impl Valor {
+    pub fn numero(valor: f64) -> Self {
+        Valor::Escalar(Quantidade::numero(valor))
+    }
+
+    /// Tipo do operando para mensagens de erro: a unidade, o próprio número ou `[2×3]`
+    pub fn descrever(&self) -> String {
+        match self {
+            Valor::Escalar(q) => q.unidade_exibida().unwrap_or_else(|| q.valor.to_string()),
+            Valor::Matriz(m) => format!("[{}×{}]", m.linhas, m.colunas),
+        }
+    }
+
+    pub fn negar(&self) -> Result<Valor, CalcError> {
+        match self {
+            Valor::Escalar(q) => q.negar().map(Valor::Escalar),
+            Valor::Matriz(m) => m.escalar(-1.0).map(Valor::Matriz),
+        }
+    }
+
+    /// Aplica um operador binário a números, matrizes ou número com matriz
+    pub fn operar(op: char, a: &Valor, b: &Valor) -> Result<Valor, CalcError> {
+        match (a, b) {
+            _ if !"+-*/%^".contains(op) => Err(CalcError::OperadorDesconhecido(op)),
+            (Valor::Escalar(a), Valor::Escalar(b)) => {
+                let resultado = match op {
+                    '+' => a.somar(b),
+                    '-' => a.subtrair(b),
+                    '*' => a.multiplicar(b),
+                    '/' => a.dividir(b),
+                    '%' => a.resto(b),
+                    _ => a.potencia(b),
+                };
+                resultado.map(Valor::Escalar)
+            }
+            (Valor::Matriz(a), Valor::Matriz(b)) => match op {
+                '+' => a.somar(b).map(Valor::Matriz),
+                '-' => a.subtrair(b).map(Valor::Matriz),
+                '*' => a.multiplicar(b).map(Valor::Matriz),
+                _ => Err(CalcError::TiposIncompativeis),
+            },
+            (Valor::Escalar(k), Valor::Matriz(m)) | (Valor::Matriz(m), Valor::Escalar(k)) if op == '*' => {
+                m.escalar(numero_puro(k)?).map(Valor::Matriz)
+            }
+            (Valor::Matriz(m), Valor::Escalar(k)) if op == '/' => m.dividir_por(numero_puro(k)?).map(Valor::Matriz),
+            (Valor::Matriz(m), Valor::Escalar(k)) if op == '^' => m.potencia(numero_puro(k)?).map(Valor::Matriz),
+            _ => Err(CalcError::TiposIncompativeis),
+        }
+    }
+}
+
+/// Matrizes só se combinam com números sem unidade
+fn numero_puro(q: &Quantidade) -> Result<f64, CalcError> {
+    if q.adimensional() {
+        Ok(q.valor)
+    } else {
+        Err(CalcError::UnidadesIncompativeis)
+    }
+}
+
+impl From<Quantidade> for Valor {
+    fn from(q: Quantidade) -> Self {
+        Valor::Escalar(q)
+    }
+}
+
+impl fmt::Display for Valor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Valor::Escalar(q) => write!(f, "{}", q),
+            Valor::Matriz(m) => write!(f, "{}", m),
+        }
+    }
+}
+
+// ========================================
+// FUNÇÕES DE MATRIZES
+// ========================================
+
+pub const FUNCOES: [(&str, usize, &str); 4] = [
+    ("det", 1, "determinante"),
+    ("inv", 1, "matriz inversa"),
+    ("transpose", 1, "matriz transposta"),
+    ("solve", 2, "resolve A·x = b (b em coluna)"),
+];
+
+pub fn aridade(nome: &str) -> Option<usize> {
+    let nome = nome.to_lowercase();
+    FUNCOES.iter().find(|(n, _, _)| *n == nome).map(|(_, aridade, _)| *aridade)
+}
+
+/// Aplica uma função de matrizes, ou `None` se `nome` não for uma delas
+pub fn aplicar(nome: &str, args: &[Valor]) -> Option<Result<Valor, CalcError>> {
+    let nome = nome.to_lowercase();
+    aridade(&nome)?;
+
+    let matrizes: Vec<&Matriz> = match args
+        .iter()
+        .map(|v| match v {
+            Valor::Matriz(m) => Ok(m),
+            Valor::Escalar(_) => Err(CalcError::TiposIncompativeis),
+        })
+        .collect()
+    {
+        Ok(matrizes) => matrizes,
+        Err(erro) => return Some(Err(erro)),
+    };
+
+    Some(match (nome.as_str(), matrizes.as_slice()) {
+        ("det", [a]) => a.determinante().map(Valor::numero),
+        ("inv", [a]) => a.inversa().map(Valor::Matriz),
+        ("transpose", [a]) => Ok(Valor::Matriz(a.transposta())),
+        ("solve", [a, b]) => a.resolver(b).map(Valor::Matriz),
+        _ => unreachable!("a aridade é conferida pelo avaliador"),
+    })
+}
+
+pub fn exibir_catalogo() {
+    println!("\n🔢 MATRIZES ([1, 2; 3, 4] — ',' separa colunas, ';' separa linhas):");
+    for (nome, aridade, descricao) in FUNCOES.iter() {
+        let assinatura = if *aridade == 2 {
+            format!("{}(A, b)", nome)
+        } else {
+            format!("{}(A)", nome)
+        };
+        println!("  {:<12} {}", assinatura, descricao);
+    }
+    println!("  Operadores: A + B, A - B, A * B, 2 * A, A / 2, A ^ n");
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn m(linhas: &[&[f64]]) -> Matriz {
+        Matriz::de_linhas(linhas.iter().map(|l| l.to_vec()).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_de_linhas() {
+        assert_eq!(m(&[&[1.0, 2.0], &[3.0, 4.0]]).dimensoes(), (2, 2));
+        assert!(Matriz::de_linhas(vec![vec![1.0, 2.0], vec![3.0]]).is_none());
+        assert!(Matriz::de_linhas(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_soma_e_produto() {
+        let a = m(&[&[1.0, 2.0], &[3.0, 4.0]]);
+        let b = m(&[&[5.0, 6.0], &[7.0, 8.0]]);
+        assert_eq!(a.somar(&b), Ok(m(&[&[6.0, 8.0], &[10.0, 12.0]])));
+        assert_eq!(a.multiplicar(&b), Ok(m(&[&[19.0, 22.0], &[43.0, 50.0]])));
+        assert_eq!(a.escalar(2.0), Ok(m(&[&[2.0, 4.0], &[6.0, 8.0]])));
+
+        let linha = m(&[&[1.0, 2.0, 3.0]]);
+        assert_eq!(linha.multiplicar(&linha.transposta()), Ok(m(&[&[14.0]])));
+    }
+
+    #[test]
+    fn test_dimensoes_incompativeis() {
+        let a = m(&[&[1.0, 2.0], &[3.0, 4.0]]);
+        let v = m(&[&[1.0, 2.0, 3.0]]);
+        assert_eq!(
+            a.somar(&v),
+            Err(CalcError::DimensoesIncompativeis { operacao: "+", esquerda: (2, 2), direita: (1, 3) })
+        );
+        assert!(matches!(a.multiplicar(&v), Err(CalcError::DimensoesIncompativeis { operacao: "*", .. })));
+        assert_eq!(v.determinante(), Err(CalcError::MatrizNaoQuadrada { linhas: 1, colunas: 3 }));
+        assert!(matches!(a.resolver(&v.transposta()), Err(CalcError::DimensoesIncompativeis { .. })));
+    }
+
+    #[test]
+    fn test_transposta() {
+        let a = m(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]);
+        assert_eq!(a.transposta(), m(&[&[1.0, 4.0], &[2.0, 5.0], &[3.0, 6.0]]));
+    }
+
+    #[test]
+    fn test_determinante() {
+        assert_eq!(m(&[&[1.0, 2.0], &[3.0, 4.0]]).determinante(), Ok(-2.0));
+        assert_eq!(m(&[&[2.0, 0.0, 1.0], &[1.0, 3.0, 2.0], &[1.0, 1.0, 2.0]]).determinante(), Ok(6.0));
+        assert_eq!(m(&[&[1.0, 2.0], &[2.0, 4.0]]).determinante(), Ok(0.0));
+        assert_eq!(m(&[&[1.0, 2.0], &[0.0, 0.0]]).determinante(), Ok(0.0));
+    }
+
+    #[test]
+    fn test_escalas_muito_diferentes() {
+        let diagonal = m(&[&[1e15, 0.0], &[0.0, 1.0]]);
+        assert_eq!(diagonal.determinante(), Ok(1e15));
+        let inversa = diagonal.inversa().unwrap();
+        assert!((inversa.dados[0] - 1e-15).abs() < 1e-28);
+        assert_eq!(inversa.dados[1..], [0.0, 0.0, 1.0]);
+        assert_eq!(m(&[&[1e-20, 0.0], &[0.0, 1e20]]).determinante(), Ok(1.0));
+
+        // Valores pequenos e corretos no resultado não são resíduo
+        let identidade = Matriz::identidade(2);
+        assert_eq!(identidade.resolver(&m(&[&[1e15], &[1.0]])), Ok(m(&[&[1e15], &[1.0]])));
+        assert_eq!(diagonal.resolver(&m(&[&[1e15], &[1e-9]])), Ok(m(&[&[1.0], &[1e-9]])));
+
+        // Singular continua singular em qualquer escala
+        assert_eq!(m(&[&[1e15, 2e15], &[1e-15, 2e-15]]).inversa(), Err(CalcError::MatrizSingular));
+        assert_eq!(m(&[&[1e15, 1.0], &[1.0, 1e-15]]).determinante(), Ok(0.0));
+    }
+
+    #[test]
+    fn test_inversa_e_sistemas() {
+        let a = m(&[&[4.0, 7.0], &[2.0, 6.0]]);
+        assert_eq!(a.inversa(), Ok(m(&[&[0.6, -0.7], &[-0.2, 0.4]])));
+        assert_eq!(m(&[&[1.0, 2.0], &[2.0, 4.0]]).inversa(), Err(CalcError::MatrizSingular));
+
+        // x + y = 3, x - y = 1
+        let sistema = m(&[&[1.0, 1.0], &[1.0, -1.0]]);
+        assert_eq!(sistema.resolver(&m(&[&[3.0], &[1.0]])), Ok(m(&[&[2.0], &[1.0]])));
+    }
+
+    #[test]
+    fn test_potencia() {
+        let a = m(&[&[1.0, 1.0], &[1.0, 0.0]]);
+        assert_eq!(a.potencia(10.0), Ok(m(&[&[89.0, 55.0], &[55.0, 34.0]])));
+        assert_eq!(a.potencia(0.0), Ok(Matriz::identidade(2)));
+        assert_eq!(a.potencia(0.5), Err(CalcError::ForaDoDominio("^")));
+    }
+
+    #[test]
+    fn test_exibicao_alinhada() {
+        let a = m(&[&[1.0, -2.0], &[3.0, 10.0]]);
+        assert_eq!(a.to_string(), "[ 1  -2 ]\n[ 3  10 ]");
+        assert_eq!(a.em_linha(), "[1, -2; 3, 10]");
+    }
+
+    #[test]
+    fn test_valor_operar() {
+        let a = Valor::Matriz(m(&[&[1.0, 2.0]]));
+        assert_eq!(Valor::operar('*', &Valor::numero(3.0), &a), Ok(Valor::Matriz(m(&[&[3.0, 6.0]]))));
+        assert_eq!(Valor::operar('+', &a, &Valor::numero(1.0)), Err(CalcError::TiposIncompativeis));
+        assert_eq!(Valor::operar('/', &Valor::numero(1.0), &a), Err(CalcError::TiposIncompativeis));
+        assert_eq!(Valor::operar('+', &Valor::numero(1.0), &Valor::numero(2.0)), Ok(Valor::numero(3.0)));
+    }
+}
@@ -0,0 +1,498 @@
+use std::fmt;
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use crate::erro::CalcError;
+
+// ========================================
+// TRAIT NUMERO
+// ========================================
+
+/// Maior expoente aceito nos modos exatos (evita números gigantes)
+const LIMITE_EXPOENTE: u32 = 10_000;
+
+/// Backend numérico da calculadora.
+///
+/// Os modos exatos nunca dão overflow; em compensação, alguns resultados
+/// (como `2^0.5` em racional) dão `CalcError::NaoRepresentavel`.
+pub trait Numero: Clone + fmt::Display {
+    /// `true` se o backend não introduz erros de arredondamento binário
+    const EXATO: bool;
+
+    fn de_texto(texto: &str) -> Option<Self>;
+    fn para_f64(&self) -> f64;
+
+    fn somar(&self, outro: &Self) -> Result<Self, CalcError>;
+    fn subtrair(&self, outro: &Self) -> Result<Self, CalcError>;
+    fn multiplicar(&self, outro: &Self) -> Result<Self, CalcError>;
+    fn dividir(&self, outro: &Self) -> Result<Self, CalcError>;
+    fn resto(&self, outro: &Self) -> Result<Self, CalcError>;
+    fn potencia(&self, expoente: &Self) -> Result<Self, CalcError>;
+    fn raiz_quadrada(&self) -> Result<Self, CalcError>;
+}
+
+// ========================================
+// REAL (f64)
+// ========================================
+
+impl Numero for f64 {
+    const EXATO: bool = false;
+
+    /// Recusa `inf` e `NaN`, que `str::parse` aceitaria
+    fn de_texto(texto: &str) -> Option<Self> {
+        texto.parse().ok().filter(|n: &f64| n.is_finite())
+    }
+
+    fn para_f64(&self) -> f64 {
+        *self
+    }
+
+    fn somar(&self, outro: &Self) -> Result<Self, CalcError> {
+        crate::somar(*self, *outro)
+    }
+
+    fn subtrair(&self, outro: &Self) -> Result<Self, CalcError> {
+        crate::subtrair(*self, *outro)
+    }
+
+    fn multiplicar(&self, outro: &Self) -> Result<Self, CalcError> {
+        crate::multiplicar(*self, *outro)
+    }
+
+    fn dividir(&self, outro: &Self) -> Result<Self, CalcError> {
+        crate::dividir(*self, *outro)
+    }
+
+    fn resto(&self, outro: &Self) -> Result<Self, CalcError> {
+        crate::resto(*self, *outro)
+    }
+
+    fn potencia(&self, expoente: &Self) -> Result<Self, CalcError> {
+        crate::potencia(*self, *expoente)
+    }
+
+    fn raiz_quadrada(&self) -> Result<Self, CalcError> {
+        crate::raiz_quadrada(*self)
+    }
+}
+
+// ========================================
+// INTEIRO (precisão arbitrária)
+// ========================================
+
+/// Inteiro sem limite de tamanho; `/` é divisão inteira (trunca em direção a zero)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inteiro(pub BigInt);
+
+impl fmt::Display for Inteiro {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Numero for Inteiro {
+    const EXATO: bool = true;
+
+    fn de_texto(texto: &str) -> Option<Self> {
+        BigInt::from_str(texto).ok().map(Inteiro)
+    }
+
+    fn para_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+
+    fn somar(&self, outro: &Self) -> Result<Self, CalcError> {
+        Ok(Inteiro(&self.0 + &outro.0))
+    }
+
+    fn subtrair(&self, outro: &Self) -> Result<Self, CalcError> {
+        Ok(Inteiro(&self.0 - &outro.0))
+    }
+
+    fn multiplicar(&self, outro: &Self) -> Result<Self, CalcError> {
+        Ok(Inteiro(&self.0 * &outro.0))
+    }
+
+    fn dividir(&self, outro: &Self) -> Result<Self, CalcError> {
+        if outro.0.is_zero() {
+            Err(CalcError::DivisaoPorZero)
+        } else {
+            Ok(Inteiro(&self.0 / &outro.0))
+        }
+    }
+
+    fn resto(&self, outro: &Self) -> Result<Self, CalcError> {
+        if outro.0.is_zero() {
+            Err(CalcError::RestoPorZero)
+        } else {
+            Ok(Inteiro(&self.0 % &outro.0))
+        }
+    }
+
+    /// Só expoentes inteiros não negativos dão resultado inteiro
+    fn potencia(&self, expoente: &Self) -> Result<Self, CalcError> {
+        let expoente = expoente
+            .0
+            .to_u32()
+            .filter(|e| *e <= LIMITE_EXPOENTE)
+            .ok_or(CalcError::NaoRepresentavel)?;
+        Ok(Inteiro(self.0.pow(expoente)))
+    }
+
+    /// Só quadrados perfeitos
+    fn raiz_quadrada(&self) -> Result<Self, CalcError> {
+        if self.0.is_negative() {
+            return Err(CalcError::RaizNegativa);
+        }
+        let raiz = self.0.sqrt();
+        if &raiz * &raiz == self.0 {
+            Ok(Inteiro(raiz))
+        } else {
+            Err(CalcError::NaoRepresentavel)
+        }
+    }
+}
+
+// ========================================
+// RACIONAL (fração exata)
+// ========================================
+
+/// Lê inteiros (`-12`), decimais (`0.1`) e frações (`3/4`) sem perder precisão
+fn racional_de_texto(texto: &str) -> Option<BigRational> {
+    if let Some((num, den)) = texto.split_once('/') {
+        let num = BigInt::from_str(num.trim()).ok()?;
+        let den = BigInt::from_str(den.trim()).ok()?;
+        return (!den.is_zero()).then(|| BigRational::new(num, den));
+    }
+
+    let (negativo, digitos) = match texto.strip_prefix('-') {
+        Some(resto) => (true, resto),
+        None => (false, texto.strip_prefix('+').unwrap_or(texto)),
+    };
+    let (inteira, fracao) = digitos.split_once('.').unwrap_or((digitos, ""));
+
+    if inteira.is_empty() && fracao.is_empty() {
+        return None;
+    }
+    if !inteira.chars().chain(fracao.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let numerador = BigInt::from_str(&format!("0{}{}", inteira, fracao)).ok()?;
+    let denominador = BigInt::from(10u32).pow(fracao.len() as u32);
+    let valor = BigRational::new(numerador, denominador);
+
+    Some(if negativo { -valor } else { valor })
+}
+
+/// Converte o expoente para `i32` se for inteiro e não for grande demais
+fn expoente_inteiro(expoente: &BigRational) -> Result<i32, CalcError> {
+    if !expoente.is_integer() {
+        return Err(CalcError::NaoRepresentavel);
+    }
+    expoente
+        .to_integer()
+        .to_i32()
+        .filter(|e| e.unsigned_abs() <= LIMITE_EXPOENTE)
+        .ok_or(CalcError::NaoRepresentavel)
+}
+
+fn racional_para_f64(r: &BigRational) -> f64 {
+    r.to_f64().unwrap_or(f64::NAN)
+}
+
+/// Fração exata; `0.1 + 0.2` dá exatamente `3/10`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Racional(pub BigRational);
+
+impl fmt::Display for Racional {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Numero for Racional {
+    const EXATO: bool = true;
+
+    fn de_texto(texto: &str) -> Option<Self> {
+        racional_de_texto(texto).map(Racional)
+    }
+
+    fn para_f64(&self) -> f64 {
+        racional_para_f64(&self.0)
+    }
+
+    fn somar(&self, outro: &Self) -> Result<Self, CalcError> {
+        Ok(Racional(&self.0 + &outro.0))
+    }
+
+    fn subtrair(&self, outro: &Self) -> Result<Self, CalcError> {
+        Ok(Racional(&self.0 - &outro.0))
+    }
+
+    fn multiplicar(&self, outro: &Self) -> Result<Self, CalcError> {
+        Ok(Racional(&self.0 * &outro.0))
+    }
+
+    fn dividir(&self, outro: &Self) -> Result<Self, CalcError> {
+        if outro.0.is_zero() {
+            Err(CalcError::DivisaoPorZero)
+        } else {
+            Ok(Racional(&self.0 / &outro.0))
+        }
+    }
+
+    fn resto(&self, outro: &Self) -> Result<Self, CalcError> {
+        if outro.0.is_zero() {
+            Err(CalcError::RestoPorZero)
+        } else {
+            Ok(Racional(&self.0 % &outro.0))
+        }
+    }
+
+    /// Expoentes inteiros (inclusive negativos); expoente fracionário daria irracional
+    fn potencia(&self, expoente: &Self) -> Result<Self, CalcError> {
+        let expoente = expoente_inteiro(&expoente.0)?;
+        if expoente < 0 && self.0.is_zero() {
+            return Err(CalcError::Infinito);
+        }
+        Ok(Racional(self.0.pow(expoente)))
+    }
+
+    /// Só quando numerador e denominador são quadrados perfeitos
+    fn raiz_quadrada(&self) -> Result<Self, CalcError> {
+        let numerador = Inteiro(self.0.numer().clone()).raiz_quadrada()?;
+        let denominador = Inteiro(self.0.denom().clone()).raiz_quadrada()?;
+        Ok(Racional(BigRational::new(numerador.0, denominador.0)))
+    }
+}
+
+// ========================================
+// DECIMAL (escala fixa)
+// ========================================
+
+/// Divide arredondando para o par mais próximo ("arredondamento bancário")
+fn dividir_arredondando(numerador: &BigInt, denominador: &BigInt) -> BigInt {
+    let (quociente, resto) = numerador.div_rem(denominador);
+    let dobro_resto = resto.abs() * 2u32;
+    let divisor = denominador.abs();
+
+    let arredondar_para_longe = dobro_resto > divisor || (dobro_resto == divisor && quociente.is_odd());
+    if !arredondar_para_longe {
+        return quociente;
+    }
+
+    if numerador.is_negative() != denominador.is_negative() {
+        quociente - 1
+    } else {
+        quociente + 1
+    }
+}
+
+/// Decimal com exatamente `CASAS` casas depois da vírgula, guardado como
+/// inteiro em unidades de `10^-CASAS` (ex.: centavos para `CASAS = 2`).
+/// Multiplicação, divisão e raiz arredondam com arredondamento bancário.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decimal<const CASAS: u32>(pub BigInt);
+
+impl<const CASAS: u32> Decimal<CASAS> {
+    fn fator() -> BigInt {
+        BigInt::from(10u32).pow(CASAS)
+    }
+
+    fn de_racional(r: &BigRational) -> Self {
+        Decimal(dividir_arredondando(&(r.numer() * Self::fator()), r.denom()))
+    }
+
+    fn para_racional(&self) -> BigRational {
+        BigRational::new(self.0.clone(), Self::fator())
+    }
+}
+
+impl<const CASAS: u32> fmt::Display for Decimal<CASAS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (inteira, fracao) = self.0.abs().div_rem(&Self::fator());
+        let sinal = if self.0.is_negative() { "-" } else { "" };
+
+        if CASAS == 0 {
+            write!(f, "{}{}", sinal, inteira)
+        } else {
+            write!(f, "{}{}.{:0>largura$}", sinal, inteira, fracao, largura = CASAS as usize)
+        }
+    }
+}
+
+impl<const CASAS: u32> Numero for Decimal<CASAS> {
+    const EXATO: bool = true;
+
+    fn de_texto(texto: &str) -> Option<Self> {
+        racional_de_texto(texto).map(|r| Self::de_racional(&r))
+    }
+
+    fn para_f64(&self) -> f64 {
+        racional_para_f64(&self.para_racional())
+    }
+
+    fn somar(&self, outro: &Self) -> Result<Self, CalcError> {
+        Ok(Decimal(&self.0 + &outro.0))
+    }
+
+    fn subtrair(&self, outro: &Self) -> Result<Self, CalcError> {
+        Ok(Decimal(&self.0 - &outro.0))
+    }
+
+    fn multiplicar(&self, outro: &Self) -> Result<Self, CalcError> {
+        Ok(Decimal(dividir_arredondando(&(&self.0 * &outro.0), &Self::fator())))
+    }
+
+    fn dividir(&self, outro: &Self) -> Result<Self, CalcError> {
+        if outro.0.is_zero() {
+            Err(CalcError::DivisaoPorZero)
+        } else {
+            Ok(Decimal(dividir_arredondando(&(&self.0 * Self::fator()), &outro.0)))
+        }
+    }
+
+    fn resto(&self, outro: &Self) -> Result<Self, CalcError> {
+        if outro.0.is_zero() {
+            Err(CalcError::RestoPorZero)
+        } else {
+            Ok(Decimal(&self.0 % &outro.0))
+        }
+    }
+
+    fn potencia(&self, expoente: &Self) -> Result<Self, CalcError> {
+        let expoente = expoente_inteiro(&expoente.para_racional())?;
+        if expoente < 0 && self.0.is_zero() {
+            return Err(CalcError::Infinito);
+        }
+        Ok(Self::de_racional(&self.para_racional().pow(expoente)))
+    }
+
+    fn raiz_quadrada(&self) -> Result<Self, CalcError> {
+        if self.0.is_negative() {
+            return Err(CalcError::RaizNegativa);
+        }
+        // √(a / 10^C) = √(a · 10^C) / 10^C, arredondado ao mais próximo
+        let radicando = &self.0 * Self::fator();
+        let raiz = radicando.sqrt();
+        let meio = &raiz * 2u32 + 1u32;
+        if &meio * &meio <= radicando * 4u32 {
+            Ok(Decimal(raiz + 1u32))
+        } else {
+            Ok(Decimal(raiz))
+        }
+    }
+}
+
+// ========================================
+// MODO NUMÉRICO
+// ========================================
+
+/// Qual backend a calculadora está usando
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModoNumerico {
+    Real,
+    Inteiro,
+    Racional,
+    Decimal2,
+    Decimal10,
+}
+
+impl ModoNumerico {
+    pub const TODOS: [ModoNumerico; 5] = [
+        ModoNumerico::Real,
+        ModoNumerico::Inteiro,
+        ModoNumerico::Racional,
+        ModoNumerico::Decimal2,
+        ModoNumerico::Decimal10,
+    ];
+}
+
+impl fmt::Display for ModoNumerico {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ModoNumerico::Real => write!(f, "Real (f64)"),
+            ModoNumerico::Inteiro => write!(f, "Inteiro (precisão arbitrária)"),
+            ModoNumerico::Racional => write!(f, "Racional (fração exata)"),
+            ModoNumerico::Decimal2 => write!(f, "Decimal (2 casas, financeiro)"),
+            ModoNumerico::Decimal10 => write!(f, "Decimal (10 casas)"),
+        }
+    }
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ler<N: Numero>(texto: &str) -> N {
+        N::de_texto(texto).unwrap()
+    }
+
+    #[test]
+    fn test_racional_soma_exata() {
+        let soma = ler::<Racional>("0.1").somar(&ler("0.2")).unwrap();
+        assert_eq!(soma.to_string(), "3/10");
+        assert_eq!(soma, ler("3/10"));
+        assert_ne!(0.1 + 0.2, 0.3);
+    }
+
+    #[test]
+    fn test_racional_potencia_e_raiz() {
+        assert_eq!(ler::<Racional>("2/3").potencia(&ler("-2")).unwrap().to_string(), "9/4");
+        assert_eq!(ler::<Racional>("9/16").raiz_quadrada().unwrap().to_string(), "3/4");
+        assert_eq!(ler::<Racional>("2").raiz_quadrada(), Err(CalcError::NaoRepresentavel));
+        assert_eq!(ler::<Racional>("2").potencia(&ler("1/2")), Err(CalcError::NaoRepresentavel));
+        assert_eq!(ler::<Racional>("0").potencia(&ler("-1")), Err(CalcError::Infinito));
+        assert_eq!(ler::<Racional>("1").dividir(&ler("0")), Err(CalcError::DivisaoPorZero));
+    }
+
+    #[test]
+    fn test_inteiro_grande() {
+        let grande = ler::<Inteiro>("2").potencia(&ler("100")).unwrap();
+        assert_eq!(grande.to_string(), "1267650600228229401496703205376");
+        assert_eq!(ler::<Inteiro>("7").dividir(&ler("2")).unwrap().to_string(), "3");
+        assert_eq!(ler::<Inteiro>("-7").resto(&ler("2")).unwrap().to_string(), "-1");
+        assert!(Inteiro::de_texto("1.5").is_none());
+        assert_eq!(ler::<Inteiro>("2").potencia(&ler("-1")), Err(CalcError::NaoRepresentavel));
+        assert_eq!(ler::<Inteiro>("-4").raiz_quadrada(), Err(CalcError::RaizNegativa));
+    }
+
+    #[test]
+    fn test_decimal_financeiro() {
+        let soma = ler::<Decimal<2>>("0.1").somar(&ler("0.2")).unwrap();
+        assert_eq!(soma.to_string(), "0.30");
+
+        // 10,00 / 3 = 3,33 e 3 × 3,33 = 9,99 (sem sobras "mágicas" de ponto flutuante)
+        let terco = ler::<Decimal<2>>("10").dividir(&ler("3")).unwrap();
+        assert_eq!(terco.to_string(), "3.33");
+        assert_eq!(terco.multiplicar(&ler("3")).unwrap().to_string(), "9.99");
+        assert_eq!(ler::<Decimal<2>>("-1.5").to_string(), "-1.50");
+    }
+
+    #[test]
+    fn test_decimal_arredondamento_bancario() {
+        assert_eq!(ler::<Decimal<2>>("0.125").to_string(), "0.12");
+        assert_eq!(ler::<Decimal<2>>("0.135").to_string(), "0.14");
+        assert_eq!(ler::<Decimal<2>>("-0.125").to_string(), "-0.12");
+        assert_eq!(ler::<Decimal<2>>("2").raiz_quadrada().unwrap().to_string(), "1.41");
+        assert_eq!(ler::<Decimal<10>>("2").raiz_quadrada().unwrap().to_string(), "1.4142135624");
+    }
+
+    #[test]
+    fn test_real_delega_para_funcoes_da_calculadora() {
+        assert_eq!(2.0f64.potencia(&10.0), Ok(1024.0));
+        assert_eq!(1.0f64.dividir(&0.0), Err(CalcError::DivisaoPorZero));
+        assert_eq!((-4.0f64).raiz_quadrada(), Err(CalcError::RaizNegativa));
+        assert_eq!(f64::MAX.multiplicar(&2.0), Err(CalcError::Overflow));
+        assert!(f64::de_texto("inf").is_none());
+    }
+}
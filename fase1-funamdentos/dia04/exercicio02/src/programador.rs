@@ -0,0 +1,751 @@
+use std::fmt;
+
+use crate::erro::CalcError;
+use crate::expressao::ErroExpressao;
+
+// ========================================
+// TIPOS, POLÍTICAS E BASES
+// ========================================
+
+/// Largura e sinal do inteiro, como os tipos `i8`..`u128` do Rust
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TipoInteiro {
+    bits: u32,
+    com_sinal: bool,
+}
+
+impl TipoInteiro {
+    pub const PADRAO: TipoInteiro = TipoInteiro { bits: 32, com_sinal: true };
+
+    /// `i8`, `u16`, ..., `u128`
+    pub fn de_nome(nome: &str) -> Option<TipoInteiro> {
+        let com_sinal = match nome.chars().next()? {
+            'i' => true,
+            'u' => false,
+            _ => return None,
+        };
+        let bits: u32 = nome[1..].parse().ok()?;
+        matches!(bits, 8 | 16 | 32 | 64 | 128).then_some(TipoInteiro { bits, com_sinal })
+    }
+
+    fn mascara(&self) -> u128 {
+        u128::MAX >> (128 - self.bits)
+    }
+}
+
+impl fmt::Display for TipoInteiro {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", if self.com_sinal { 'i' } else { 'u' }, self.bits)
+    }
+}
+
+/// Executa `$corpo` com `$t` sendo o tipo primitivo correspondente a `$tipo`,
+/// para usar os próprios `wrapping_*`, `checked_*` e `saturating_*` do Rust
+macro_rules! com_tipo {
+    ($tipo:expr, $t:ident => $corpo:expr) => {
+        match ($tipo.bits, $tipo.com_sinal) {
+            (8, true) => {
+                type $t = i8;
+                $corpo
+            }
+            (8, false) => {
+                type $t = u8;
+                $corpo
+            }
+            (16, true) => {
+                type $t = i16;
+                $corpo
+            }
+            (16, false) => {
+                type $t = u16;
+                $corpo
+            }
+            (32, true) => {
+                type $t = i32;
+                $corpo
+            }
+            (32, false) => {
+                type $t = u32;
+                $corpo
+            }
+            (64, true) => {
+                type $t = i64;
+                $corpo
+            }
+            (64, false) => {
+                type $t = u64;
+                $corpo
+            }
+            (128, true) => {
+                type $t = i128;
+                $corpo
+            }
+            // Os `as u128` do corpo viram conversões para o próprio tipo
+            #[allow(clippy::unnecessary_cast)]
+            (128, false) => {
+                type $t = u128;
+                $corpo
+            }
+            _ => unreachable!("TipoInteiro só existe com 8, 16, 32, 64 ou 128 bits"),
+        }
+    };
+}
+
+/// O que fazer quando o resultado não cabe no tipo (ver `demonstrar_overflow` em dia02)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoliticaOverflow {
+    /// `wrapping_*`: 255u8 + 1 = 0
+    Circular,
+    /// `checked_*`: 255u8 + 1 é erro
+    Verificar,
+    /// `saturating_*`: 255u8 + 1 = 255
+    Saturar,
+}
+
+impl PoliticaOverflow {
+    pub fn de_nome(nome: &str) -> Option<PoliticaOverflow> {
+        match nome {
+            "circular" | "wrap" => Some(PoliticaOverflow::Circular),
+            "verificar" | "check" => Some(PoliticaOverflow::Verificar),
+            "saturar" | "saturate" => Some(PoliticaOverflow::Saturar),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PoliticaOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PoliticaOverflow::Circular => write!(f, "circular"),
+            PoliticaOverflow::Verificar => write!(f, "verificar"),
+            PoliticaOverflow::Saturar => write!(f, "saturar"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base {
+    Decimal,
+    Hexadecimal,
+    Octal,
+    Binario,
+}
+
+impl Base {
+    pub const TODAS: [Base; 4] = [Base::Decimal, Base::Hexadecimal, Base::Octal, Base::Binario];
+
+    pub fn de_nome(nome: &str) -> Option<Base> {
+        match nome {
+            "dec" => Some(Base::Decimal),
+            "hex" => Some(Base::Hexadecimal),
+            "oct" => Some(Base::Octal),
+            "bin" => Some(Base::Binario),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Base {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Base::Decimal => write!(f, "dec"),
+            Base::Hexadecimal => write!(f, "hex"),
+            Base::Octal => write!(f, "oct"),
+            Base::Binario => write!(f, "bin"),
+        }
+    }
+}
+
+// ========================================
+// PALAVRA (inteiro de largura fixa)
+// ========================================
+
+/// Inteiro guardado como padrão de bits (complemento de dois) do tamanho do tipo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palavra {
+    bits: u128,
+    tipo: TipoInteiro,
+}
+
+impl Palavra {
+    pub fn tipo(&self) -> TipoInteiro {
+        self.tipo
+    }
+
+    /// Valor com sinal, em decimal
+    pub fn decimal(&self) -> String {
+        com_tipo!(self.tipo, T => (self.bits as T).to_string())
+    }
+
+    pub fn para_f64(&self) -> f64 {
+        com_tipo!(self.tipo, T => (self.bits as T) as f64)
+    }
+
+    /// Hexadecimal, octal e binário mostram o padrão de bits: -1i8 é `0xFF`
+    pub fn formatar(&self, base: Base) -> String {
+        match base {
+            Base::Decimal => self.decimal(),
+            Base::Hexadecimal => format!("0x{:X}", self.bits),
+            Base::Octal => format!("0o{:o}", self.bits),
+            Base::Binario => format!("0b{}", agrupar(&format!("{:b}", self.bits), 4)),
+        }
+    }
+
+    /// Reinterpreta em outro tipo, como o `as` do Rust (estende o sinal e trunca)
+    pub fn converter(&self, tipo: TipoInteiro) -> Palavra {
+        let estendido = if self.negativo() { self.bits | !self.tipo.mascara() } else { self.bits };
+        Palavra { bits: estendido & tipo.mascara(), tipo }
+    }
+
+    fn negativo(&self) -> bool {
+        self.tipo.com_sinal && self.bits >> (self.tipo.bits - 1) & 1 == 1
+    }
+
+    /// Valor usado como quantidade de bits num deslocamento
+    fn quantidade_de_bits(&self, op: &'static str) -> Result<u32, CalcError> {
+        if self.negativo() {
+            return Err(CalcError::ForaDoDominio(op));
+        }
+        Ok(u32::try_from(self.bits).unwrap_or(u32::MAX))
+    }
+}
+
+impl fmt::Display for Palavra {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.decimal())
+    }
+}
+
+/// Separa os dígitos em grupos a partir da direita: `11111111` → `1111_1111`
+fn agrupar(digitos: &str, tamanho: usize) -> String {
+    let caracteres: Vec<char> = digitos.chars().collect();
+    let grupos: Vec<String> = caracteres
+        .rchunks(tamanho)
+        .rev()
+        .map(|grupo| grupo.iter().collect())
+        .collect();
+    grupos.join("_")
+}
+
+// ========================================
+// OPERAÇÕES
+// ========================================
+
+/// Converte um literal para o tipo. Hex, octal e binário que cabem na largura
+/// são padrões de bits (`0xFF` em i8 é -1); o resto é valor e segue a política
+fn de_literal(
+    valor: u128,
+    negativo: bool,
+    padrao_de_bits: bool,
+    tipo: TipoInteiro,
+    politica: PoliticaOverflow,
+) -> Result<Palavra, CalcError> {
+    let mascara = tipo.mascara();
+    if padrao_de_bits && !negativo && valor <= mascara {
+        return Ok(Palavra { bits: valor, tipo });
+    }
+
+    let com_sinal: Option<i128> = match negativo {
+        true if valor <= i128::MAX as u128 => Some(-(valor as i128)),
+        true if valor == 1 << 127 => Some(i128::MIN),
+        _ => None,
+    };
+    let cabe: Option<u128> = com_tipo!(tipo, T => match negativo {
+        true => com_sinal.and_then(|v| T::try_from(v).ok()).map(|v| v as u128 & mascara),
+        false => T::try_from(valor).ok().map(|v| v as u128 & mascara),
+    });
+
+    let bits = match (cabe, politica) {
+        (Some(bits), _) => bits,
+        (None, PoliticaOverflow::Circular) => (if negativo { valor.wrapping_neg() } else { valor }) & mascara,
+        (None, PoliticaOverflow::Verificar) => return Err(CalcError::Overflow),
+        (None, PoliticaOverflow::Saturar) => com_tipo!(tipo, T => (if negativo { T::MIN } else { T::MAX }) as u128 & mascara),
+    };
+    Ok(Palavra { bits, tipo })
+}
+
+/// `+ - * / %` com os métodos do próprio tipo
+fn aritmetica(op: &str, a: Palavra, b: Palavra, politica: PoliticaOverflow) -> Result<Palavra, CalcError> {
+    use PoliticaOverflow::{Circular, Saturar, Verificar};
+
+    let tipo = a.tipo;
+    let mascara = tipo.mascara();
+    com_tipo!(tipo, T => {
+        let (x, y) = (a.bits as T, b.bits as T);
+        if y == 0 && op == "/" {
+            return Err(CalcError::DivisaoPorZero);
+        }
+        if y == 0 && op == "%" {
+            return Err(CalcError::RestoPorZero);
+        }
+
+        let resultado: Option<T> = match (op, politica) {
+            ("+", Circular) => Some(x.wrapping_add(y)),
+            ("+", Verificar) => x.checked_add(y),
+            ("+", Saturar) => Some(x.saturating_add(y)),
+            ("-", Circular) => Some(x.wrapping_sub(y)),
+            ("-", Verificar) => x.checked_sub(y),
+            ("-", Saturar) => Some(x.saturating_sub(y)),
+            ("*", Circular) => Some(x.wrapping_mul(y)),
+            ("*", Verificar) => x.checked_mul(y),
+            ("*", Saturar) => Some(x.saturating_mul(y)),
+            // Só MIN / -1 transborda
+            ("/", Circular) => Some(x.wrapping_div(y)),
+            ("/", Verificar) => x.checked_div(y),
+            ("/", Saturar) => Some(x.saturating_div(y)),
+            ("%", Verificar) => x.checked_rem(y),
+            ("%", _) => Some(x.wrapping_rem(y)),
+            _ => unreachable!("operador aritmético desconhecido '{}'", op),
+        };
+        resultado
+            .map(|r| Palavra { bits: r as u128 & mascara, tipo })
+            .ok_or(CalcError::Overflow)
+    })
+}
+
+/// `<<` e `>>` (aritmético para tipos com sinal). Circular usa o resto da
+/// quantidade (`wrapping_shl`). Um `<<` que perde bits (ou muda o sinal) é
+/// overflow: verificar dá erro e saturar vai para MAX/MIN, como `saturating_mul`.
+/// `>>` pela largura ou mais: verificar é erro, saturar fica com 0 ou -1
+fn deslocar(op: &'static str, a: Palavra, b: Palavra, politica: PoliticaOverflow) -> Result<Palavra, CalcError> {
+    let n = b.quantidade_de_bits(op)?;
+    let tipo = a.tipo;
+    let mascara = tipo.mascara();
+    let negativo = tipo.com_sinal && (a.bits >> (tipo.bits - 1)) & 1 == 1;
+    com_tipo!(tipo, T => {
+        let x = a.bits as T;
+        // Exato quando desfazer o deslocamento devolve `x`; zero nunca perde bits
+        let exato = match x {
+            0 => Some(0),
+            _ => x.checked_shl(n).filter(|r| r >> n == x),
+        };
+        let resultado: Option<T> = match (op, politica) {
+            ("<<", PoliticaOverflow::Circular) => Some(x.wrapping_shl(n)),
+            (">>", PoliticaOverflow::Circular) => Some(x.wrapping_shr(n)),
+            ("<<", PoliticaOverflow::Verificar) => exato,
+            (">>", PoliticaOverflow::Verificar) => x.checked_shr(n),
+            ("<<", PoliticaOverflow::Saturar) => Some(exato.unwrap_or(if negativo { T::MIN } else { T::MAX })),
+            // Em dois passos para não deslocar pela largura: fica 0 ou -1
+            (">>", PoliticaOverflow::Saturar) => Some(x.checked_shr(n).unwrap_or((x >> (tipo.bits - 1)) >> 1)),
+            _ => unreachable!("deslocamento desconhecido '{}'", op),
+        };
+        resultado
+            .map(|r| Palavra { bits: r as u128 & mascara, tipo })
+            .ok_or(CalcError::Overflow)
+    })
+}
+
+fn operar(op: &'static str, a: Palavra, b: Palavra, politica: PoliticaOverflow) -> Result<Palavra, CalcError> {
+    let tipo = a.tipo;
+    match op {
+        "&" => Ok(Palavra { bits: a.bits & b.bits, tipo }),
+        "|" => Ok(Palavra { bits: a.bits | b.bits, tipo }),
+        "^" => Ok(Palavra { bits: a.bits ^ b.bits, tipo }),
+        "<<" | ">>" => deslocar(op, a, b, politica),
+        _ => aritmetica(op, a, b, politica),
+    }
+}
+
+// ========================================
+// TOKENIZADOR E AVALIADOR
+// ========================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// `padrao_de_bits` é verdadeiro para `0x`, `0o` e `0b`
+    Literal { valor: u128, padrao_de_bits: bool },
+    Ans,
+    Operador(&'static str),
+    AbreParenteses,
+    FechaParenteses,
+}
+
+const OPERADORES: [&str; 12] = ["<<", ">>", "+", "-", "*", "/", "%", "&", "|", "^", "~", "("];
+
+fn tokenizar(entrada: &str) -> Result<Vec<(Token, usize)>, ErroExpressao> {
+    let caracteres: Vec<char> = entrada.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < caracteres.len() {
+        let c = caracteres[i];
+        let inicio = i;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_alphanumeric() {
+            while i < caracteres.len() && (caracteres[i].is_ascii_alphanumeric() || caracteres[i] == '_') {
+                i += 1;
+            }
+            let texto: String = caracteres[inicio..i].iter().filter(|c| **c != '_').collect();
+            let token = match texto.as_str() {
+                "ans" => Token::Ans,
+                _ => literal(&texto).ok_or_else(|| ErroExpressao {
+                    posicao: inicio,
+                    mensagem: format!("Número inválido '{}'", texto),
+                })?,
+            };
+            tokens.push((token, inicio));
+            continue;
+        }
+
+        let resto: String = caracteres[i..].iter().take(2).collect();
+        let token = match OPERADORES.iter().find(|op| resto.starts_with(*op)) {
+            Some(&"(") => Token::AbreParenteses,
+            Some(op) => Token::Operador(op),
+            None if c == ')' => Token::FechaParenteses,
+            None => {
+                return Err(ErroExpressao {
+                    posicao: inicio,
+                    mensagem: format!("Caractere inesperado '{}'", c),
+                });
+            }
+        };
+        i += match &token {
+            Token::Operador(op) => op.len(),
+            _ => 1,
+        };
+        tokens.push((token, inicio));
+    }
+
+    Ok(tokens)
+}
+
+/// `42`, `0x2A`, `0o52` ou `0b101010`
+fn literal(texto: &str) -> Option<Token> {
+    let (digitos, base) = match texto.get(..2) {
+        Some("0x") | Some("0X") => (&texto[2..], 16),
+        Some("0o") | Some("0O") => (&texto[2..], 8),
+        Some("0b") | Some("0B") => (&texto[2..], 2),
+        _ => (texto, 10),
+    };
+    let valor = u128::from_str_radix(digitos, base).ok()?;
+    Some(Token::Literal { valor, padrao_de_bits: base != 10 })
+}
+
+/// Descida recursiva com a precedência de C: `* / %` > `+ -` > `<< >>` > `&` > `^` > `|`
+struct Avaliador<'a> {
+    tokens: Vec<(Token, usize)>,
+    atual: usize,
+    fim: usize,
+    modo: &'a ModoProgramador,
+}
+
+type Nivel<'a> = fn(&mut Avaliador<'a>) -> Result<Palavra, ErroExpressao>;
+
+impl<'a> Avaliador<'a> {
+    fn posicao(&self) -> usize {
+        self.tokens.get(self.atual).map_or(self.fim, |(_, posicao)| *posicao)
+    }
+
+    fn espiar(&self) -> Option<&Token> {
+        self.tokens.get(self.atual).map(|(token, _)| token)
+    }
+
+    fn erro(&self, posicao: usize, erro: CalcError) -> ErroExpressao {
+        let mensagem = match erro {
+            CalcError::Overflow => format!("{} em {}", erro, self.modo.tipo),
+            _ => erro.to_string(),
+        };
+        ErroExpressao { posicao, mensagem }
+    }
+
+    /// Um nível de operadores binários associativos à esquerda
+    fn binario(&mut self, operadores: &[&str], proximo: Nivel<'a>) -> Result<Palavra, ErroExpressao> {
+        let mut esquerda = proximo(self)?;
+        while let Some(Token::Operador(op)) = self.espiar() {
+            if !operadores.contains(op) {
+                break;
+            }
+            let (op, posicao) = (*op, self.posicao());
+            self.atual += 1;
+            let direita = proximo(self)?;
+            esquerda = operar(op, esquerda, direita, self.modo.politica).map_err(|e| self.erro(posicao, e))?;
+        }
+        Ok(esquerda)
+    }
+
+    fn ou(&mut self) -> Result<Palavra, ErroExpressao> {
+        self.binario(&["|"], Self::ou_exclusivo)
+    }
+
+    fn ou_exclusivo(&mut self) -> Result<Palavra, ErroExpressao> {
+        self.binario(&["^"], Self::e)
+    }
+
+    fn e(&mut self) -> Result<Palavra, ErroExpressao> {
+        self.binario(&["&"], Self::deslocamento)
+    }
+
+    fn deslocamento(&mut self) -> Result<Palavra, ErroExpressao> {
+        self.binario(&["<<", ">>"], Self::soma)
+    }
+
+    fn soma(&mut self) -> Result<Palavra, ErroExpressao> {
+        self.binario(&["+", "-"], Self::termo)
+    }
+
+    fn termo(&mut self) -> Result<Palavra, ErroExpressao> {
+        self.binario(&["*", "/", "%"], Self::unario)
+    }
+
+    fn unario(&mut self) -> Result<Palavra, ErroExpressao> {
+        let posicao = self.posicao();
+        match self.espiar() {
+            Some(Token::Operador("-")) => {
+                self.atual += 1;
+                // `-128` em i8 é um literal só, não a negação de 128 (que não cabe)
+                if let Some(Token::Literal { valor, padrao_de_bits: false }) = self.espiar() {
+                    let valor = *valor;
+                    self.atual += 1;
+                    return de_literal(valor, true, false, self.modo.tipo, self.modo.politica)
+                        .map_err(|e| self.erro(posicao, e));
+                }
+                let valor = self.unario()?;
+                let zero = Palavra { bits: 0, tipo: valor.tipo };
+                aritmetica("-", zero, valor, self.modo.politica).map_err(|e| self.erro(posicao, e))
+            }
+            Some(Token::Operador("~")) => {
+                self.atual += 1;
+                let valor = self.unario()?;
+                Ok(Palavra { bits: !valor.bits & valor.tipo.mascara(), tipo: valor.tipo })
+            }
+            Some(Token::Operador("+")) => {
+                self.atual += 1;
+                self.unario()
+            }
+            _ => self.primario(),
+        }
+    }
+
+    fn primario(&mut self) -> Result<Palavra, ErroExpressao> {
+        let posicao = self.posicao();
+        let token = self.tokens.get(self.atual).map(|(token, _)| token.clone());
+        self.atual += 1;
+
+        match token {
+            Some(Token::Literal { valor, padrao_de_bits }) => {
+                de_literal(valor, false, padrao_de_bits, self.modo.tipo, self.modo.politica)
+                    .map_err(|e| self.erro(posicao, e))
+            }
+            Some(Token::Ans) => self.modo.ans.ok_or(ErroExpressao {
+                posicao,
+                mensagem: String::from("'ans' ainda não tem valor"),
+            }),
+            Some(Token::AbreParenteses) => {
+                let valor = self.ou()?;
+                match self.espiar() {
+                    Some(Token::FechaParenteses) => {
+                        self.atual += 1;
+                        Ok(valor)
+                    }
+                    _ => Err(ErroExpressao { posicao: self.posicao(), mensagem: String::from("Esperado ')'") }),
+                }
+            }
+            Some(_) => Err(ErroExpressao { posicao, mensagem: String::from("Token inesperado") }),
+            None => Err(ErroExpressao { posicao, mensagem: String::from("Expressão incompleta") }),
+        }
+    }
+}
+
+// ========================================
+// MODO PROGRAMADOR
+// ========================================
+
+/// Configuração e último resultado do modo programador
+pub struct ModoProgramador {
+    pub tipo: TipoInteiro,
+    pub politica: PoliticaOverflow,
+    pub base: Base,
+    ans: Option<Palavra>,
+}
+
+impl ModoProgramador {
+    pub fn novo() -> Self {
+        ModoProgramador {
+            tipo: TipoInteiro::PADRAO,
+            politica: PoliticaOverflow::Circular,
+            base: Base::Decimal,
+            ans: None,
+        }
+    }
+
+    /// Troca o tipo; `ans` é convertido como um `as`
+    pub fn definir_tipo(&mut self, tipo: TipoInteiro) {
+        self.tipo = tipo;
+        self.ans = self.ans.map(|ans| ans.converter(tipo));
+    }
+
+    pub fn avaliar(&mut self, entrada: &str) -> Result<Palavra, ErroExpressao> {
+        let mut avaliador = Avaliador {
+            tokens: tokenizar(entrada)?,
+            atual: 0,
+            fim: entrada.chars().count(),
+            modo: self,
+        };
+        let resultado = avaliador.ou()?;
+        if avaliador.atual < avaliador.tokens.len() {
+            return Err(ErroExpressao {
+                posicao: avaliador.posicao(),
+                mensagem: String::from("Token inesperado"),
+            });
+        }
+
+        self.ans = Some(resultado);
+        Ok(resultado)
+    }
+
+    /// Resultado na base escolhida, com as outras entre parênteses
+    pub fn formatar(&self, palavra: &Palavra) -> String {
+        let outras: Vec<String> = Base::TODAS
+            .iter()
+            .filter(|base| **base != self.base)
+            .map(|base| palavra.formatar(*base))
+            .collect();
+        format!("{}  ({})", palavra.formatar(self.base), outras.join(" | "))
+    }
+}
+
+impl fmt::Display for ModoProgramador {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.tipo, self.base, self.politica)
+    }
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modo(tipo: &str, politica: PoliticaOverflow) -> ModoProgramador {
+        let mut modo = ModoProgramador::novo();
+        modo.definir_tipo(TipoInteiro::de_nome(tipo).unwrap());
+        modo.politica = politica;
+        modo
+    }
+
+    fn calcular(tipo: &str, politica: PoliticaOverflow, entrada: &str) -> Result<String, String> {
+        modo(tipo, politica)
+            .avaliar(entrada)
+            .map(|p| p.decimal())
+            .map_err(|e| e.mensagem)
+    }
+
+    #[test]
+    fn test_tipo_de_nome() {
+        assert_eq!(TipoInteiro::de_nome("u8").unwrap().to_string(), "u8");
+        assert_eq!(TipoInteiro::de_nome("i128").unwrap().to_string(), "i128");
+        assert!(TipoInteiro::de_nome("i7").is_none());
+        assert!(TipoInteiro::de_nome("f32").is_none());
+    }
+
+    #[test]
+    fn test_overflow_como_demonstrar_overflow() {
+        use PoliticaOverflow::*;
+        assert_eq!(calcular("u8", Circular, "255 + 1"), Ok(String::from("0")));
+        assert_eq!(calcular("u8", Verificar, "255 + 1"), Err(String::from("Resultado grande demais (overflow) em u8")));
+        assert_eq!(calcular("u8", Saturar, "255 + 1"), Ok(String::from("255")));
+
+        assert_eq!(calcular("u8", Circular, "0 - 1"), Ok(String::from("255")));
+        assert!(calcular("u8", Verificar, "0 - 1").is_err());
+        assert_eq!(calcular("u8", Saturar, "0 - 1"), Ok(String::from("0")));
+
+        assert_eq!(calcular("i8", Circular, "127 + 1"), Ok(String::from("-128")));
+        assert_eq!(calcular("i8", Saturar, "-100 - 100"), Ok(String::from("-128")));
+        assert_eq!(calcular("i8", Circular, "-128 / -1"), Ok(String::from("-128")));
+        assert!(calcular("i8", Verificar, "-128 / -1").is_err());
+    }
+
+    #[test]
+    fn test_literais() {
+        use PoliticaOverflow::*;
+        assert_eq!(calcular("i32", Circular, "0xFF + 0o10 + 0b1_0000"), Ok(String::from("279")));
+        // Padrão de bits que cabe na largura é reinterpretado
+        assert_eq!(calcular("i8", Verificar, "0xFF"), Ok(String::from("-1")));
+        assert_eq!(calcular("i8", Verificar, "-128"), Ok(String::from("-128")));
+        assert!(calcular("i8", Verificar, "128").is_err());
+        assert_eq!(calcular("i8", Circular, "300"), Ok(String::from("44")));
+        assert_eq!(calcular("u8", Saturar, "300"), Ok(String::from("255")));
+        assert_eq!(calcular("u8", Saturar, "-5"), Ok(String::from("0")));
+        assert_eq!(
+            calcular("u128", Verificar, "340282366920938463463374607431768211455"),
+            Ok(u128::MAX.to_string())
+        );
+        assert!(calcular("u8", Circular, "0xZZ").is_err());
+    }
+
+    #[test]
+    fn test_operadores_bit_a_bit() {
+        use PoliticaOverflow::*;
+        assert_eq!(calcular("u8", Circular, "0b1100 & 0b1010"), Ok(String::from("8")));
+        assert_eq!(calcular("u8", Circular, "0b1100 | 0b1010"), Ok(String::from("14")));
+        assert_eq!(calcular("u8", Circular, "0b1100 ^ 0b1010"), Ok(String::from("6")));
+        assert_eq!(calcular("u8", Circular, "~0"), Ok(String::from("255")));
+        assert_eq!(calcular("i8", Circular, "~0"), Ok(String::from("-1")));
+        assert_eq!(calcular("u32", Circular, "1 << 4 + 1"), Ok(String::from("32")));
+        assert_eq!(calcular("u32", Circular, "6 & 3 | 8"), Ok(String::from("10")));
+        assert_eq!(calcular("i8", Circular, "-128 >> 7"), Ok(String::from("-1")));
+    }
+
+    #[test]
+    fn test_deslocamento_pela_largura() {
+        use PoliticaOverflow::*;
+        assert_eq!(calcular("u8", Circular, "1 << 9"), Ok(String::from("2")));
+        assert!(calcular("u8", Verificar, "1 << 8").is_err());
+        assert_eq!(calcular("u8", Saturar, "1 << 8"), Ok(String::from("255")));
+        assert_eq!(calcular("u8", Verificar, "0 << 8"), Ok(String::from("0")));
+        assert_eq!(calcular("i8", Saturar, "-4 >> 100"), Ok(String::from("-1")));
+        assert!(calcular("i8", Circular, "1 << -1").is_err());
+    }
+
+    #[test]
+    fn test_deslocamento_que_perde_bits() {
+        use PoliticaOverflow::*;
+        assert_eq!(calcular("u8", Circular, "64 << 2"), Ok(String::from("0")));
+        assert!(calcular("u8", Verificar, "64 << 2").is_err());
+        assert_eq!(calcular("u8", Saturar, "64 << 2"), Ok(String::from("255")));
+        assert_eq!(calcular("u8", Verificar, "64 << 1"), Ok(String::from("128")));
+
+        // Com sinal, virar o bit de sinal também é perder bits
+        assert!(calcular("i8", Verificar, "64 << 1").is_err());
+        assert_eq!(calcular("i8", Saturar, "64 << 1"), Ok(String::from("127")));
+        assert_eq!(calcular("i8", Saturar, "-64 << 2"), Ok(String::from("-128")));
+        assert_eq!(calcular("i8", Verificar, "-1 << 7"), Ok(String::from("-128")));
+    }
+
+    #[test]
+    fn test_formatar_bases() {
+        let mut modo = modo("i8", PoliticaOverflow::Circular);
+        let menos_um = modo.avaliar("-1").unwrap();
+        assert_eq!(menos_um.formatar(Base::Hexadecimal), "0xFF");
+        assert_eq!(menos_um.formatar(Base::Octal), "0o377");
+        assert_eq!(menos_um.formatar(Base::Binario), "0b1111_1111");
+
+        modo.base = Base::Hexadecimal;
+        assert_eq!(modo.formatar(&menos_um), "0xFF  (-1 | 0o377 | 0b1111_1111)");
+    }
+
+    #[test]
+    fn test_ans_e_troca_de_tipo() {
+        let mut modo = modo("i8", PoliticaOverflow::Verificar);
+        modo.avaliar("-1").unwrap();
+        modo.definir_tipo(TipoInteiro::de_nome("u16").unwrap());
+        assert_eq!(modo.avaliar("ans").unwrap().decimal(), "65535");
+        assert_eq!(modo.avaliar("ans - 65534").unwrap().decimal(), "1");
+    }
+
+    #[test]
+    fn test_erros_de_sintaxe() {
+        let mut modo = ModoProgramador::novo();
+        assert_eq!(modo.avaliar("1 + $").unwrap_err().posicao, 4);
+        assert_eq!(modo.avaliar("(1 + 2").unwrap_err().posicao, 6);
+        assert_eq!(modo.avaliar("4 / 0").unwrap_err().mensagem, "Divisão por zero");
+        assert!(modo.avaliar("1 2").is_err());
+    }
+}
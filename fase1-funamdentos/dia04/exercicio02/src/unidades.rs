@@ -0,0 +1,499 @@
+use std::fmt;
+
+use temperatura::{DiferencaTemperatura, EscalaTemperatura, Temperatura};
+
+use crate::erro::{CalcError, verificar};
+use crate::{dividir, multiplicar, potencia, raiz_quadrada, resto, somar, subtrair};
+
+// ========================================
+// DIMENSÕES
+// ========================================
+
+/// Símbolos das grandezas de base, na ordem dos expoentes de `Dimensao`
+const BASES: [&str; 5] = ["kg", "m", "s", "K", "A"];
+
+/// Expoentes de massa, comprimento, tempo, temperatura e corrente.
+/// Ex.: força (N = kg·m/s^2) é `[1, 1, -2, 0, 0]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dimensao([i32; 5]);
+
+impl Dimensao {
+    pub const ADIMENSIONAL: Dimensao = Dimensao([0; 5]);
+    const COMPRIMENTO: Dimensao = Dimensao([0, 1, 0, 0, 0]);
+    const AREA: Dimensao = Dimensao([0, 2, 0, 0, 0]);
+    const VOLUME: Dimensao = Dimensao([0, 3, 0, 0, 0]);
+    const MASSA: Dimensao = Dimensao([1, 0, 0, 0, 0]);
+    const TEMPO: Dimensao = Dimensao([0, 0, 1, 0, 0]);
+    const TEMPERATURA: Dimensao = Dimensao([0, 0, 0, 1, 0]);
+    const CORRENTE: Dimensao = Dimensao([0, 0, 0, 0, 1]);
+    const FREQUENCIA: Dimensao = Dimensao([0, 0, -1, 0, 0]);
+    const VELOCIDADE: Dimensao = Dimensao([0, 1, -1, 0, 0]);
+    const FORCA: Dimensao = Dimensao([1, 1, -2, 0, 0]);
+    const PRESSAO: Dimensao = Dimensao([1, -1, -2, 0, 0]);
+    const ENERGIA: Dimensao = Dimensao([1, 2, -2, 0, 0]);
+    const POTENCIA: Dimensao = Dimensao([1, 2, -3, 0, 0]);
+
+    pub fn adimensional(&self) -> bool {
+        *self == Dimensao::ADIMENSIONAL
+    }
+
+    /// Soma (`sinal = 1`, multiplicação) ou subtrai (`sinal = -1`, divisão) os expoentes
+    fn combinar(self, outra: Dimensao, sinal: i32) -> Dimensao {
+        Dimensao(std::array::from_fn(|i| self.0[i] + sinal * outra.0[i]))
+    }
+
+    /// Eleva a dimensão a `expoente`; `None` se algum expoente ficaria fracionário (ex.: `m^0.5`)
+    fn elevar(self, expoente: f64) -> Option<Dimensao> {
+        let mut resultado = [0; 5];
+        for (novo, atual) in resultado.iter_mut().zip(self.0) {
+            let valor = atual as f64 * expoente;
+            if valor.fract() != 0.0 || valor.abs() > i32::MAX as f64 {
+                return None;
+            }
+            *novo = valor as i32;
+        }
+        Some(Dimensao(resultado))
+    }
+}
+
+impl fmt::Display for Dimensao {
+    /// Ex.: `kg·m/s^2`, `1/s`, `kg/(m·s^2)`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let termo = |base: &str, expoente: i32| match expoente {
+            1 => base.to_string(),
+            _ => format!("{}^{}", base, expoente),
+        };
+        let positivos: Vec<String> = BASES
+            .iter()
+            .zip(self.0)
+            .filter(|(_, e)| *e > 0)
+            .map(|(base, e)| termo(base, e))
+            .collect();
+        let negativos: Vec<String> = BASES
+            .iter()
+            .zip(self.0)
+            .filter(|(_, e)| *e < 0)
+            .map(|(base, e)| termo(base, -e))
+            .collect();
+
+        let numerador = match positivos.is_empty() {
+            true => String::from("1"),
+            false => positivos.join("·"),
+        };
+        match negativos.len() {
+            0 if positivos.is_empty() => write!(f, "adimensional"),
+            0 => write!(f, "{}", numerador),
+            1 => write!(f, "{}/{}", numerador, negativos[0]),
+            _ => write!(f, "{}/({})", numerador, negativos.join("·")),
+        }
+    }
+}
+
+// ========================================
+// CATÁLOGO DE UNIDADES
+// ========================================
+
+#[derive(Debug, PartialEq)]
+pub struct Unidade {
+    pub simbolo: &'static str,
+    pub nome: &'static str,
+    /// Quanto vale 1 desta unidade nas unidades de base do SI
+    fator: f64,
+    dimensao: Dimensao,
+    /// Temperaturas não são só um fator: a conversão fica com a biblioteca `Temperatura`
+    escala: Option<EscalaTemperatura>,
+}
+
+const fn unidade(simbolo: &'static str, nome: &'static str, fator: f64, dimensao: Dimensao) -> Unidade {
+    Unidade { simbolo, nome, fator, dimensao, escala: None }
+}
+
+const fn temperatura(simbolo: &'static str, nome: &'static str, escala: EscalaTemperatura) -> Unidade {
+    Unidade { simbolo, nome, fator: 1.0, dimensao: Dimensao::TEMPERATURA, escala: Some(escala) }
+}
+
+pub static UNIDADES: [Unidade; 36] = [
+    // Comprimento
+    unidade("mm", "milímetro", 1e-3, Dimensao::COMPRIMENTO),
+    unidade("cm", "centímetro", 1e-2, Dimensao::COMPRIMENTO),
+    unidade("m", "metro", 1.0, Dimensao::COMPRIMENTO),
+    unidade("km", "quilômetro", 1e3, Dimensao::COMPRIMENTO),
+    unidade("in", "polegada", 0.0254, Dimensao::COMPRIMENTO),
+    unidade("ft", "pé", 0.3048, Dimensao::COMPRIMENTO),
+    unidade("yd", "jarda", 0.9144, Dimensao::COMPRIMENTO),
+    unidade("mi", "milha", 1609.344, Dimensao::COMPRIMENTO),
+    // Área e volume
+    unidade("ha", "hectare", 1e4, Dimensao::AREA),
+    unidade("L", "litro", 1e-3, Dimensao::VOLUME),
+    unidade("mL", "mililitro", 1e-6, Dimensao::VOLUME),
+    // Massa
+    unidade("mg", "miligrama", 1e-6, Dimensao::MASSA),
+    unidade("g", "grama", 1e-3, Dimensao::MASSA),
+    unidade("kg", "quilograma", 1.0, Dimensao::MASSA),
+    unidade("t", "tonelada", 1e3, Dimensao::MASSA),
+    unidade("lb", "libra", 0.45359237, Dimensao::MASSA),
+    unidade("oz", "onça", 0.028349523125, Dimensao::MASSA),
+    // Tempo
+    unidade("ms", "milissegundo", 1e-3, Dimensao::TEMPO),
+    unidade("s", "segundo", 1.0, Dimensao::TEMPO),
+    unidade("min", "minuto", 60.0, Dimensao::TEMPO),
+    unidade("h", "hora", 3600.0, Dimensao::TEMPO),
+    unidade("dia", "dia", 86_400.0, Dimensao::TEMPO),
+    unidade("Hz", "hertz", 1.0, Dimensao::FREQUENCIA),
+    // Velocidade
+    unidade("kmh", "quilômetro por hora", 1e3 / 3600.0, Dimensao::VELOCIDADE),
+    unidade("mph", "milha por hora", 1609.344 / 3600.0, Dimensao::VELOCIDADE),
+    // Mecânica e eletricidade
+    unidade("N", "newton", 1.0, Dimensao::FORCA),
+    unidade("Pa", "pascal", 1.0, Dimensao::PRESSAO),
+    unidade("bar", "bar", 1e5, Dimensao::PRESSAO),
+    unidade("J", "joule", 1.0, Dimensao::ENERGIA),
+    unidade("kJ", "quilojoule", 1e3, Dimensao::ENERGIA),
+    unidade("cal", "caloria", 4.184, Dimensao::ENERGIA),
+    unidade("W", "watt", 1.0, Dimensao::POTENCIA),
+    unidade("A", "ampere", 1.0, Dimensao::CORRENTE),
+    // Temperatura
+    temperatura("K", "kelvin", EscalaTemperatura::Kelvin),
+    temperatura("°C", "grau Celsius", EscalaTemperatura::Celsius),
+    temperatura("°F", "grau Fahrenheit", EscalaTemperatura::Fahrenheit),
+];
+
+/// Procura uma unidade pelo símbolo (maiúsculas importam: `mm` ≠ `Mm`).
+/// `degC` e `degF` são atalhos para quem não tem `°` no teclado
+pub fn unidade_por_simbolo(simbolo: &str) -> Option<&'static Unidade> {
+    let simbolo = match simbolo {
+        "degC" => "°C",
+        "degF" => "°F",
+        outro => outro,
+    };
+    UNIDADES.iter().find(|u| u.simbolo == simbolo)
+}
+
+impl Unidade {
+    /// Multiplicar ou dividir preserva o sentido da unidade? Não para °C e °F,
+    /// cujo zero não é o zero absoluto
+    fn escalavel(&self) -> bool {
+        self.escala.is_none_or(|e| e == EscalaTemperatura::Kelvin)
+    }
+}
+
+pub fn exibir_catalogo() {
+    println!("\n📏 UNIDADES (ex.: 3 km + 200 m, 72 kg / (1.8 m)^2, 20 °C + 5 °C):");
+    for u in UNIDADES.iter() {
+        println!("  {:<4} {:<20} {}", u.simbolo, u.nome, u.dimensao);
+    }
+    println!("  degC e degF também valem para °C e °F");
+}
+
+// ========================================
+// QUANTIDADES
+// ========================================
+
+/// Valor com dimensão. Guardado nas unidades de base do SI (temperaturas em kelvin)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantidade {
+    pub valor: f64,
+    pub dimensao: Dimensao,
+    /// Unidade usada para exibir o resultado, herdada dos operandos
+    unidade: Option<&'static Unidade>,
+}
+
+impl Quantidade {
+    /// Número puro, sem unidade
+    pub fn numero(valor: f64) -> Self {
+        Quantidade { valor, dimensao: Dimensao::ADIMENSIONAL, unidade: None }
+    }
+
+    /// `valor` medido em `unidade`, ex.: `3 km` ou `20 °C`
+    pub fn de_unidade(valor: f64, unidade: &'static Unidade) -> Result<Self, CalcError> {
+        let si = match unidade.escala {
+            Some(escala) => Temperatura::new(valor, escala).para_kelvin(),
+            None => valor * unidade.fator,
+        };
+        Ok(Quantidade {
+            valor: verificar(si)?,
+            dimensao: unidade.dimensao,
+            unidade: Some(unidade),
+        })
+    }
+
+    /// `valor` em `unidade^expoente`, ex.: `3 m^2`; não faz sentido para °C e °F
+    pub fn de_unidade_elevada(valor: f64, unidade: &'static Unidade, expoente: i32) -> Result<Self, CalcError> {
+        if expoente == 1 {
+            return Quantidade::de_unidade(valor, unidade);
+        }
+        if !unidade.escalavel() {
+            return Err(CalcError::UnidadesIncompativeis);
+        }
+        Ok(Quantidade {
+            valor: multiplicar(valor, potencia(unidade.fator, expoente as f64)?)?,
+            dimensao: unidade.dimensao.elevar(expoente as f64).ok_or(CalcError::Overflow)?,
+            unidade: None,
+        })
+    }
+
+    pub fn adimensional(&self) -> bool {
+        self.dimensao.adimensional()
+    }
+
+    /// Valor convertido para `unidade` (que deve ter a mesma dimensão)
+    fn valor_em(&self, unidade: &Unidade) -> f64 {
+        match unidade.escala {
+            // Sem verificar o zero absoluto: diferenças de temperatura podem ser negativas
+            Some(escala) => arredondar(escala.de_kelvin(self.valor)),
+            None => arredondar(self.valor / unidade.fator),
+        }
+    }
+
+    /// Valor na unidade em que a quantidade é exibida
+    pub fn valor_exibido(&self) -> f64 {
+        match self.unidade {
+            Some(unidade) if !self.adimensional() => self.valor_em(unidade),
+            _ => self.valor,
+        }
+    }
+
+    /// Texto da unidade exibida (`km`, `kg/m^2`); `None` para números puros
+    pub fn unidade_exibida(&self) -> Option<String> {
+        match self.unidade {
+            _ if self.adimensional() => None,
+            Some(unidade) => Some(unidade.simbolo.to_string()),
+            None => Some(self.dimensao.to_string()),
+        }
+    }
+
+    /// Unidade para exibir quando a dimensão não muda (`+`, `-`, `%`, escala por número)
+    fn unidade_preservada(&self) -> Option<&'static Unidade> {
+        self.unidade.filter(|u| u.escalavel())
+    }
+
+    /// Soma ou subtração. Com temperaturas, a da esquerda é um ponto e a da direita
+    /// uma diferença (`DiferencaTemperatura`), convertida só pelo tamanho do grau:
+    /// `20 °C + 9 °F` dá 25 °C, e `0 K + 0 °C` continua 0 K
+    fn combinar(
+        &self,
+        outra: &Quantidade,
+        operacao: fn(f64, f64) -> Result<f64, CalcError>,
+    ) -> Result<Quantidade, CalcError> {
+        if self.dimensao != outra.dimensao {
+            return Err(CalcError::UnidadesIncompativeis);
+        }
+
+        let valor_outra = match outra.unidade {
+            Some(unidade @ Unidade { escala: Some(escala), .. }) => {
+                DiferencaTemperatura::new(outra.valor_em(unidade), *escala).em_kelvin()
+            }
+            _ => outra.valor,
+        };
+        Ok(Quantidade {
+            valor: operacao(self.valor, valor_outra)?,
+            dimensao: self.dimensao,
+            unidade: self.unidade.or(outra.unidade),
+        })
+    }
+
+    pub fn somar(&self, outra: &Quantidade) -> Result<Quantidade, CalcError> {
+        self.combinar(outra, somar)
+    }
+
+    pub fn subtrair(&self, outra: &Quantidade) -> Result<Quantidade, CalcError> {
+        self.combinar(outra, subtrair)
+    }
+
+    pub fn multiplicar(&self, outra: &Quantidade) -> Result<Quantidade, CalcError> {
+        let unidade = match (self.adimensional(), outra.adimensional()) {
+            (false, true) => self.unidade_preservada(),
+            (true, false) => outra.unidade_preservada(),
+            _ => None,
+        };
+        Ok(Quantidade {
+            valor: multiplicar(self.valor, outra.valor)?,
+            dimensao: self.dimensao.combinar(outra.dimensao, 1),
+            unidade,
+        })
+    }
+
+    pub fn dividir(&self, outra: &Quantidade) -> Result<Quantidade, CalcError> {
+        let unidade = match outra.adimensional() {
+            true => self.unidade_preservada(),
+            false => None,
+        };
+        Ok(Quantidade {
+            valor: dividir(self.valor, outra.valor)?,
+            dimensao: self.dimensao.combinar(outra.dimensao, -1),
+            unidade,
+        })
+    }
+
+    pub fn resto(&self, outra: &Quantidade) -> Result<Quantidade, CalcError> {
+        if self.dimensao != outra.dimensao {
+            return Err(CalcError::UnidadesIncompativeis);
+        }
+        Ok(Quantidade {
+            valor: resto(self.valor, outra.valor)?,
+            dimensao: self.dimensao,
+            unidade: self.unidade_preservada(),
+        })
+    }
+
+    /// O expoente não pode ter unidade, e só vale se os expoentes da dimensão
+    /// continuarem inteiros: `(2 m)^2` sim, `(2 m)^0.5` não
+    pub fn potencia(&self, expoente: &Quantidade) -> Result<Quantidade, CalcError> {
+        if !expoente.adimensional() {
+            return Err(CalcError::UnidadesIncompativeis);
+        }
+        let dimensao = self
+            .dimensao
+            .elevar(expoente.valor)
+            .ok_or(CalcError::UnidadesIncompativeis)?;
+        Ok(Quantidade {
+            valor: potencia(self.valor, expoente.valor)?,
+            dimensao,
+            unidade: None,
+        })
+    }
+
+    pub fn raiz_quadrada(&self) -> Result<Quantidade, CalcError> {
+        let dimensao = self.dimensao.elevar(0.5).ok_or(CalcError::UnidadesIncompativeis)?;
+        Ok(Quantidade {
+            valor: raiz_quadrada(self.valor)?,
+            dimensao,
+            unidade: None,
+        })
+    }
+
+    /// Aplica `f` ao valor exibido; para °C e °F isso faz `-(20 °C)` dar -20 °C
+    fn mapear(&self, f: fn(f64) -> f64) -> Result<Quantidade, CalcError> {
+        match self.unidade {
+            Some(unidade) if unidade.escala.is_some() => Quantidade::de_unidade(f(self.valor_em(unidade)), unidade),
+            _ => Ok(Quantidade { valor: f(self.valor), ..*self }),
+        }
+    }
+
+    pub fn negar(&self) -> Result<Quantidade, CalcError> {
+        self.mapear(|v| -v)
+    }
+
+    pub fn abs(&self) -> Result<Quantidade, CalcError> {
+        self.mapear(f64::abs)
+    }
+}
+
+impl From<f64> for Quantidade {
+    fn from(valor: f64) -> Self {
+        Quantidade::numero(valor)
+    }
+}
+
+/// Números puros aparecem como antes (`14`); com unidade, `3.2 km` ou `22.2 kg/m^2`
+impl fmt::Display for Quantidade {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.unidade_exibida() {
+            Some(unidade) => write!(f, "{} {}", self.valor_exibido(), unidade),
+            None => write!(f, "{}", self.valor),
+        }
+    }
+}
+
+/// Corta o ruído de ponto flutuante das conversões (12 algarismos significativos),
+/// para `20 °C` voltar como 20 e não 19.999999999999986
+pub fn arredondar(valor: f64) -> f64 {
+    if valor == 0.0 || !valor.is_finite() {
+        return valor;
+    }
+    let casas = 11 - valor.abs().log10().floor() as i32;
+    let escala = 10f64.powi(casas);
+    if !escala.is_finite() || escala == 0.0 {
+        return valor;
+    }
+    (valor * escala).round() / escala
+}
+
+// ========================================
+// TESTES
+// ========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(valor: f64, simbolo: &str) -> Quantidade {
+        Quantidade::de_unidade(valor, unidade_por_simbolo(simbolo).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_dimensao_display() {
+        assert_eq!(Dimensao::FORCA.to_string(), "kg·m/s^2");
+        assert_eq!(Dimensao::FREQUENCIA.to_string(), "1/s");
+        assert_eq!(Dimensao::PRESSAO.to_string(), "kg/(m·s^2)");
+        assert_eq!(Dimensao::ADIMENSIONAL.to_string(), "adimensional");
+    }
+
+    #[test]
+    fn test_soma_converte_para_unidade_da_esquerda() {
+        assert_eq!(q(3.0, "km").somar(&q(200.0, "m")).unwrap().to_string(), "3.2 km");
+        assert_eq!(q(1.0, "ft").somar(&q(12.0, "in")).unwrap().to_string(), "2 ft");
+        assert_eq!(q(1.0, "h").subtrair(&q(30.0, "min")).unwrap().to_string(), "0.5 h");
+    }
+
+    #[test]
+    fn test_unidades_incompativeis() {
+        assert_eq!(q(3.0, "m").somar(&q(2.0, "s")), Err(CalcError::UnidadesIncompativeis));
+        assert_eq!(q(3.0, "m").somar(&Quantidade::numero(1.0)), Err(CalcError::UnidadesIncompativeis));
+        assert_eq!(q(3.0, "m").resto(&q(2.0, "kg")), Err(CalcError::UnidadesIncompativeis));
+        assert_eq!(q(2.0, "m").potencia(&q(2.0, "s")), Err(CalcError::UnidadesIncompativeis));
+        assert_eq!(q(2.0, "m").raiz_quadrada(), Err(CalcError::UnidadesIncompativeis));
+    }
+
+    #[test]
+    fn test_multiplicacao_e_divisao_combinam_dimensoes() {
+        let imc = q(72.0, "kg").dividir(&q(1.8, "m").potencia(&Quantidade::numero(2.0)).unwrap()).unwrap();
+        assert_eq!(imc.dimensao, Dimensao([1, -2, 0, 0, 0]));
+        assert!((imc.valor - 22.2222).abs() < 1e-4);
+
+        let velocidade = q(100.0, "km").dividir(&q(1.0, "h")).unwrap();
+        assert_eq!(velocidade.dimensao, Dimensao::VELOCIDADE);
+        assert!((velocidade.valor_em(unidade_por_simbolo("kmh").unwrap()) - 100.0).abs() < 1e-9);
+
+        // Número puro mantém a unidade; mesma dimensão se cancela
+        assert_eq!(q(3.0, "km").multiplicar(&Quantidade::numero(2.0)).unwrap().to_string(), "6 km");
+        assert_eq!(q(1.0, "km").dividir(&q(1.0, "m")).unwrap(), Quantidade::numero(1000.0));
+
+        let area = q(4.0, "m").multiplicar(&q(4.0, "m")).unwrap();
+        assert_eq!(area.raiz_quadrada().unwrap().to_string(), "4 m");
+    }
+
+    #[test]
+    fn test_temperaturas_usam_biblioteca_temperatura() {
+        assert_eq!(q(20.0, "°C").to_string(), "20 °C");
+        assert!((q(20.0, "°C").valor - 293.15).abs() < 1e-9);
+        assert_eq!(q(20.0, "°C").somar(&q(5.0, "°C")).unwrap().to_string(), "25 °C");
+        assert_eq!(q(20.0, "°C").subtrair(&q(5.0, "°C")).unwrap().to_string(), "15 °C");
+        assert_eq!(q(20.0, "degC").negar().unwrap().to_string(), "-20 °C");
+        assert_eq!(q(300.0, "K").multiplicar(&Quantidade::numero(2.0)).unwrap().to_string(), "600 K");
+
+        // °C não é escalável: o produto sai em kelvin
+        assert_eq!(q(10.0, "°C").multiplicar(&Quantidade::numero(2.0)).unwrap().to_string(), "566.3 K");
+        assert!(Quantidade::de_unidade_elevada(1.0, unidade_por_simbolo("°C").unwrap(), 2).is_err());
+    }
+
+    #[test]
+    fn test_temperatura_da_direita_e_diferenca() {
+        assert_eq!(q(20.0, "°C").somar(&q(9.0, "°F")).unwrap().to_string(), "25 °C");
+        assert_eq!(q(20.0, "°C").subtrair(&q(9.0, "°F")).unwrap().to_string(), "15 °C");
+        let soma = q(100.0, "°C").somar(&q(32.0, "°F")).unwrap();
+        assert_eq!(soma.to_string(), format!("{} °C", arredondar(100.0 + 32.0 * 5.0 / 9.0)));
+        assert_eq!(q(50.0, "°F").somar(&q(10.0, "K")).unwrap().to_string(), "68 °F");
+
+        // A escala da direita nunca entra pelo zero: trocar o zero de lugar não cria 273.15
+        assert_eq!(q(0.0, "°C").somar(&q(0.0, "K")).unwrap().to_string(), "0 °C");
+        assert_eq!(q(0.0, "K").somar(&q(0.0, "°C")).unwrap().to_string(), "0 K");
+        assert_eq!(q(0.0, "K").somar(&q(1.0, "°C")).unwrap().valor, 1.0);
+    }
+
+    #[test]
+    fn test_numero_puro_exibe_como_antes() {
+        assert_eq!(Quantidade::numero(14.0).to_string(), "14");
+        assert_eq!(Quantidade::numero(0.5).unidade_exibida(), None);
+    }
+}
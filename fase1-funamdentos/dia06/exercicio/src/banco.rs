@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use crate::conta::ContaBancaria;
+use crate::dinheiro::{Dinheiro, ErroDinheiro, Moeda};
+use crate::erro::ErroBancario;
+use crate::transacao::Transferencia;
+
+// Número dado à primeira conta aberta
+pub const PRIMEIRO_NUMERO: u32 = 1001;
+
+// Dono de todas as contas, indexadas pelo número.
+// Operações entre contas passam por aqui, que consegue emprestar as duas
+pub struct Banco {
+    contas: BTreeMap<u32, ContaBancaria>,
+    // `None` depois de entregar `u32::MAX`: não há mais números
+    proximo_numero: Option<u32>,
+}
+
+impl Banco {
+    pub fn new() -> Self {
+        Banco::com_primeiro_numero(PRIMEIRO_NUMERO)
+    }
+
+    // Banco cuja numeração começa em outro ponto
+    pub fn com_primeiro_numero(numero: u32) -> Self {
+        Banco { contas: BTreeMap::new(), proximo_numero: Some(numero) }
+    }
+
+    // Abre uma conta com o próximo número livre e devolve esse número.
+    // Se a conta for recusada, o número não é gasto; números nunca voltam
+    // a ser usados, então depois de `u32::MAX` nenhuma conta abre mais
+    pub fn abrir_conta(&mut self, titular: String, saldo_inicial: Dinheiro) -> Result<u32, ErroBancario> {
+        let numero = self.proximo_numero.ok_or(ErroBancario::NumerosEsgotados)?;
+        let conta = ContaBancaria::new(titular, numero, saldo_inicial)?;
+        self.proximo_numero = numero.checked_add(1);
+        self.contas.insert(numero, conta);
+        Ok(numero)
+    }
+
+    pub fn conta(&self, numero: u32) -> Result<&ContaBancaria, ErroBancario> {
+        self.contas.get(&numero).ok_or(ErroBancario::ContaDesconhecida(numero))
+    }
+
+    pub fn conta_mut(&mut self, numero: u32) -> Result<&mut ContaBancaria, ErroBancario> {
+        self.contas.get_mut(&numero).ok_or(ErroBancario::ContaDesconhecida(numero))
+    }
+
+    pub fn depositar(&mut self, numero: u32, valor: Dinheiro) -> Result<(), ErroBancario> {
+        self.conta_mut(numero)?.depositar(valor)
+    }
+
+    pub fn sacar(&mut self, numero: u32, valor: Dinheiro) -> Result<(), ErroBancario> {
+        self.conta_mut(numero)?.sacar(valor)
+    }
+
+    // Tudo ou nada (ver `Transferencia`)
+    pub fn transferir(&mut self, origem: u32, destino: u32, valor: Dinheiro) -> Result<(), ErroBancario> {
+        if origem == destino {
+            return Err(ErroBancario::MesmaConta(origem));
+        }
+        self.conta(origem)?;
+        self.conta(destino)?;
+
+        // Empresta as duas contas sem tirá-las do mapa: um pânico no meio
+        // não pode fazer uma conta sumir. A faixa entre os dois números
+        // entrega a menor pela frente e a maior por trás, sem varrer o resto
+        let (menor, maior) = (origem.min(destino), origem.max(destino));
+        let mut faixa = self.contas.range_mut(menor..=maior);
+        let conta_menor = faixa
+            .next()
+            .filter(|(numero, _)| **numero == menor)
+            .map(|(_, conta)| conta)
+            .ok_or(ErroBancario::ContaDesconhecida(menor))?;
+        let conta_maior = faixa
+            .next_back()
+            .filter(|(numero, _)| **numero == maior)
+            .map(|(_, conta)| conta)
+            .ok_or(ErroBancario::ContaDesconhecida(maior))?;
+        let (conta_origem, conta_destino) =
+            if menor == origem { (conta_menor, conta_maior) } else { (conta_maior, conta_menor) };
+        Transferencia::preparar(conta_origem, conta_destino, valor)?.confirmar();
+        Ok(())
+    }
+
+    pub fn encerrar_conta(&mut self, numero: u32) -> Result<(), ErroBancario> {
+        self.conta_mut(numero)?.desativar()
+    }
+
+    // Contas cujo titular contém `nome`, sem diferenciar maiúsculas
+    pub fn buscar_por_titular(&self, nome: &str) -> Vec<&ContaBancaria> {
+        let nome = nome.to_lowercase();
+        self.contas.values().filter(|conta| conta.titular().to_lowercase().contains(&nome)).collect()
+    }
+
+    // Contas que passam no filtro, em ordem de número
+    pub fn listar(&self, filtro: &FiltroContas) -> Vec<&ContaBancaria> {
+        self.contas.values().filter(|conta| filtro.aceita(conta)).collect()
+    }
+
+    // Soma dos saldos de todas as contas na moeda (as outras ficam de fora)
+    pub fn saldo_total(&self, moeda: Moeda) -> Result<Dinheiro, ErroDinheiro> {
+        self.contas
+            .values()
+            .map(ContaBancaria::obter_saldo)
+            .filter(|saldo| saldo.moeda() == moeda)
+            .try_fold(Dinheiro::zero(moeda), Dinheiro::somar)
+    }
+
+    pub fn quantidade_de_contas(&self) -> usize {
+        self.contas.len()
+    }
+}
+
+impl Default for Banco {
+    fn default() -> Self {
+        Banco::new()
+    }
+}
+
+// Critérios de `Banco::listar`; sem nenhum, lista todas as contas
+#[derive(Debug, Clone, Default)]
+pub struct FiltroContas {
+    ativa: Option<bool>,
+    saldo_minimo: Option<Dinheiro>,
+    saldo_maximo: Option<Dinheiro>,
+}
+
+impl FiltroContas {
+    pub fn new() -> Self {
+        FiltroContas::default()
+    }
+
+    pub fn so_ativas(mut self) -> Self {
+        self.ativa = Some(true);
+        self
+    }
+
+    pub fn so_inativas(mut self) -> Self {
+        self.ativa = Some(false);
+        self
+    }
+
+    // Saldo de `minimo` a `maximo` (inclusive). Contas em outra moeda ficam de fora
+    pub fn com_saldo_entre(mut self, minimo: Dinheiro, maximo: Dinheiro) -> Self {
+        self.saldo_minimo = Some(minimo);
+        self.saldo_maximo = Some(maximo);
+        self
+    }
+
+    pub fn com_saldo_minimo(mut self, minimo: Dinheiro) -> Self {
+        self.saldo_minimo = Some(minimo);
+        self
+    }
+
+    pub fn com_saldo_maximo(mut self, maximo: Dinheiro) -> Self {
+        self.saldo_maximo = Some(maximo);
+        self
+    }
+
+    fn aceita(&self, conta: &ContaBancaria) -> bool {
+        let saldo = conta.obter_saldo();
+        self.ativa.is_none_or(|ativa| conta.esta_ativa() == ativa)
+            && self.saldo_minimo.is_none_or(|minimo| saldo >= minimo)
+            && self.saldo_maximo.is_none_or(|maximo| saldo <= maximo)
+    }
+}
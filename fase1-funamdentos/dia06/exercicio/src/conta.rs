@@ -0,0 +1,146 @@
+use crate::diario::{Data, Diario, Extrato, Lancamento, TipoOperacao, agora};
+use crate::dinheiro::{Dinheiro, ErroDinheiro};
+use crate::erro::ErroBancario;
+use crate::transacao::Transferencia;
+
+// Struct representando uma conta bancária.
+// O saldo não é guardado: vem da soma dos lançamentos do diário
+pub struct ContaBancaria {
+    titular: String,
+    numero: u32,
+    ativa: bool,
+    diario: Diario,
+}
+
+impl ContaBancaria {
+    // Construtor (função associada); o saldo inicial vira o lançamento de
+    // abertura e define a moeda da conta. Pode ser zero, mas não negativo
+    pub fn new(titular: String, numero: u32, saldo_inicial: Dinheiro) -> Result<ContaBancaria, ErroBancario> {
+        if saldo_inicial.negativo() {
+            return Err(ErroBancario::SaldoInicialNegativo(saldo_inicial));
+        }
+
+        let mut diario = Diario::new(saldo_inicial.moeda());
+        diario.registrar(TipoOperacao::Abertura, saldo_inicial, agora()).expect("diário vazio aceita qualquer saldo");
+        Ok(ContaBancaria { titular, numero, ativa: true, diario })
+    }
+
+    // Método para depositar (&mut self - acrescenta ao diário)
+    pub fn depositar(&mut self, valor: Dinheiro) -> Result<(), ErroBancario> {
+        self.creditar(TipoOperacao::Deposito, valor)
+    }
+
+    // Método para sacar (&mut self - acrescenta ao diário)
+    pub fn sacar(&mut self, valor: Dinheiro) -> Result<(), ErroBancario> {
+        self.debitar(TipoOperacao::Saque, valor)
+    }
+
+    // Método para transferir (&mut self e &mut outra): tudo ou nada.
+    // As duas contas são validadas antes de qualquer lançamento
+    pub fn transferir(&mut self, destino: &mut ContaBancaria, valor: Dinheiro) -> Result<(), ErroBancario> {
+        Transferencia::preparar(self, destino, valor)?.confirmar();
+        Ok(())
+    }
+
+    // Método para exibir dados (&self - só leitura)
+    pub fn exibir_dados(&self) {
+        println!("\n========== DADOS DA CONTA ==========");
+        println!("Titular: {}", self.titular);
+        println!("Número: {}", self.numero);
+        println!("Saldo: {}", self.obter_saldo());
+        println!("Status: {}", if self.esta_ativa() { "Ativa" } else { "Inativa" });
+        println!("====================================\n");
+    }
+
+    // Método para obter saldo (&self - só leitura)
+    pub fn obter_saldo(&self) -> Dinheiro {
+        self.diario.saldo()
+    }
+
+    // Método para desativar conta (&mut self)
+    pub fn desativar(&mut self) -> Result<(), ErroBancario> {
+        let saldo = self.obter_saldo();
+        if !saldo.zerado() {
+            return Err(ErroBancario::SaldoNaoZerado(saldo));
+        }
+
+        self.ativa = false;
+        Ok(())
+    }
+
+    // Método para verificar se está ativa (&self)
+    pub fn esta_ativa(&self) -> bool {
+        self.ativa
+    }
+
+    pub fn titular(&self) -> &str {
+        &self.titular
+    }
+
+    pub fn numero(&self) -> u32 {
+        self.numero
+    }
+
+    // Todas as operações, na ordem em que aconteceram
+    pub fn diario(&self) -> &Diario {
+        &self.diario
+    }
+
+    // Extrato entre duas datas (inclusive)
+    pub fn extrato(&self, inicio: Data, fim: Data) -> Extrato {
+        self.diario.extrato(inicio, fim)
+    }
+
+    // Regras comuns a toda operação
+    fn validar(&self, valor: Dinheiro) -> Result<(), ErroBancario> {
+        if !self.ativa {
+            return Err(ErroBancario::ContaInativa { numero: self.numero });
+        }
+
+        if valor.moeda() != self.diario.moeda() {
+            let erro = ErroDinheiro::MoedasDiferentes { esperada: self.diario.moeda(), recebida: valor.moeda() };
+            return Err(erro.into());
+        }
+
+        if !valor.positivo() {
+            return Err(ErroBancario::ValorNaoPositivo(valor));
+        }
+
+        Ok(())
+    }
+
+    fn creditar(&mut self, tipo: TipoOperacao, valor: Dinheiro) -> Result<(), ErroBancario> {
+        let lancamento = self.preparar_credito(tipo, valor)?;
+        self.confirmar(lancamento);
+        Ok(())
+    }
+
+    fn debitar(&mut self, tipo: TipoOperacao, valor: Dinheiro) -> Result<(), ErroBancario> {
+        let lancamento = self.preparar_debito(tipo, valor)?;
+        self.confirmar(lancamento);
+        Ok(())
+    }
+
+    // Valida um crédito e monta o lançamento, sem mudar o saldo
+    pub(crate) fn preparar_credito(&mut self, tipo: TipoOperacao, valor: Dinheiro) -> Result<Lancamento, ErroBancario> {
+        self.validar(valor)?;
+        Ok(self.diario.preparar(tipo, valor, agora())?)
+    }
+
+    // Valida um débito (inclusive o saldo) e monta o lançamento, sem mudar o saldo
+    pub(crate) fn preparar_debito(&mut self, tipo: TipoOperacao, valor: Dinheiro) -> Result<Lancamento, ErroBancario> {
+        self.validar(valor)?;
+
+        let disponivel = self.obter_saldo();
+        if valor > disponivel {
+            return Err(ErroBancario::SaldoInsuficiente { disponivel, solicitado: valor });
+        }
+
+        Ok(self.diario.preparar(tipo, valor, agora())?)
+    }
+
+    // Aplica um lançamento preparado; não falha
+    pub(crate) fn confirmar(&mut self, lancamento: Lancamento) {
+        self.diario.confirmar(lancamento);
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::dinheiro::{Dinheiro, ErroDinheiro, Moeda};
+
+// ========================================
+// LANÇAMENTOS
+// ========================================
+
+// O que aconteceu em cada lançamento do diário
+#[derive(Debug, Clone, PartialEq)]
+pub enum TipoOperacao {
+    Abertura,
+    Deposito,
+    Saque,
+    TransferenciaEnviada { destino: String },
+    TransferenciaRecebida { origem: String },
+    Pagamento { descricao: String },
+}
+
+impl TipoOperacao {
+    // Créditos somam ao saldo; débitos subtraem
+    pub fn credito(&self) -> bool {
+        matches!(self, TipoOperacao::Abertura | TipoOperacao::Deposito | TipoOperacao::TransferenciaRecebida { .. })
+    }
+}
+
+impl fmt::Display for TipoOperacao {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TipoOperacao::Abertura => write!(f, "Abertura"),
+            TipoOperacao::Deposito => write!(f, "Depósito"),
+            TipoOperacao::Saque => write!(f, "Saque"),
+            TipoOperacao::TransferenciaEnviada { destino } => write!(f, "Transferência para {}", destino),
+            TipoOperacao::TransferenciaRecebida { origem } => write!(f, "Transferência de {}", origem),
+            TipoOperacao::Pagamento { descricao } => write!(f, "Pagamento: {}", descricao),
+        }
+    }
+}
+
+// Uma linha do diário; depois de registrada não muda mais
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lancamento {
+    pub id: u64,
+    // Segundos desde 1970-01-01 00:00:00 UTC
+    pub timestamp: u64,
+    pub tipo: TipoOperacao,
+    // Sempre positivo; o sinal vem do tipo
+    pub valor: Dinheiro,
+    // Saldo da conta logo depois deste lançamento
+    pub saldo: Dinheiro,
+}
+
+impl Lancamento {
+    // Centavos com o sinal do tipo
+    pub fn centavos_com_sinal(&self) -> i64 {
+        if self.tipo.credito() { self.valor.centavos() } else { -self.valor.centavos() }
+    }
+}
+
+// ========================================
+// DIÁRIO
+// ========================================
+
+// Diário só de acréscimo: lançamentos não são alterados nem removidos,
+// e o saldo é sempre a soma deles
+#[derive(Debug, Clone)]
+pub struct Diario {
+    moeda: Moeda,
+    lancamentos: Vec<Lancamento>,
+}
+
+impl Diario {
+    pub fn new(moeda: Moeda) -> Self {
+        Diario { moeda, lancamentos: Vec::new() }
+    }
+
+    pub fn moeda(&self) -> Moeda {
+        self.moeda
+    }
+
+    // Acrescenta um lançamento; as regras (saldo, conta ativa) ficam com a conta.
+    // Recusa outra moeda e saldos que não caberiam em centavos i64
+    pub fn registrar(
+        &mut self,
+        tipo: TipoOperacao,
+        valor: Dinheiro,
+        timestamp: u64,
+    ) -> Result<&Lancamento, ErroDinheiro> {
+        let lancamento = self.preparar(tipo, valor, timestamp)?;
+        self.confirmar(lancamento);
+        Ok(&self.lancamentos[self.lancamentos.len() - 1])
+    }
+
+    // Fase 1: monta o próximo lançamento sem mudar o diário, e reserva
+    // espaço para que `confirmar` não precise alocar
+    pub(crate) fn preparar(
+        &mut self,
+        tipo: TipoOperacao,
+        valor: Dinheiro,
+        timestamp: u64,
+    ) -> Result<Lancamento, ErroDinheiro> {
+        let saldo = if tipo.credito() { self.saldo().somar(valor)? } else { self.saldo().subtrair(valor)? };
+        let id = self.lancamentos.len() as u64 + 1;
+        self.lancamentos.reserve(1);
+        Ok(Lancamento { id, timestamp, tipo, valor, saldo })
+    }
+
+    // Fase 2: acrescenta um lançamento de `preparar`; não falha
+    pub(crate) fn confirmar(&mut self, lancamento: Lancamento) {
+        debug_assert_eq!(lancamento.id, self.lancamentos.len() as u64 + 1, "lançamento preparado ficou velho");
+        self.lancamentos.push(lancamento);
+    }
+
+    pub fn saldo(&self) -> Dinheiro {
+        self.somar(self.lancamentos.iter())
+    }
+
+    pub fn lancamentos(&self) -> &[Lancamento] {
+        &self.lancamentos
+    }
+
+    // Lançamentos de `inicio` a `fim` (inclusive, datas em UTC)
+    pub fn extrato(&self, inicio: Data, fim: Data) -> Extrato {
+        let data = |lancamento: &Lancamento| Data::de_timestamp(lancamento.timestamp);
+        let lancamentos = self.lancamentos.iter().filter(|l| inicio <= data(l) && data(l) <= fim).cloned().collect();
+        let saldo_anterior = self.somar(self.lancamentos.iter().filter(|l| data(l) < inicio));
+        let saldo_final = self.somar(self.lancamentos.iter().filter(|l| data(l) <= fim));
+
+        Extrato { inicio, fim, saldo_anterior, lancamentos, saldo_final }
+    }
+
+    // Não estoura: `registrar` só aceita lançamentos cujo saldo cabe em i64
+    fn somar<'a>(&self, lancamentos: impl Iterator<Item = &'a Lancamento>) -> Dinheiro {
+        Dinheiro::new(lancamentos.map(Lancamento::centavos_com_sinal).sum(), self.moeda)
+    }
+}
+
+// Recorte do diário num período
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extrato {
+    pub inicio: Data,
+    pub fim: Data,
+    pub saldo_anterior: Dinheiro,
+    pub lancamentos: Vec<Lancamento>,
+    pub saldo_final: Dinheiro,
+}
+
+impl fmt::Display for Extrato {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "\n========== EXTRATO {} a {} ==========", self.inicio, self.fim)?;
+        writeln!(f, "Saldo anterior: {}", self.saldo_anterior)?;
+        for lancamento in &self.lancamentos {
+            let sinal = if lancamento.tipo.credito() { '+' } else { '-' };
+            writeln!(
+                f,
+                "#{:<4} {} {}  {:<30} {:>17}  {:>16}",
+                lancamento.id,
+                Data::de_timestamp(lancamento.timestamp),
+                formatar_hora(lancamento.timestamp),
+                lancamento.tipo.to_string(),
+                format!("{}{}", sinal, lancamento.valor),
+                lancamento.saldo.to_string()
+            )?;
+        }
+        writeln!(f, "Saldo final: {}", self.saldo_final)?;
+        write!(f, "====================================")
+    }
+}
+
+// ========================================
+// DATAS
+// ========================================
+
+// Data do calendário (UTC), para os períodos do extrato
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Data {
+    pub ano: i64,
+    pub mes: u32,
+    pub dia: u32,
+}
+
+impl Data {
+    pub fn new(ano: i64, mes: u32, dia: u32) -> Option<Data> {
+        let bissexto = (ano % 4 == 0 && ano % 100 != 0) || ano % 400 == 0;
+        let dias_no_mes = match mes {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if bissexto => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if dia == 0 || dia > dias_no_mes {
+            return None;
+        }
+        Some(Data { ano, mes, dia })
+    }
+
+    pub fn hoje() -> Data {
+        Data::de_timestamp(agora())
+    }
+
+    // Algoritmo "civil_from_days" de Howard Hinnant
+    pub fn de_timestamp(segundos: u64) -> Data {
+        let z = (segundos / 86_400) as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let dia = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let mes = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let ano = yoe + era * 400 + if mes <= 2 { 1 } else { 0 };
+        Data { ano, mes, dia }
+    }
+
+    // Primeiro segundo do dia; inverso de `de_timestamp` ("days_from_civil")
+    pub fn timestamp(&self) -> u64 {
+        let ano = if self.mes <= 2 { self.ano - 1 } else { self.ano };
+        let era = ano.div_euclid(400);
+        let yoe = ano.rem_euclid(400);
+        let mes = self.mes as i64;
+        let doy = (153 * (if mes > 2 { mes - 3 } else { mes + 9 }) + 2) / 5 + self.dia as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let dias = era * 146_097 + doe - 719_468;
+        (dias.max(0) as u64) * 86_400
+    }
+}
+
+// Lê `AAAA-MM-DD`
+impl FromStr for Data {
+    type Err = String;
+
+    fn from_str(texto: &str) -> Result<Self, Self::Err> {
+        let invalida = || format!("Data inválida '{}' (use AAAA-MM-DD)", texto);
+        let partes: Vec<&str> = texto.trim().split('-').collect();
+        let [ano, mes, dia] = partes[..] else {
+            return Err(invalida());
+        };
+        let ano = ano.parse().map_err(|_| invalida())?;
+        let mes = mes.parse().map_err(|_| invalida())?;
+        let dia = dia.parse().map_err(|_| invalida())?;
+        Data::new(ano, mes, dia).ok_or_else(invalida)
+    }
+}
+
+impl fmt::Display for Data {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}/{:02}/{:04}", self.dia, self.mes, self.ano)
+    }
+}
+
+// Segundos desde 1970-01-01 00:00:00 UTC
+pub fn agora() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+fn formatar_hora(segundos: u64) -> String {
+    let resto = segundos % 86_400;
+    format!("{:02}:{:02}", resto / 3_600, resto % 3_600 / 60)
+}
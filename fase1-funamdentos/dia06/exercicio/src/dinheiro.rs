@@ -0,0 +1,263 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// ========================================
+// MOEDA
+// ========================================
+
+// Moedas aceitas, pelo código ISO 4217
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Moeda {
+    Brl,
+    Usd,
+    Eur,
+}
+
+impl Moeda {
+    pub const TODAS: [Moeda; 3] = [Moeda::Brl, Moeda::Usd, Moeda::Eur];
+
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            Moeda::Brl => "BRL",
+            Moeda::Usd => "USD",
+            Moeda::Eur => "EUR",
+        }
+    }
+
+    pub fn simbolo(&self) -> &'static str {
+        match self {
+            Moeda::Brl => "R$",
+            Moeda::Usd => "US$",
+            Moeda::Eur => "€",
+        }
+    }
+
+    // (milhar, decimal) usados ao escrever e ler valores nessa moeda
+    fn separadores(&self) -> (char, char) {
+        match self {
+            Moeda::Usd => (',', '.'),
+            Moeda::Brl | Moeda::Eur => ('.', ','),
+        }
+    }
+}
+
+impl fmt::Display for Moeda {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.codigo())
+    }
+}
+
+// ========================================
+// DINHEIRO
+// ========================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErroDinheiro {
+    MoedasDiferentes { esperada: Moeda, recebida: Moeda },
+    // Resultado não cabe em i64 centavos
+    Estouro,
+    FormatoInvalido(String),
+}
+
+impl fmt::Display for ErroDinheiro {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErroDinheiro::MoedasDiferentes { esperada, recebida } => {
+                write!(f, "Moedas diferentes: esperava {}, recebeu {}", esperada, recebida)
+            }
+            ErroDinheiro::Estouro => write!(f, "Valor grande demais"),
+            ErroDinheiro::FormatoInvalido(texto) => write!(f, "Valor inválido: '{}'", texto),
+        }
+    }
+}
+
+impl Error for ErroDinheiro {}
+
+// Quantia exata em centavos; nunca passa por f64
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dinheiro {
+    centavos: i64,
+    moeda: Moeda,
+}
+
+impl Dinheiro {
+    pub fn new(centavos: i64, moeda: Moeda) -> Dinheiro {
+        Dinheiro { centavos, moeda }
+    }
+
+    // Reais inteiros: `Dinheiro::reais(1000)` é R$ 1.000,00.
+    // Pânico se não couber em centavos (mais de ~92 quadrilhões); para
+    // valores vindos de fora, use `try_reais` ou `parse`
+    pub fn reais(reais: i64) -> Dinheiro {
+        Dinheiro::try_reais(reais).expect("reais demais para caber em centavos")
+    }
+
+    pub fn try_reais(reais: i64) -> Result<Dinheiro, ErroDinheiro> {
+        let centavos = reais.checked_mul(100).ok_or(ErroDinheiro::Estouro)?;
+        Ok(Dinheiro::new(centavos, Moeda::Brl))
+    }
+
+    pub fn zero(moeda: Moeda) -> Dinheiro {
+        Dinheiro::new(0, moeda)
+    }
+
+    pub fn centavos(&self) -> i64 {
+        self.centavos
+    }
+
+    pub fn moeda(&self) -> Moeda {
+        self.moeda
+    }
+
+    pub fn positivo(&self) -> bool {
+        self.centavos > 0
+    }
+
+    pub fn negativo(&self) -> bool {
+        self.centavos < 0
+    }
+
+    pub fn zerado(&self) -> bool {
+        self.centavos == 0
+    }
+
+    pub fn somar(self, outro: Dinheiro) -> Result<Dinheiro, ErroDinheiro> {
+        self.mesma_moeda(outro)?;
+        let centavos = self.centavos.checked_add(outro.centavos).ok_or(ErroDinheiro::Estouro)?;
+        Ok(Dinheiro::new(centavos, self.moeda))
+    }
+
+    pub fn subtrair(self, outro: Dinheiro) -> Result<Dinheiro, ErroDinheiro> {
+        self.mesma_moeda(outro)?;
+        let centavos = self.centavos.checked_sub(outro.centavos).ok_or(ErroDinheiro::Estouro)?;
+        Ok(Dinheiro::new(centavos, self.moeda))
+    }
+
+    pub fn multiplicar(self, fator: i64) -> Result<Dinheiro, ErroDinheiro> {
+        let centavos = self.centavos.checked_mul(fator).ok_or(ErroDinheiro::Estouro)?;
+        Ok(Dinheiro::new(centavos, self.moeda))
+    }
+
+    pub fn negar(self) -> Result<Dinheiro, ErroDinheiro> {
+        let centavos = self.centavos.checked_neg().ok_or(ErroDinheiro::Estouro)?;
+        Ok(Dinheiro::new(centavos, self.moeda))
+    }
+
+    fn mesma_moeda(&self, outro: Dinheiro) -> Result<(), ErroDinheiro> {
+        if self.moeda != outro.moeda {
+            return Err(ErroDinheiro::MoedasDiferentes { esperada: self.moeda, recebida: outro.moeda });
+        }
+        Ok(())
+    }
+}
+
+// Só compara quantias da mesma moeda
+impl PartialOrd for Dinheiro {
+    fn partial_cmp(&self, outro: &Dinheiro) -> Option<Ordering> {
+        if self.moeda != outro.moeda {
+            return None;
+        }
+        Some(self.centavos.cmp(&outro.centavos))
+    }
+}
+
+// `R$ 1.234,56`, `-US$ 0.50`, `€ 10,00`
+impl fmt::Display for Dinheiro {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (milhar, decimal) = self.moeda.separadores();
+        let absoluto = self.centavos.unsigned_abs();
+        let inteira = (absoluto / 100).to_string();
+
+        let digitos: Vec<char> = inteira.chars().collect();
+        let grupos: Vec<String> = digitos.rchunks(3).rev().map(|grupo| grupo.iter().collect()).collect();
+        let sinal = if self.negativo() { "-" } else { "" };
+        write!(
+            f,
+            "{}{} {}{}{:02}",
+            sinal,
+            self.moeda.simbolo(),
+            grupos.join(&milhar.to_string()),
+            decimal,
+            absoluto % 100
+        )
+    }
+}
+
+// Lê `R$ 1.234,56`, `1234,56` (reais), `US$ 10.50`, `$10.50`, `€ 3,20`,
+// `BRL 5,00` ou `-R$ 5,00`. No máximo duas casas decimais
+impl FromStr for Dinheiro {
+    type Err = ErroDinheiro;
+
+    fn from_str(texto: &str) -> Result<Self, Self::Err> {
+        let invalido = || ErroDinheiro::FormatoInvalido(texto.to_string());
+        let mut resto = texto.trim();
+
+        let negativo = resto.starts_with('-');
+        if negativo {
+            resto = resto[1..].trim_start();
+        }
+
+        // Símbolos mais longos primeiro: "US$" antes de "$"
+        let prefixos = [
+            ("R$", Moeda::Brl),
+            ("US$", Moeda::Usd),
+            ("$", Moeda::Usd),
+            ("€", Moeda::Eur),
+            ("BRL", Moeda::Brl),
+            ("USD", Moeda::Usd),
+            ("EUR", Moeda::Eur),
+        ];
+        let moeda = match prefixos.iter().find(|(prefixo, _)| resto.starts_with(prefixo)) {
+            Some((prefixo, moeda)) => {
+                resto = resto[prefixo.len()..].trim_start();
+                *moeda
+            }
+            None => Moeda::Brl,
+        };
+        // O sinal também pode vir depois do símbolo: `R$ -5,00`
+        let negativo = match resto.strip_prefix('-') {
+            Some(sem_sinal) if !negativo => {
+                resto = sem_sinal;
+                true
+            }
+            Some(_) => return Err(invalido()),
+            None => negativo,
+        };
+
+        let valor = ler_numero(resto, moeda).ok_or_else(invalido)??;
+        if negativo { valor.negar() } else { Ok(valor) }
+    }
+}
+
+// Número sem sinal com os separadores da moeda: `1.234,56`.
+// `None` se o formato não bate; `Some(Err)` se não cabe em i64
+fn ler_numero(texto: &str, moeda: Moeda) -> Option<Result<Dinheiro, ErroDinheiro>> {
+    let (milhar, decimal) = moeda.separadores();
+
+    let (inteira, fracao) = texto.split_once(decimal).unwrap_or((texto, ""));
+    let grupos: Vec<&str> = inteira.split(milhar).collect();
+    let grupos_ok =
+        grupos.len() == 1 || (matches!(grupos[0].len(), 1..=3) && grupos[1..].iter().all(|grupo| grupo.len() == 3));
+    let so_digitos = |parte: &str| parte.chars().all(|c| c.is_ascii_digit());
+    if inteira.is_empty()
+        || texto.ends_with(decimal)
+        || !grupos_ok
+        || fracao.len() > 2
+        || !so_digitos(&grupos.concat())
+        || !so_digitos(fracao)
+    {
+        return None;
+    }
+
+    let centavos: i64 = format!("{:0<2}", fracao).parse().ok()?;
+    let total = grupos
+        .concat()
+        .parse::<i64>()
+        .ok()
+        .and_then(|reais| reais.checked_mul(100))
+        .and_then(|c| c.checked_add(centavos))
+        .ok_or(ErroDinheiro::Estouro);
+    Some(total.map(|total| Dinheiro::new(total, moeda)))
+}
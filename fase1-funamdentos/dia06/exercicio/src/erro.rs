@@ -0,0 +1,103 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::dinheiro::{Dinheiro, ErroDinheiro};
+
+// Idiomas das mensagens de erro
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Idioma {
+    Portugues,
+    Ingles,
+}
+
+// Tudo o que pode dar errado numa operação bancária
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErroBancario {
+    ContaInativa { numero: u32 },
+    ValorNaoPositivo(Dinheiro),
+    // Conta aberta devendo
+    SaldoInicialNegativo(Dinheiro),
+    SaldoInsuficiente { disponivel: Dinheiro, solicitado: Dinheiro },
+    // Só se encerra conta com saldo zero
+    SaldoNaoZerado(Dinheiro),
+    ContaDesconhecida(u32),
+    // Todos os números de conta já foram usados
+    NumerosEsgotados,
+    // Transferência com origem e destino iguais
+    MesmaConta(u32),
+    // Moeda diferente da conta ou valor que estouraria
+    Dinheiro(ErroDinheiro),
+}
+
+impl ErroBancario {
+    // Mensagem para o usuário; em português são as mensagens de sempre
+    pub fn mensagem(&self, idioma: Idioma) -> String {
+        match (self, idioma) {
+            (ErroBancario::ContaInativa { .. }, Idioma::Portugues) => String::from("Conta inativa!"),
+            (ErroBancario::ContaInativa { .. }, Idioma::Ingles) => String::from("Inactive account!"),
+            (ErroBancario::ValorNaoPositivo(_), Idioma::Portugues) => String::from("Valor deve ser positivo!"),
+            (ErroBancario::ValorNaoPositivo(_), Idioma::Ingles) => String::from("Amount must be positive!"),
+            (ErroBancario::SaldoInicialNegativo(_), Idioma::Portugues) => {
+                String::from("Saldo inicial não pode ser negativo!")
+            }
+            (ErroBancario::SaldoInicialNegativo(_), Idioma::Ingles) => {
+                String::from("Opening balance cannot be negative!")
+            }
+            (ErroBancario::SaldoInsuficiente { .. }, Idioma::Portugues) => String::from("Saldo insuficiente!"),
+            (ErroBancario::SaldoInsuficiente { .. }, Idioma::Ingles) => String::from("Insufficient funds!"),
+            (ErroBancario::SaldoNaoZerado(saldo), Idioma::Portugues) if saldo.negativo() => {
+                String::from("Não pode desativar conta com saldo negativo!")
+            }
+            (ErroBancario::SaldoNaoZerado(_), Idioma::Portugues) => {
+                String::from("Não pode desativar conta com saldo positivo!")
+            }
+            (ErroBancario::SaldoNaoZerado(saldo), Idioma::Ingles) if saldo.negativo() => {
+                String::from("Cannot close an account with a negative balance!")
+            }
+            (ErroBancario::SaldoNaoZerado(_), Idioma::Ingles) => {
+                String::from("Cannot close an account with a positive balance!")
+            }
+            (ErroBancario::ContaDesconhecida(numero), Idioma::Portugues) => {
+                format!("Conta {} não encontrada!", numero)
+            }
+            (ErroBancario::ContaDesconhecida(numero), Idioma::Ingles) => format!("Account {} not found!", numero),
+            (ErroBancario::NumerosEsgotados, Idioma::Portugues) => String::from("Não há mais números de conta livres!"),
+            (ErroBancario::NumerosEsgotados, Idioma::Ingles) => String::from("No account numbers left!"),
+            (ErroBancario::MesmaConta(numero), Idioma::Portugues) => {
+                format!("Não pode transferir da conta {} para ela mesma!", numero)
+            }
+            (ErroBancario::MesmaConta(numero), Idioma::Ingles) => {
+                format!("Cannot transfer from account {} to itself!", numero)
+            }
+            (ErroBancario::Dinheiro(erro), Idioma::Portugues) => erro.to_string(),
+            (ErroBancario::Dinheiro(erro), Idioma::Ingles) => match erro {
+                ErroDinheiro::MoedasDiferentes { esperada, recebida } => {
+                    format!("Currency mismatch: expected {}, got {}", esperada, recebida)
+                }
+                ErroDinheiro::Estouro => String::from("Amount too large"),
+                ErroDinheiro::FormatoInvalido(texto) => format!("Invalid amount: '{}'", texto),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ErroBancario {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.mensagem(Idioma::Portugues))
+    }
+}
+
+impl Error for ErroBancario {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ErroBancario::Dinheiro(erro) => Some(erro),
+            _ => None,
+        }
+    }
+}
+
+impl From<ErroDinheiro> for ErroBancario {
+    fn from(erro: ErroDinheiro) -> Self {
+        ErroBancario::Dinheiro(erro)
+    }
+}
@@ -0,0 +1,13 @@
+mod banco;
+mod conta;
+mod diario;
+mod dinheiro;
+mod erro;
+mod transacao;
+
+pub use banco::{Banco, FiltroContas, PRIMEIRO_NUMERO};
+pub use conta::ContaBancaria;
+pub use diario::{Data, Diario, Extrato, Lancamento, TipoOperacao, agora};
+pub use dinheiro::{Dinheiro, ErroDinheiro, Moeda};
+pub use erro::{ErroBancario, Idioma};
+pub use transacao::Transferencia;
@@ -0,0 +1,56 @@
+use crate::conta::ContaBancaria;
+use crate::diario::{Lancamento, TipoOperacao};
+use crate::dinheiro::Dinheiro;
+use crate::erro::ErroBancario;
+
+// Transferência em duas fases. `preparar` valida as duas contas e monta os
+// dois lançamentos sem mudar nada; `confirmar` aplica os dois e não falha.
+// Enquanto ela existe, as contas ficam emprestadas com `&mut`: ninguém
+// consegue ver (nem mexer em) uma conta com só metade aplicada.
+// Descartar sem confirmar é o rollback: nada foi escrito.
+// A atomicidade é só em memória: nada é gravado em disco, então se o
+// processo morrer o estado inteiro se perde. Registrar preparo e
+// confirmação de forma durável (e recuperar depois de uma queda) fica
+// fora do escopo deste tipo
+#[must_use = "a transferência só acontece em `confirmar`"]
+pub struct Transferencia<'a> {
+    origem: &'a mut ContaBancaria,
+    destino: &'a mut ContaBancaria,
+    debito: Lancamento,
+    credito: Lancamento,
+}
+
+impl<'a> Transferencia<'a> {
+    // Fase 1: qualquer erro (conta inativa, saldo, moeda...) aparece aqui
+    pub fn preparar(
+        origem: &'a mut ContaBancaria,
+        destino: &'a mut ContaBancaria,
+        valor: Dinheiro,
+    ) -> Result<Transferencia<'a>, ErroBancario> {
+        let enviada = TipoOperacao::TransferenciaEnviada { destino: destino.numero().to_string() };
+        let recebida = TipoOperacao::TransferenciaRecebida { origem: origem.numero().to_string() };
+        let debito = origem.preparar_debito(enviada, valor)?;
+        let credito = destino.preparar_credito(recebida, valor)?;
+        Ok(Transferencia { origem, destino, debito, credito })
+    }
+
+    // Lançamento que entrará na origem
+    pub fn debito(&self) -> &Lancamento {
+        &self.debito
+    }
+
+    // Lançamento que entrará no destino
+    pub fn credito(&self) -> &Lancamento {
+        &self.credito
+    }
+
+    // Fase 2: aplica os dois lançamentos. O espaço nos diários já foi
+    // reservado em `preparar`, então aqui não há alocação nem erro
+    pub fn confirmar(self) {
+        self.origem.confirmar(self.debito);
+        self.destino.confirmar(self.credito);
+    }
+
+    // Desiste da transferência; as contas ficam como estavam
+    pub fn cancelar(self) {}
+}
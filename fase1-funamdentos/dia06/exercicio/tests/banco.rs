@@ -0,0 +1,80 @@
+use exercicio::{Banco, Dinheiro, ErroBancario, FiltroContas, Moeda, PRIMEIRO_NUMERO};
+
+fn banco_com_tres_contas() -> (Banco, [u32; 3]) {
+    let mut banco = Banco::new();
+    let numeros = [
+        banco.abrir_conta(String::from("Bianeck"), Dinheiro::reais(1000)).unwrap(),
+        banco.abrir_conta(String::from("Clara"), Dinheiro::reais(500)).unwrap(),
+        banco.abrir_conta(String::from("Clara Lunna"), Dinheiro::reais(0)).unwrap(),
+    ];
+    (banco, numeros)
+}
+
+fn numeros(contas: Vec<&exercicio::ContaBancaria>) -> Vec<u32> {
+    contas.iter().map(|conta| conta.numero()).collect()
+}
+
+#[test]
+fn test_numeros_sao_sequenciais() {
+    let (banco, [a, b, c]) = banco_com_tres_contas();
+    assert_eq!([a, b, c], [PRIMEIRO_NUMERO, PRIMEIRO_NUMERO + 1, PRIMEIRO_NUMERO + 2]);
+    assert_eq!(banco.quantidade_de_contas(), 3);
+    assert_eq!(banco.conta(b).unwrap().titular(), "Clara");
+    assert!(matches!(banco.conta(42), Err(ErroBancario::ContaDesconhecida(42))));
+}
+
+#[test]
+fn test_transferencia_por_numero() {
+    let (mut banco, [bianeck, clara, _]) = banco_com_tres_contas();
+    banco.transferir(bianeck, clara, Dinheiro::reais(300)).unwrap();
+    assert_eq!(banco.conta(bianeck).unwrap().obter_saldo(), Dinheiro::reais(700));
+    assert_eq!(banco.conta(clara).unwrap().obter_saldo(), Dinheiro::reais(800));
+
+    assert_eq!(banco.transferir(bianeck, 9999, Dinheiro::reais(1)), Err(ErroBancario::ContaDesconhecida(9999)));
+    assert_eq!(banco.transferir(9999, bianeck, Dinheiro::reais(1)), Err(ErroBancario::ContaDesconhecida(9999)));
+    assert_eq!(banco.transferir(clara, clara, Dinheiro::reais(1)), Err(ErroBancario::MesmaConta(clara)));
+    // Transferência recusada não muda o saldo da origem
+    assert!(banco.transferir(bianeck, clara, Dinheiro::reais(5000)).is_err());
+    assert_eq!(banco.conta(bianeck).unwrap().obter_saldo(), Dinheiro::reais(700));
+}
+
+#[test]
+fn test_saldo_inicial_negativo() {
+    let (mut banco, [_, _, clara_lunna]) = banco_com_tres_contas();
+    let devendo = Dinheiro::reais(-1);
+    assert_eq!(banco.abrir_conta(String::from("Devedor"), devendo), Err(ErroBancario::SaldoInicialNegativo(devendo)));
+    // O número recusado não é gasto
+    assert_eq!(banco.quantidade_de_contas(), 3);
+    assert_eq!(banco.abrir_conta(String::from("Lunna"), Dinheiro::reais(0)), Ok(clara_lunna + 1));
+}
+
+#[test]
+fn test_numeros_esgotados() {
+    let mut banco = Banco::com_primeiro_numero(u32::MAX - 1);
+    assert_eq!(banco.abrir_conta(String::from("Bianeck"), Dinheiro::reais(1)), Ok(u32::MAX - 1));
+    assert_eq!(banco.abrir_conta(String::from("Clara"), Dinheiro::reais(1)), Ok(u32::MAX));
+    // Sem dar a volta: o próximo número seria o de uma conta que já existe
+    assert_eq!(banco.abrir_conta(String::from("Lunna"), Dinheiro::reais(1)), Err(ErroBancario::NumerosEsgotados));
+    assert_eq!(banco.quantidade_de_contas(), 2);
+}
+
+#[test]
+fn test_busca_por_titular() {
+    let (banco, [_, clara, clara_lunna]) = banco_com_tres_contas();
+    assert_eq!(numeros(banco.buscar_por_titular("CLARA")), vec![clara, clara_lunna]);
+    assert!(banco.buscar_por_titular("Ninguém").is_empty());
+}
+
+#[test]
+fn test_listagem_com_filtros() {
+    let (mut banco, [bianeck, clara, clara_lunna]) = banco_com_tres_contas();
+    banco.encerrar_conta(clara_lunna).unwrap();
+    let euros = banco.abrir_conta(String::from("Lunna"), Dinheiro::new(90_000, Moeda::Eur)).unwrap();
+
+    assert_eq!(numeros(banco.listar(&FiltroContas::new())), vec![bianeck, clara, clara_lunna, euros]);
+    assert_eq!(numeros(banco.listar(&FiltroContas::new().so_inativas())), vec![clara_lunna]);
+    let faixa = FiltroContas::new().so_ativas().com_saldo_entre(Dinheiro::reais(100), Dinheiro::reais(600));
+    assert_eq!(numeros(banco.listar(&faixa)), vec![clara]);
+    // Saldo em outra moeda não entra numa faixa em reais
+    assert_eq!(numeros(banco.listar(&FiltroContas::new().com_saldo_minimo(Dinheiro::reais(1)))), vec![bianeck, clara]);
+}
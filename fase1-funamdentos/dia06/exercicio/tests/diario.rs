@@ -0,0 +1,90 @@
+use exercicio::{ContaBancaria, Data, Diario, Dinheiro, Moeda, TipoOperacao};
+
+// 2025-11-20 12:00 UTC e um dia depois
+const DIA_20: u64 = 1_763_640_000;
+const DIA: u64 = 86_400;
+
+fn diario_de_tres_dias() -> Diario {
+    let mut diario = Diario::new(Moeda::Brl);
+    diario.registrar(TipoOperacao::Abertura, Dinheiro::reais(100), DIA_20).unwrap();
+    diario.registrar(TipoOperacao::Deposito, Dinheiro::reais(50), DIA_20 + DIA).unwrap();
+    diario.registrar(TipoOperacao::Saque, Dinheiro::reais(30), DIA_20 + DIA).unwrap();
+    diario
+        .registrar(TipoOperacao::Pagamento { descricao: String::from("Luz") }, Dinheiro::reais(20), DIA_20 + 2 * DIA)
+        .unwrap();
+    diario
+}
+
+#[test]
+fn test_saldo_vem_do_diario() {
+    let diario = diario_de_tres_dias();
+    assert_eq!(diario.saldo(), Dinheiro::reais(100));
+    let ids: Vec<u64> = diario.lancamentos().iter().map(|l| l.id).collect();
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+    let saldos: Vec<i64> = diario.lancamentos().iter().map(|l| l.saldo.centavos()).collect();
+    assert_eq!(saldos, vec![10_000, 15_000, 12_000, 10_000]);
+}
+
+#[test]
+fn test_extrato_por_periodo() {
+    let diario = diario_de_tres_dias();
+    let dia_21 = Data::new(2025, 11, 21).unwrap();
+
+    let extrato = diario.extrato(dia_21, dia_21);
+    assert_eq!(extrato.saldo_anterior, Dinheiro::reais(100));
+    assert_eq!(extrato.lancamentos.len(), 2);
+    assert_eq!(extrato.saldo_final, Dinheiro::reais(120));
+
+    let tudo = diario.extrato(Data::new(2025, 1, 1).unwrap(), Data::new(2025, 12, 31).unwrap());
+    assert_eq!(tudo.saldo_anterior, Dinheiro::reais(0));
+    assert_eq!(tudo.lancamentos.len(), 4);
+    assert!(tudo.to_string().contains("Pagamento: Luz"));
+}
+
+#[test]
+fn test_datas() {
+    let data: Data = "2025-11-20".parse().unwrap();
+    assert_eq!(data, Data::de_timestamp(DIA_20));
+    assert_eq!(Data::de_timestamp(data.timestamp()), data);
+    assert_eq!(data.timestamp(), DIA_20 - 12 * 3_600);
+    assert_eq!(data.to_string(), "20/11/2025");
+    assert_eq!(Data::new(2024, 2, 29).map(|d| d.dia), Some(29));
+    assert!(Data::new(2025, 2, 29).is_none());
+    assert!("20/11/2025".parse::<Data>().is_err());
+}
+
+#[test]
+fn test_conta_registra_cada_operacao() {
+    let mut origem = ContaBancaria::new(String::from("Bianeck"), 1001, Dinheiro::reais(1000)).unwrap();
+    let mut destino = ContaBancaria::new(String::from("Clara"), 1002, Dinheiro::reais(0)).unwrap();
+    origem.depositar(Dinheiro::reais(500)).unwrap();
+    origem.sacar(Dinheiro::reais(200)).unwrap();
+    origem.transferir(&mut destino, Dinheiro::reais(300)).unwrap();
+    assert!(origem.sacar(Dinheiro::reais(5000)).is_err());
+
+    let tipos: Vec<&TipoOperacao> = origem.diario().lancamentos().iter().map(|l| &l.tipo).collect();
+    assert_eq!(
+        tipos,
+        vec![
+            &TipoOperacao::Abertura,
+            &TipoOperacao::Deposito,
+            &TipoOperacao::Saque,
+            &TipoOperacao::TransferenciaEnviada { destino: String::from("1002") },
+        ]
+    );
+    assert_eq!(origem.obter_saldo(), Dinheiro::reais(1000));
+    assert_eq!(destino.obter_saldo(), Dinheiro::reais(300));
+}
+
+#[test]
+fn test_transferencia_recusada_nao_deixa_lancamento() {
+    let mut origem = ContaBancaria::new(String::from("Bianeck"), 1001, Dinheiro::reais(100)).unwrap();
+    let mut destino = ContaBancaria::new(String::from("Lunna"), 1003, Dinheiro::reais(0)).unwrap();
+    destino.desativar().unwrap();
+
+    assert!(origem.transferir(&mut destino, Dinheiro::reais(40)).is_err());
+    assert_eq!(origem.obter_saldo(), Dinheiro::reais(100));
+    // Só a abertura: o débito nunca chegou a ser escrito
+    assert_eq!(origem.diario().lancamentos().len(), 1);
+    assert_eq!(destino.diario().lancamentos().len(), 1);
+}
@@ -0,0 +1,75 @@
+use exercicio::{ContaBancaria, Dinheiro, ErroDinheiro, Moeda};
+
+fn brl(centavos: i64) -> Dinheiro {
+    Dinheiro::new(centavos, Moeda::Brl)
+}
+
+#[test]
+fn test_formatacao() {
+    assert_eq!(brl(123_456).to_string(), "R$ 1.234,56");
+    assert_eq!(brl(-5).to_string(), "-R$ 0,05");
+    assert_eq!(Dinheiro::reais(1_000_000).to_string(), "R$ 1.000.000,00");
+    assert_eq!(Dinheiro::new(123_456, Moeda::Usd).to_string(), "US$ 1,234.56");
+    assert_eq!(Dinheiro::new(320, Moeda::Eur).to_string(), "€ 3,20");
+}
+
+#[test]
+fn test_leitura() {
+    assert_eq!("R$ 1.234,56".parse(), Ok(brl(123_456)));
+    assert_eq!("1234,5".parse(), Ok(brl(123_450)));
+    assert_eq!("-R$ 5,00".parse(), Ok(brl(-500)));
+    assert_eq!("R$ -5".parse(), Ok(brl(-500)));
+    assert_eq!("US$ 1,234.56".parse(), Ok(Dinheiro::new(123_456, Moeda::Usd)));
+    assert_eq!("$0.99".parse(), Ok(Dinheiro::new(99, Moeda::Usd)));
+    assert_eq!("EUR 3,20".parse(), Ok(Dinheiro::new(320, Moeda::Eur)));
+
+    // Formato e volta
+    for valor in [brl(0), brl(7), brl(-123_456_789), Dinheiro::new(100_000, Moeda::Usd)] {
+        assert_eq!(valor.to_string().parse(), Ok(valor));
+    }
+}
+
+#[test]
+fn test_leitura_invalida() {
+    for texto in ["", "R$", "R$ 1,234", "R$ 12.34,00", "R$ 1.2345,00", "R$ 1,", "abc", "R$ --5", "R$ 1,2,3"] {
+        assert!(matches!(texto.parse::<Dinheiro>(), Err(ErroDinheiro::FormatoInvalido(_))), "{}", texto);
+    }
+    assert_eq!("R$ 99999999999999999999".parse::<Dinheiro>(), Err(ErroDinheiro::Estouro));
+}
+
+#[test]
+fn test_aritmetica_verificada() {
+    assert_eq!(brl(10).somar(brl(5)), Ok(brl(15)));
+    assert_eq!(brl(10).subtrair(brl(15)), Ok(brl(-5)));
+    assert_eq!(brl(10).multiplicar(3), Ok(brl(30)));
+    assert_eq!(brl(i64::MAX).somar(brl(1)), Err(ErroDinheiro::Estouro));
+    assert_eq!(brl(i64::MIN).negar(), Err(ErroDinheiro::Estouro));
+    assert_eq!(Dinheiro::try_reais(i64::MAX), Err(ErroDinheiro::Estouro));
+    assert_eq!(Dinheiro::try_reais(-7), Ok(brl(-700)));
+    assert_eq!(
+        brl(10).somar(Dinheiro::new(10, Moeda::Usd)),
+        Err(ErroDinheiro::MoedasDiferentes { esperada: Moeda::Brl, recebida: Moeda::Usd })
+    );
+    assert!(brl(10) < brl(11));
+    assert_eq!(brl(10).partial_cmp(&Dinheiro::new(10, Moeda::Eur)), None);
+}
+
+#[test]
+fn test_centavos_nao_acumulam_erro() {
+    // Com f64, dez depósitos de 0,10 não somam exatamente 1,00
+    let mut conta = ContaBancaria::new(String::from("Clara"), 1002, brl(0)).unwrap();
+    for _ in 0..10 {
+        conta.depositar(brl(10)).unwrap();
+    }
+    assert_eq!(conta.obter_saldo(), Dinheiro::reais(1));
+    conta.sacar(Dinheiro::reais(1)).unwrap();
+    assert!(conta.obter_saldo().zerado());
+    assert!(conta.desativar().is_ok());
+}
+
+#[test]
+fn test_conta_recusa_outra_moeda() {
+    let mut conta = ContaBancaria::new(String::from("Lunna"), 1003, Dinheiro::reais(10)).unwrap();
+    assert!(conta.depositar(Dinheiro::new(500, Moeda::Usd)).is_err());
+    assert_eq!(conta.obter_saldo(), Dinheiro::reais(10));
+}
@@ -0,0 +1,54 @@
+use exercicio::{ContaBancaria, Dinheiro, ErroBancario, ErroDinheiro, Idioma, Moeda};
+
+fn conta(numero: u32, saldo: i64) -> ContaBancaria {
+    ContaBancaria::new(String::from("Bianeck"), numero, Dinheiro::reais(saldo)).unwrap()
+}
+
+#[test]
+fn test_erros_tipados() {
+    let mut origem = conta(1001, 100);
+    assert_eq!(origem.depositar(Dinheiro::reais(0)), Err(ErroBancario::ValorNaoPositivo(Dinheiro::reais(0))));
+    assert_eq!(
+        origem.sacar(Dinheiro::reais(150)),
+        Err(ErroBancario::SaldoInsuficiente { disponivel: Dinheiro::reais(100), solicitado: Dinheiro::reais(150) })
+    );
+    assert_eq!(origem.desativar(), Err(ErroBancario::SaldoNaoZerado(Dinheiro::reais(100))));
+    assert_eq!(
+        origem.depositar(Dinheiro::new(100, Moeda::Eur)),
+        Err(ErroBancario::Dinheiro(ErroDinheiro::MoedasDiferentes { esperada: Moeda::Brl, recebida: Moeda::Eur }))
+    );
+
+    let mut encerrada = conta(1002, 0);
+    encerrada.desativar().unwrap();
+    assert_eq!(encerrada.depositar(Dinheiro::reais(1)), Err(ErroBancario::ContaInativa { numero: 1002 }));
+    // O erro aponta o destino inativo, não a origem
+    assert_eq!(
+        origem.transferir(&mut encerrada, Dinheiro::reais(10)),
+        Err(ErroBancario::ContaInativa { numero: 1002 })
+    );
+    assert_eq!(origem.obter_saldo(), Dinheiro::reais(100));
+}
+
+#[test]
+fn test_mensagens_de_sempre_em_portugues() {
+    let insuficiente =
+        ErroBancario::SaldoInsuficiente { disponivel: Dinheiro::reais(1), solicitado: Dinheiro::reais(2) };
+    assert_eq!(ErroBancario::ContaInativa { numero: 1 }.to_string(), "Conta inativa!");
+    assert_eq!(ErroBancario::ValorNaoPositivo(Dinheiro::reais(0)).to_string(), "Valor deve ser positivo!");
+    assert_eq!(insuficiente.to_string(), "Saldo insuficiente!");
+    assert_eq!(
+        ErroBancario::SaldoNaoZerado(Dinheiro::reais(5)).to_string(),
+        "Não pode desativar conta com saldo positivo!"
+    );
+    assert_eq!(insuficiente.mensagem(Idioma::Ingles), "Insufficient funds!");
+    assert_eq!(ErroBancario::ContaDesconhecida(42).mensagem(Idioma::Ingles), "Account 42 not found!");
+}
+
+#[test]
+fn test_origem_do_erro() {
+    use std::error::Error;
+
+    let erro = ErroBancario::from(ErroDinheiro::Estouro);
+    assert!(erro.source().is_some());
+    assert!(ErroBancario::ContaDesconhecida(1).source().is_none());
+}
@@ -0,0 +1,183 @@
+use exercicio::{Banco, ContaBancaria, Dinheiro, ErroBancario, FiltroContas, Moeda, TipoOperacao, Transferencia};
+
+fn conta(numero: u32, reais: i64) -> ContaBancaria {
+    ContaBancaria::new(format!("Titular {}", numero), numero, Dinheiro::reais(reais)).unwrap()
+}
+
+// ========================================
+// DUAS FASES
+// ========================================
+
+#[test]
+fn test_preparar_nao_muda_nada_ate_confirmar() {
+    let mut origem = conta(1, 100);
+    let mut destino = conta(2, 0);
+
+    let transferencia = Transferencia::preparar(&mut origem, &mut destino, Dinheiro::reais(30)).unwrap();
+    assert_eq!(transferencia.debito().saldo, Dinheiro::reais(70));
+    assert_eq!(transferencia.credito().saldo, Dinheiro::reais(30));
+    assert_eq!(transferencia.credito().tipo, TipoOperacao::TransferenciaRecebida { origem: String::from("1") });
+    transferencia.cancelar();
+
+    assert_eq!(origem.obter_saldo(), Dinheiro::reais(100));
+    assert_eq!(origem.diario().lancamentos().len(), 1);
+    assert_eq!(destino.diario().lancamentos().len(), 1);
+
+    Transferencia::preparar(&mut origem, &mut destino, Dinheiro::reais(30)).unwrap().confirmar();
+    assert_eq!(origem.obter_saldo(), Dinheiro::reais(70));
+    assert_eq!(destino.obter_saldo(), Dinheiro::reais(30));
+}
+
+#[test]
+fn test_destino_invalido_recusa_antes_de_debitar() {
+    let mut origem = conta(1, 100);
+    let mut inativa = conta(2, 0);
+    inativa.desativar().unwrap();
+    let mut em_euros = ContaBancaria::new(String::from("Lunna"), 3, Dinheiro::new(0, Moeda::Eur)).unwrap();
+
+    assert_eq!(origem.transferir(&mut inativa, Dinheiro::reais(10)), Err(ErroBancario::ContaInativa { numero: 2 }));
+    assert!(matches!(origem.transferir(&mut em_euros, Dinheiro::reais(10)), Err(ErroBancario::Dinheiro(_))));
+    assert_eq!(origem.obter_saldo(), Dinheiro::reais(100));
+    assert_eq!(origem.diario().lancamentos().len(), 1);
+}
+
+// ========================================
+// PROPRIEDADES
+// ========================================
+
+// Gerador xorshift64*: determinístico, para a falha se repetir com a mesma semente
+struct Aleatorio(u64);
+
+impl Aleatorio {
+    fn new(semente: u64) -> Self {
+        Aleatorio(semente.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn proximo(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    // De 0 a `limite - 1`
+    fn ate(&mut self, limite: u64) -> u64 {
+        self.proximo() % limite
+    }
+}
+
+const CONTAS: u32 = 5;
+
+// Valor de teste: às vezes zero, às vezes maior que qualquer saldo
+fn valor_aleatorio(aleatorio: &mut Aleatorio) -> Dinheiro {
+    match aleatorio.ate(10) {
+        0 => Dinheiro::reais(0),
+        1 => Dinheiro::reais(1_000_000),
+        _ => Dinheiro::new(aleatorio.ate(50_000) as i64 + 1, Moeda::Brl),
+    }
+}
+
+// Número de conta, às vezes inexistente
+fn numero_aleatorio(aleatorio: &mut Aleatorio, primeira: u32) -> u32 {
+    primeira + aleatorio.ate(CONTAS as u64 + 1) as u32
+}
+
+// (número, saldo, quantidade de lançamentos) de cada conta
+fn fotografia(banco: &Banco) -> Vec<(u32, Dinheiro, usize)> {
+    banco
+        .listar(&FiltroContas::new())
+        .iter()
+        .map(|conta| (conta.numero(), conta.obter_saldo(), conta.diario().lancamentos().len()))
+        .collect()
+}
+
+fn verificar_diarios(banco: &Banco) {
+    for conta in banco.listar(&FiltroContas::new()) {
+        let lancamentos = conta.diario().lancamentos();
+        let soma: i64 = lancamentos.iter().map(|l| l.centavos_com_sinal()).sum();
+        assert_eq!(soma, conta.obter_saldo().centavos(), "conta {}", conta.numero());
+        assert_eq!(lancamentos.last().map(|l| l.saldo), Some(conta.obter_saldo()));
+        assert!(!conta.obter_saldo().negativo(), "conta {} negativa", conta.numero());
+    }
+}
+
+#[test]
+fn test_dinheiro_se_conserva_em_sequencias_aleatorias() {
+    for semente in 0..200 {
+        let mut aleatorio = Aleatorio::new(semente);
+        let mut banco = Banco::new();
+        let primeira = banco.abrir_conta(String::from("Conta 0"), Dinheiro::reais(1_000)).unwrap();
+        for i in 1..CONTAS {
+            banco.abrir_conta(format!("Conta {}", i), Dinheiro::reais(aleatorio.ate(2_000) as i64)).unwrap();
+        }
+        let mut esperado = banco.saldo_total(Moeda::Brl).unwrap();
+
+        for passo in 0..300 {
+            let antes = fotografia(&banco);
+            let (origem, destino) =
+                (numero_aleatorio(&mut aleatorio, primeira), numero_aleatorio(&mut aleatorio, primeira));
+            let valor = valor_aleatorio(&mut aleatorio);
+
+            let (resultado, variacao) = match aleatorio.ate(10) {
+                0..=1 => (banco.depositar(origem, valor), valor),
+                2..=3 => (banco.sacar(origem, valor), valor.negar().unwrap()),
+                4 => (banco.encerrar_conta(origem), Dinheiro::reais(0)),
+                _ => (banco.transferir(origem, destino, valor), Dinheiro::reais(0)),
+            };
+            let contexto = format!("semente {}, passo {}: {:?}", semente, passo, resultado);
+
+            if resultado.is_ok() {
+                esperado = esperado.somar(variacao).unwrap();
+            } else {
+                // Operação recusada não deixa nenhum rastro
+                assert_eq!(fotografia(&banco), antes, "{}", contexto);
+            }
+            assert_eq!(banco.saldo_total(Moeda::Brl).unwrap(), esperado, "{}", contexto);
+        }
+        verificar_diarios(&banco);
+    }
+}
+
+#[test]
+fn test_transferencia_e_tudo_ou_nada() {
+    for semente in 0..200 {
+        let mut aleatorio = Aleatorio::new(semente);
+        let mut banco = Banco::new();
+        let primeira = banco.abrir_conta(String::from("Conta 0"), Dinheiro::reais(500)).unwrap();
+        for i in 1..CONTAS {
+            let numero = banco.abrir_conta(format!("Conta {}", i), Dinheiro::reais(aleatorio.ate(500) as i64)).unwrap();
+            if aleatorio.ate(4) == 0 {
+                let _ = banco.sacar(numero, banco.conta(numero).unwrap().obter_saldo());
+                let _ = banco.encerrar_conta(numero);
+            }
+        }
+
+        for _ in 0..100 {
+            let (origem, destino) =
+                (numero_aleatorio(&mut aleatorio, primeira), numero_aleatorio(&mut aleatorio, primeira));
+            let valor = valor_aleatorio(&mut aleatorio);
+            let antes = fotografia(&banco);
+            let resultado = banco.transferir(origem, destino, valor);
+            let depois = fotografia(&banco);
+
+            let mudaram: Vec<_> = antes.iter().zip(&depois).filter(|(a, d)| a != d).map(|(a, _)| a.0).collect();
+            match resultado {
+                // Exatamente as duas contas, um lançamento em cada
+                Ok(()) => {
+                    assert_eq!(mudaram, {
+                        let mut par = vec![origem, destino];
+                        par.sort();
+                        par
+                    });
+                    for (a, d) in antes.iter().zip(&depois).filter(|(a, d)| a != d) {
+                        assert_eq!(d.2, a.2 + 1);
+                        let variacao = if a.0 == origem { valor.negar().unwrap() } else { valor };
+                        assert_eq!(d.1, a.1.somar(variacao).unwrap());
+                    }
+                }
+                Err(_) => assert!(mudaram.is_empty(), "semente {}: {:?}", semente, mudaram),
+            }
+        }
+        verificar_diarios(&banco);
+    }
+}
@@ -1,82 +1,93 @@
+use banco::{Data, Diario, Dinheiro, TipoOperacao, agora};
+
 // Enum que representa diferentes tipos de transações
 enum Transacao {
-    // TODO: Implemente as 4 variantes
-    // Deposito -> valor: f64
-    // Saque -> valor: f64
-    // Transferencia -> valor: f64, conta_destino: String
-    // Pagamento -> descricao: String, valor: f64
+    Deposito(Dinheiro),
+    Saque(Dinheiro),
+    Transferencia { valor: Dinheiro, conta_destino: String },
+    Pagamento { descricao: String, valor: Dinheiro },
 }
 
-// Struct que representa uma conta bancária
+// Struct que representa uma conta bancária.
+// O saldo vem do diário, que guarda cada transação aceita
 struct ContaBancaria {
     titular: String,
-    saldo: f64,
+    diario: Diario,
 }
 
 impl ContaBancaria {
     // Cria nova conta
-    fn nova(titular: String, saldo_inicial: f64) -> Self {
-        ContaBancaria {
-            titular,
-            saldo: saldo_inicial,
-        }
+    fn nova(titular: String, saldo_inicial: Dinheiro) -> Self {
+        let mut diario = Diario::new(saldo_inicial.moeda());
+        diario.registrar(TipoOperacao::Abertura, saldo_inicial, agora()).expect("diário vazio aceita qualquer saldo");
+        ContaBancaria { titular, diario }
     }
-    
+
     // Processa uma transação
     // Retorna Ok com mensagem de sucesso ou Err com mensagem de erro
     fn processar_transacao(&mut self, transacao: Transacao) -> Result<String, String> {
-        // TODO: Implemente usando match
-        // - Validar valores (devem ser > 0)
-        // - Deposito: adicionar ao saldo
-        // - Saque: verificar saldo suficiente antes de subtrair
-        // - Transferencia: verificar saldo suficiente antes de subtrair
-        // - Pagamento: verificar saldo suficiente antes de subtrair
-        // Retornar mensagens descritivas
-        
-        todo!() // Remova esta linha e implemente
+        let (tipo, valor, mensagem) = match transacao {
+            Transacao::Deposito(valor) => (TipoOperacao::Deposito, valor, format!("Depósito de {}", valor)),
+            Transacao::Saque(valor) => (TipoOperacao::Saque, valor, format!("Saque de {}", valor)),
+            Transacao::Transferencia { valor, conta_destino } => {
+                let mensagem = format!("Transferência de {} para {}", valor, conta_destino);
+                (TipoOperacao::TransferenciaEnviada { destino: conta_destino }, valor, mensagem)
+            }
+            Transacao::Pagamento { descricao, valor } => {
+                let mensagem = format!("Pagamento de {} ({})", valor, descricao);
+                (TipoOperacao::Pagamento { descricao }, valor, mensagem)
+            }
+        };
+
+        if !valor.positivo() {
+            return Err(format!("{}: valor deve ser positivo", mensagem));
+        }
+
+        // Só o depósito entra; o resto precisa de saldo
+        if !tipo.credito() && valor > self.consultar_saldo() {
+            return Err(format!("{}: saldo insuficiente (disponível {})", mensagem, self.consultar_saldo()));
+        }
+
+        self.diario.registrar(tipo, valor, agora()).map_err(|e| format!("{}: {}", mensagem, e))?;
+        Ok(format!("{} realizado", mensagem))
     }
-    
+
     // Consulta saldo
-    fn consultar_saldo(&self) -> f64 {
-        self.saldo
+    fn consultar_saldo(&self) -> Dinheiro {
+        self.diario.saldo()
     }
 }
 
 // Função que processa múltiplas transações e exibe extrato
 fn exibir_extrato(conta: &mut ContaBancaria, transacoes: Vec<Transacao>) {
-    // TODO: Implemente
-    // - Iterar sobre transacoes
-    // - Processar cada uma com processar_transacao
-    // - Usar match para Ok/Err
-    // - Exibir resultado de cada operação
-    // - Ao final, mostrar saldo
+    for transacao in transacoes {
+        match conta.processar_transacao(transacao) {
+            Ok(mensagem) => println!("✓ {}", mensagem),
+            Err(erro) => println!("✗ {}", erro),
+        }
+    }
+
+    // O extrato sai do diário: só as transações aceitas aparecem
+    let hoje = Data::hoje();
+    println!("{}", conta.diario.extrato(hoje, hoje));
 }
 
 fn main() {
     println!("=== SISTEMA BANCÁRIO ===\n");
-    
-    let mut conta = ContaBancaria::nova(
-        String::from("Bianeck"),
-        1000.0
-    );
-    
+
+    let mut conta = ContaBancaria::nova(String::from("Bianeck"), Dinheiro::reais(1000));
+
     println!("Titular: {}", conta.titular);
-    println!("Saldo inicial: R$ {:.2}\n", conta.consultar_saldo());
-    
+    println!("Saldo inicial: {}\n", conta.consultar_saldo());
+
     // Lista de transações para processar
     let transacoes = vec![
-        Transacao::Deposito(500.0),
-        Transacao::Saque(200.0),
-        Transacao::Transferencia {
-            valor: 300.0,
-            conta_destino: String::from("12345-6"),
-        },
-        Transacao::Pagamento {
-            descricao: String::from("Conta de Luz"),
-            valor: 150.0,
-        },
-        Transacao::Saque(2000.0), // Vai falhar - saldo insuficiente
+        Transacao::Deposito(Dinheiro::reais(500)),
+        Transacao::Saque(Dinheiro::reais(200)),
+        Transacao::Transferencia { valor: Dinheiro::reais(300), conta_destino: String::from("12345-6") },
+        Transacao::Pagamento { descricao: String::from("Conta de Luz"), valor: Dinheiro::reais(150) },
+        Transacao::Saque(Dinheiro::reais(2000)), // Vai falhar - saldo insuficiente
     ];
-    
+
     exibir_extrato(&mut conta, transacoes);
-}
\ No newline at end of file
+}